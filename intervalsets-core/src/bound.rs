@@ -49,6 +49,10 @@ pub trait SetBounds<T> {
 /// Side( Left | Right ) on the number line.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub enum Side {
     /// Generally the lower bound
     Left,
@@ -79,6 +83,10 @@ impl Side {
 /// The BoundType determines the inclusivity of the constraining element in a set.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub enum BoundType {
     /// An Open BoundType excludes the limit element from the `Set`.
     Open,
@@ -125,6 +133,7 @@ impl BoundType {
     feature = "serde",
     serde(bound(deserialize = "T: Element + serde::Deserialize<'de>"))
 )]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
 pub struct FiniteBound<T>(BoundType, T);
 
 /// Wire-format mirror of [`FiniteBound`] used to drive validation
@@ -143,6 +152,22 @@ impl<T: Element> TryFrom<RawFiniteBound<T>> for FiniteBound<T> {
     }
 }
 
+/// Validates through [`FiniteBound::try_new`], as serde does.
+#[cfg(feature = "rkyv")]
+impl<T, D> rkyv::Deserialize<FiniteBound<T>, D> for ArchivedFiniteBound<T>
+where
+    T: Element + rkyv::Archive,
+    T::Archived: rkyv::Deserialize<T, D>,
+    D: rkyv::rancor::Fallible + ?Sized,
+    D::Error: rkyv::rancor::Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<FiniteBound<T>, D::Error> {
+        let bound_type = rkyv::Deserialize::deserialize(&self.0, deserializer)?;
+        let limit = rkyv::Deserialize::deserialize(&self.1, deserializer)?;
+        FiniteBound::try_new(bound_type, limit).map_err(rkyv::rancor::Source::new)
+    }
+}
+
 impl<T> FiniteBound<T> {
     /// Tier 4 bypass: construct a `FiniteBound` without running
     /// `Element::validate`. Caller asserts that `limit` is a valid
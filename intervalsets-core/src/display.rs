@@ -1,119 +1,314 @@
+//! `Display` for the interval types, and [`Style`]s for other notations.
+//!
+//! The plain `Display` impls write [`Style::DISPLAY`]: `[0, 10)`,
+//! `(.., 5]`, `{[0, 5] U [10, 15]}`. [`DisplayWith::display_with`]
+//! writes any other style, and each style's text is read back by the
+//! parser under [`Style::notation`]. Precision, as in `{:.2}`, applies
+//! to every element.
+//!
+//! ```
+//! use intervalsets_core::prelude::*;
+//! use intervalsets_core::display::{DisplayWith, Style};
+//!
+//! let x = MaybeDisjoint::from_pair(
+//!     EnumInterval::closed_open(0.0, 10.0),
+//!     EnumInterval::open_unbound(20.0),
+//! );
+//! assert_eq!(x.display_with(Style::LATEX).to_string(), r"[0, 10) \cup (20, \infty)");
+//! assert_eq!(format!("{:.1}", x.display_with(Style::UNICODE)), "[0.0, 10.0) ∪ (20.0, ∞)");
+//! assert_eq!(format!("{}", x.display_with(Style::ISO)), "{[0, 10[ U ]20, ..[}");
+//! let y = EnumInterval::closed_open(0.0, 10.5);
+//! assert_eq!(y.display_with(Style::RUST).to_string(), "0..10.5");
+//! ```
+
 use core::fmt::{self, Write};
 
 use crate::bound::{BoundType, FiniteBound, Side};
+use crate::parse::Notation;
+use crate::quote::write_element;
 use crate::sets::{EnumInterval, FiniteInterval, HalfInterval, MaybeDisjoint};
 
-fn write_bound_type(
-    f: &mut fmt::Formatter<'_>,
-    side: Side,
-    bound_type: Option<BoundType>,
-) -> fmt::Result {
-    let delim = match bound_type.unwrap_or(BoundType::Open) {
-        BoundType::Closed => side.select('[', ']'),
-        BoundType::Open => side.select('(', ')'),
+/// The symbols a [`Style`] writes for unbounded sides, unions and the
+/// empty set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Symbols {
+    /// `..`, `{}`, and sets in braces joined by ` U `.
+    #[default]
+    Ascii,
+    /// `-∞` / `∞`, `∅`, and sets joined by ` ∪ `.
+    Unicode,
+    /// `-\infty` / `\infty`, `\emptyset`, and sets joined by ` \cup `.
+    Latex,
+}
+
+/// A notation to write intervals and sets in; see [`DisplayWith`].
+///
+/// | Style              | Example                         |
+/// |--------------------|---------------------------------|
+/// | [`Style::DISPLAY`] | `{[0, 10) U (20, ..)}`          |
+/// | [`Style::UNICODE`] | `[0, 10) ∪ (20, ∞)`             |
+/// | [`Style::LATEX`]   | `[0, 10) \cup (20, \infty)`     |
+/// | [`Style::ISO`]     | `{[0, 10[ U ]20, ..[}`          |
+/// | [`Style::RUST`]    | `{0..10 U (20, ..)}`            |
+///
+/// Rust ranges can't express an open left bound, so those intervals
+/// keep brackets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    symbols: Symbols,
+    iso_brackets: bool,
+    rust_ranges: bool,
+    semicolon: bool,
+}
+
+impl Style {
+    /// The `Display` notation.
+    pub const DISPLAY: Self = Self {
+        symbols: Symbols::Ascii,
+        iso_brackets: false,
+        rust_ranges: false,
+        semicolon: false,
     };
 
-    f.write_char(delim)
+    /// Unicode `∞`, `∪` and `∅`.
+    pub const UNICODE: Self = Self::DISPLAY.with_symbols(Symbols::Unicode);
+
+    /// LaTeX math mode.
+    pub const LATEX: Self = Self::DISPLAY.with_symbols(Symbols::Latex);
+
+    /// ISO 31-11 reversed brackets for open bounds.
+    pub const ISO: Self = Self::DISPLAY.with_iso_brackets(true);
+
+    /// Rust range syntax where it applies.
+    pub const RUST: Self = Self::DISPLAY.with_rust_ranges(true);
+
+    /// Write `symbols`.
+    pub const fn with_symbols(self, symbols: Symbols) -> Self {
+        Self { symbols, ..self }
+    }
+
+    /// Write ISO 31-11 reversed brackets for open bounds.
+    pub const fn with_iso_brackets(self, on: bool) -> Self {
+        Self {
+            iso_brackets: on,
+            ..self
+        }
+    }
+
+    /// Write left-closed intervals as Rust ranges.
+    pub const fn with_rust_ranges(self, on: bool) -> Self {
+        Self {
+            rust_ranges: on,
+            ..self
+        }
+    }
+
+    /// Write `;` between bounds.
+    pub const fn with_semicolon(self, on: bool) -> Self {
+        Self {
+            semicolon: on,
+            ..self
+        }
+    }
+
+    /// The parser dialect that reads this style back.
+    pub const fn notation(&self) -> Notation {
+        Notation::DISPLAY
+            .with_iso_brackets(self.iso_brackets)
+            .with_rust_ranges(self.rust_ranges)
+            .with_semicolon(self.semicolon)
+            .with_unicode_infinity(matches!(self.symbols, Symbols::Unicode))
+            .with_unicode_sets(matches!(self.symbols, Symbols::Unicode))
+            .with_latex(matches!(self.symbols, Symbols::Latex))
+    }
+
+    fn unbounded(&self, side: Side) -> &'static str {
+        match self.symbols {
+            Symbols::Ascii => "..",
+            Symbols::Unicode => side.select("-∞", "∞"),
+            Symbols::Latex => side.select("-\\infty", "\\infty"),
+        }
+    }
+
+    fn empty(&self) -> &'static str {
+        match self.symbols {
+            Symbols::Ascii => "{}",
+            Symbols::Unicode => "∅",
+            Symbols::Latex => "\\emptyset",
+        }
+    }
+
+    fn union(&self) -> &'static str {
+        match self.symbols {
+            Symbols::Ascii => " U ",
+            Symbols::Unicode => " ∪ ",
+            Symbols::Latex => " \\cup ",
+        }
+    }
+
+    fn delimiter(&self, side: Side, bound_type: BoundType) -> char {
+        match (bound_type, self.iso_brackets) {
+            (BoundType::Closed, _) => side.select('[', ']'),
+            (BoundType::Open, false) => side.select('(', ')'),
+            (BoundType::Open, true) => side.select(']', '['),
+        }
+    }
+}
+
+/// Formatting in a chosen [`Style`].
+///
+/// `Display` on the same types is `fmt_with` in [`Style::DISPLAY`].
+pub trait DisplayWith {
+    /// Writes `self` to `f` in `style`.
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, style: &Style) -> fmt::Result;
+
+    /// A `Display` adapter writing `self` in `style`.
+    fn display_with(&self, style: Style) -> Styled<'_, Self> {
+        Styled { value: self, style }
+    }
+}
+
+/// `Display` adapter returned by [`DisplayWith::display_with`].
+#[derive(Debug, Clone, Copy)]
+pub struct Styled<'a, D: ?Sized> {
+    value: &'a D,
+    style: Style,
+}
+
+impl<D: DisplayWith + ?Sized> fmt::Display for Styled<'_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_with(f, &self.style)
+    }
 }
 
-fn write_bound<T>(
+/// Writes an interval from its bounds; `None` is an unbounded side.
+fn write_bounds<T: fmt::Display>(
     f: &mut fmt::Formatter<'_>,
-    side: Side,
-    bound: Option<&FiniteBound<T>>,
-) -> fmt::Result
-where
-    T: fmt::Display,
-{
-    match side {
-        Side::Left => {
-            write_bound_type(f, side, bound.map(|x| x.bound_type()))?;
-            match bound {
-                None => f.write_str("..")?,
-                Some(inner) => f.write_fmt(format_args!("{}", inner.value()))?,
-            }
+    style: &Style,
+    lhs: Option<&FiniteBound<T>>,
+    rhs: Option<&FiniteBound<T>>,
+) -> fmt::Result {
+    let notation = style.notation();
+    let left_open = lhs.is_some_and(|l| l.bound_type() == BoundType::Open);
+    if style.rust_ranges && !left_open {
+        if let Some(l) = lhs {
+            write_element(f, l.value(), &notation)?;
         }
-        Side::Right => {
-            match bound {
-                None => f.write_str("..")?,
-                Some(inner) => f.write_fmt(format_args!("{}", inner.value()))?,
+        f.write_str("..")?;
+        if let Some(r) = rhs {
+            if r.bound_type() == BoundType::Closed {
+                f.write_char('=')?;
             }
-            write_bound_type(f, side, bound.map(|x| x.bound_type()))?;
+            write_element(f, r.value(), &notation)?;
         }
+        return Ok(());
     }
 
+    let bound_type = |b: Option<&FiniteBound<T>>| b.map_or(BoundType::Open, |b| b.bound_type());
+    f.write_char(style.delimiter(Side::Left, bound_type(lhs)))?;
+    match lhs {
+        None => f.write_str(style.unbounded(Side::Left))?,
+        Some(l) => write_element(f, l.value(), &notation)?,
+    }
+    f.write_str(if style.semicolon { "; " } else { ", " })?;
+    match rhs {
+        None => f.write_str(style.unbounded(Side::Right))?,
+        Some(r) => write_element(f, r.value(), &notation)?,
+    }
+    f.write_char(style.delimiter(Side::Right, bound_type(rhs)))
+}
+
+/// Writes `pieces` as a set in `style`: the empty-set symbol for no
+/// pieces, else the pieces joined by unions, in braces for
+/// [`Symbols::Ascii`].
+#[doc(hidden)]
+pub fn write_set<'a, D, I>(f: &mut fmt::Formatter<'_>, style: &Style, pieces: I) -> fmt::Result
+where
+    D: DisplayWith + 'a,
+    I: IntoIterator<Item = &'a D>,
+{
+    let mut pieces = pieces.into_iter().peekable();
+    if pieces.peek().is_none() {
+        return f.write_str(style.empty());
+    }
+    let braces = style.symbols == Symbols::Ascii;
+    if braces {
+        f.write_char('{')?;
+    }
+    let mut first = true;
+    for piece in pieces {
+        if !core::mem::take(&mut first) {
+            f.write_str(style.union())?;
+        }
+        piece.fmt_with(f, style)?;
+    }
+    if braces {
+        f.write_char('}')?;
+    }
     Ok(())
 }
 
-impl<T: fmt::Display> fmt::Display for FiniteInterval<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<T: fmt::Display> DisplayWith for FiniteInterval<T> {
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, style: &Style) -> fmt::Result {
         match self.view_raw() {
-            None => f.write_str("{}")?,
-            Some((lhs, rhs)) => {
-                write_bound(f, Side::Left, Some(lhs))?;
-                f.write_str(", ")?;
-                write_bound(f, Side::Right, Some(rhs))?;
-            }
+            None => f.write_str(style.empty()),
+            Some((lhs, rhs)) => write_bounds(f, style, Some(lhs), Some(rhs)),
         }
-
-        Ok(())
     }
 }
 
-impl<T: fmt::Display> fmt::Display for HalfInterval<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<T: fmt::Display> DisplayWith for HalfInterval<T> {
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, style: &Style) -> fmt::Result {
+        let bound = Some(self.finite_bound());
         match self.side() {
-            Side::Left => {
-                write_bound(f, Side::Left, Some(self.finite_bound()))?;
-                f.write_str(", ")?;
-                write_bound::<T>(f, Side::Right, None)?;
-            }
-            Side::Right => {
-                write_bound::<T>(f, Side::Left, None)?;
-                f.write_str(", ")?;
-                write_bound(f, Side::Right, Some(self.finite_bound()))?;
-            }
+            Side::Left => write_bounds(f, style, bound, None),
+            Side::Right => write_bounds(f, style, None, bound),
         }
-
-        Ok(())
     }
 }
 
-impl<T: fmt::Display> fmt::Display for EnumInterval<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<T: fmt::Display> DisplayWith for EnumInterval<T> {
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, style: &Style) -> fmt::Result {
         match self {
-            Self::Unbounded => f.write_str("(.., ..)"),
-            Self::Finite(inner) => inner.fmt(f),
-            Self::Half(inner) => inner.fmt(f),
+            Self::Unbounded => write_bounds::<T>(f, style, None, None),
+            Self::Finite(inner) => inner.fmt_with(f, style),
+            Self::Half(inner) => inner.fmt_with(f, style),
         }
     }
 }
 
-impl<T: fmt::Display> fmt::Display for MaybeDisjoint<T> {
-    /// `Connected(iv)` delegates to the inner `EnumInterval`'s
-    /// `Display`. `Disjoint(a, b)` wraps both pieces in set-notation
-    /// braces with a ` U ` (union) separator, e.g. `{[0, 5] U [10, 15]}`.
-    /// Empty MD prints as `{}` (inherited from the empty `FiniteInterval`
-    /// display).
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<T: fmt::Display> DisplayWith for MaybeDisjoint<T> {
+    /// `Connected(iv)` writes the interval alone; `Disjoint(a, b)` writes
+    /// a two-piece set, e.g. `{[0, 5] U [10, 15]}`.
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, style: &Style) -> fmt::Result {
         match self {
-            Self::Connected(iv) => iv.fmt(f),
-            Self::Disjoint(a, b) => {
-                f.write_char('{')?;
-                a.fmt(f)?;
-                f.write_str(" U ")?;
-                b.fmt(f)?;
-                f.write_char('}')
-            }
+            Self::Connected(iv) => iv.fmt_with(f, style),
+            Self::Disjoint(a, b) => write_set(f, style, [a, b]),
         }
     }
 }
 
+macro_rules! display_via_style {
+    ($($t:ident),+) => {
+        $(
+            impl<T: fmt::Display> fmt::Display for $t<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.fmt_with(f, &Style::DISPLAY)
+                }
+            }
+        )+
+    };
+}
+
+display_via_style!(FiniteInterval, HalfInterval, EnumInterval, MaybeDisjoint);
+
 #[cfg(test)]
 mod tests {
     extern crate std;
 
     use super::*;
-    use crate::factory::{FiniteFactory, HalfBoundedFactory, UnboundedFactory};
+    use crate::factory::{FiniteFactory, HalfBoundedFactory, TryFiniteFactory, UnboundedFactory};
+    use crate::parse::FromNotation;
 
     #[test]
     fn test_display_finite() {
@@ -197,4 +392,84 @@ mod tests {
         );
         assert_eq!(std::format!("{}", md), "{(.., 0] U [10, ..)}");
     }
+
+    const STYLES: [Style; 7] = [
+        Style::DISPLAY,
+        Style::UNICODE,
+        Style::LATEX,
+        Style::ISO,
+        Style::RUST,
+        Style::DISPLAY.with_semicolon(true),
+        Style::UNICODE
+            .with_iso_brackets(true)
+            .with_rust_ranges(true),
+    ];
+
+    fn styled<D: DisplayWith>(x: &D, style: Style) -> std::string::String {
+        std::format!("{}", x.display_with(style))
+    }
+
+    #[test]
+    fn test_styles() {
+        let x = MaybeDisjoint::from_pair(
+            EnumInterval::unbound_closed(-1.5),
+            EnumInterval::open_unbound(2.0),
+        );
+        assert_eq!(styled(&x, Style::UNICODE), "(-∞, -1.5] ∪ (2, ∞)");
+        assert_eq!(
+            styled(&x, Style::LATEX),
+            "(-\\infty, -1.5] \\cup (2, \\infty)"
+        );
+        assert_eq!(styled(&x, Style::ISO), "{].., -1.5] U ]2, ..[}");
+        assert_eq!(styled(&x, Style::RUST), "{..=-1.5 U (2, ..)}");
+
+        let e = EnumInterval::<i32>::empty();
+        assert_eq!(styled(&e, Style::UNICODE), "∅");
+        assert_eq!(styled(&e, Style::LATEX), "\\emptyset");
+        assert_eq!(styled(&EnumInterval::<i32>::unbounded(), Style::RUST), "..");
+        assert_eq!(
+            styled(
+                &EnumInterval::closed(0, 10),
+                Style::DISPLAY.with_semicolon(true)
+            ),
+            "[0; 10]"
+        );
+    }
+
+    #[test]
+    fn test_precision_reaches_elements() {
+        let x = EnumInterval::closed_open(0.0, 1.0 / 3.0);
+        assert_eq!(std::format!("{x:.2}"), "[0.00, 0.33)");
+        assert_eq!(
+            std::format!("{:.1}", x.display_with(Style::RUST)),
+            "0.0..0.3"
+        );
+        let x =
+            MaybeDisjoint::from_pair(EnumInterval::singleton(0.5), EnumInterval::singleton(2.25));
+        assert_eq!(std::format!("{x:.0}"), "{[0, 0] U [2, 2]}");
+    }
+
+    fn piece(kind: u8, a: i32, b: i32) -> EnumInterval<i32> {
+        let (lo, hi) = (a.min(b), a.max(b));
+        match kind % 8 {
+            0 => EnumInterval::closed(lo, hi),
+            1 => EnumInterval::closed_unbound(lo),
+            2 => EnumInterval::unbound_open(hi),
+            3 => EnumInterval::unbound_closed(hi),
+            4 => EnumInterval::open_unbound(lo),
+            5 => EnumInterval::unbounded(),
+            6 => EnumInterval::empty(),
+            _ => EnumInterval::try_open(lo, hi).unwrap_or_default(),
+        }
+    }
+
+    #[quickcheck]
+    fn check_styles_round_trip(k1: u8, a: i32, b: i32, k2: u8, c: i32, d: i32) {
+        let x = MaybeDisjoint::new(Some(piece(k1, a, b)), Some(piece(k2, c, d)));
+        for style in STYLES {
+            let text = styled(&x, style);
+            let parsed = MaybeDisjoint::<i32>::from_notation(&text, &style.notation());
+            assert_eq!(parsed, Ok(x.clone()), "{text}");
+        }
+    }
 }
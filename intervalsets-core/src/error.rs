@@ -1,4 +1,8 @@
 use core::convert::Infallible;
+use core::fmt;
+use core::ops::Range;
+
+use crate::bound::Side;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, ::thiserror::Error)]
 #[non_exhaustive]
@@ -124,16 +128,16 @@ pub struct TotalOrderError;
 /// `E` is the element type's `FromStr::Err`, kept generic so the
 /// element error is preserved verbatim — no allocation, no erasure.
 ///
-/// The two `Invalid*` variants mirror the corresponding [`Error`]
-/// variants so a [`From<Error>`] lift is lossless for the cases the
-/// parser can encounter.
+/// The `Invalid*` and `Math` variants mirror the corresponding
+/// [`Error`] variants so a [`From<Error>`] lift is lossless.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, ::thiserror::Error)]
 #[non_exhaustive]
 pub enum ParseIntervalError<E> {
     /// Top-level grammar didn't match — bad delimiters, missing
-    /// separator, unbounded marker on the wrong side, etc.
-    #[error("malformed interval syntax")]
-    Syntax,
+    /// separator, unbounded marker on the wrong side, etc. Carries
+    /// where in the input, and what was expected there.
+    #[error("malformed interval syntax: {0}")]
+    Syntax(SyntaxError),
 
     /// A bound's value text was rejected by `T::from_str`.
     #[error("element parse error: {0}")]
@@ -149,6 +153,128 @@ pub enum ParseIntervalError<E> {
     /// rejected (crossed bounds). Mirrors [`Error::InvalidBoundPair`].
     #[error("interval bound pair invalid (crossed bounds)")]
     InvalidBoundPair,
+
+    /// Arithmetic on a bound failed. The built-in parsers only call
+    /// factory `try_*` constructors and never produce this; it mirrors
+    /// [`Error::Math`] for parsers that compute their bounds.
+    #[error(transparent)]
+    Math(MathError),
+}
+
+impl<E> ParseIntervalError<E> {
+    /// The byte span of a [`Syntax`](Self::Syntax) error in the parsed
+    /// string; `None` for the other variants.
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            Self::Syntax(e) => Some(e.span()),
+            _ => None,
+        }
+    }
+
+    /// Shifts a syntax error's span `by` bytes, for text parsed out of
+    /// a larger string.
+    pub fn offset(self, by: usize) -> Self {
+        match self {
+            Self::Syntax(e) => Self::Syntax(SyntaxError {
+                start: e.start + by,
+                end: e.end + by,
+                ..e
+            }),
+            other => other,
+        }
+    }
+}
+
+/// Location and cause of a [`ParseIntervalError::Syntax`].
+///
+/// Offsets are bytes into the string handed to the parser, so
+/// `&input[e.span()]` is the offending text. Displays as, e.g.,
+/// `expected ',' between bounds at 11..16`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SyntaxError {
+    start: usize,
+    end: usize,
+    expected: Expected,
+}
+
+impl SyntaxError {
+    /// Creates a syntax error over the byte range `span`.
+    pub fn new(expected: Expected, span: Range<usize>) -> Self {
+        Self {
+            start: span.start,
+            end: span.end,
+            expected,
+        }
+    }
+
+    /// The byte range of the offending text; empty where something is
+    /// missing.
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// What the parser expected at [`span`](Self::span).
+    pub fn expected(&self) -> Expected {
+        self.expected
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.end > self.start + 1 {
+            write!(f, "{} at {}..{}", self.expected, self.start, self.end)
+        } else {
+            write!(f, "{} at {}", self.expected, self.start)
+        }
+    }
+}
+
+/// What the interval parser expected where it failed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Expected {
+    /// An opening delimiter, `[` or `(`.
+    OpenDelimiter,
+    /// A closing delimiter, `]` or `)`.
+    CloseDelimiter,
+    /// The separator between two bounds.
+    Separator,
+    /// An open delimiter on an unbounded side; infinity is never
+    /// included.
+    OpenOnUnboundedSide(Side),
+    /// A single interval rather than set notation.
+    Interval,
+    /// A fully bounded or empty interval.
+    FiniteInterval,
+    /// An interval bounded on exactly one side.
+    HalfInterval,
+    /// A set wrapped in `{` and `}`.
+    SetBraces,
+    /// An interval between `U` separators.
+    Piece,
+    /// No more than two pieces, for a `MaybeDisjoint`.
+    AtMostTwoPieces,
+    /// A bound after `..=` in a Rust range.
+    RangeEnd,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::OpenDelimiter => "expected '[' or '('",
+            Self::CloseDelimiter => "expected ']' or ')'",
+            Self::Separator => "expected ',' between bounds",
+            Self::OpenOnUnboundedSide(Side::Left) => "unbounded side must use '('",
+            Self::OpenOnUnboundedSide(Side::Right) => "unbounded side must use ')'",
+            Self::Interval => "expected an interval, found set notation",
+            Self::FiniteInterval => "expected a bounded interval",
+            Self::HalfInterval => "expected a half-bounded interval",
+            Self::SetBraces => "expected '{' and '}' around a set",
+            Self::Piece => "expected an interval between 'U' separators",
+            Self::AtMostTwoPieces => "expected at most two pieces",
+            Self::RangeEnd => "expected a bound after '..='",
+        })
+    }
 }
 
 impl<E> From<Error> for ParseIntervalError<E> {
@@ -156,11 +282,7 @@ impl<E> From<Error> for ParseIntervalError<E> {
         match e {
             Error::InvalidBoundPair => ParseIntervalError::InvalidBoundPair,
             Error::InvalidElement => ParseIntervalError::InvalidElement,
-            // The parser only invokes factory `try_*` constructors,
-            // which can't surface `Math` errors. A future additive
-            // variant of `Error` lands here as a syntax-shaped failure
-            // until this match is extended.
-            _ => ParseIntervalError::Syntax,
+            Error::Math(e) => ParseIntervalError::Math(e),
         }
     }
 }
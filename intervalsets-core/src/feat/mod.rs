@@ -16,11 +16,11 @@ mod bigint;
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal;
 
-#[cfg(feature = "serde")]
-mod serde;
-
 #[cfg(any(feature = "quickcheck", test))]
 mod quickcheck;
 
 #[cfg(feature = "fixed")]
 mod fixed;
+
+#[cfg(feature = "rand")]
+mod rand;
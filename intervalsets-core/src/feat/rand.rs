@@ -0,0 +1,187 @@
+//! [`SampleElement`] impls for the optional storage types.
+//!
+//! Primitive impls live next to the trait in `numeric`; this module
+//! only covers types that need their own storage feature as well.
+
+#[cfg(feature = "rust_decimal")]
+mod decimal {
+    use rand::Rng;
+    use rust_decimal::Decimal;
+
+    use crate::bound::FiniteBound;
+    use crate::numeric::SampleElement;
+
+    /// Digits of resolution added below the finer bound's scale.
+    /// Stored bounds are normalized (see `Element::validate`), so
+    /// without this `[1, 2]` would only ever yield `1` or `2`.
+    const EXTRA_DIGITS: u32 = 9;
+
+    /// Rejection attempts for open bounds before falling back to the
+    /// midpoint. With the extra digits an open limit is hit with
+    /// probability about `2e-9` per draw, so this is rarely reached.
+    const OPEN_RETRIES: usize = 32;
+
+    /// Samples uniformly over the grid at [`EXTRA_DIGITS`] below the
+    /// finer of the two bounds' scales (capped at `Decimal`'s maximum
+    /// of 28), via `rust_decimal`'s own `SampleUniform`. Open limits
+    /// are rejected and redrawn.
+    impl SampleElement for Decimal {
+        fn sample_between<R: Rng + ?Sized>(
+            lhs: &FiniteBound<Self>,
+            rhs: &FiniteBound<Self>,
+            rng: &mut R,
+        ) -> Option<Self> {
+            let (lo, hi) = (*lhs.value(), *rhs.value());
+            let inside =
+                |x: &Decimal| (lhs.is_closed() || *x != lo) && (rhs.is_closed() || *x != hi);
+            if lo == hi {
+                return inside(&lo).then_some(lo);
+            }
+            // `rescale` keeps the value and stops early if the mantissa
+            // would overflow; the sampler syncs `hi` to the same scale.
+            let mut fine = lo;
+            fine.rescale((lo.scale().max(hi.scale()) + EXTRA_DIGITS).min(28));
+            for _ in 0..OPEN_RETRIES {
+                let x = rng.gen_range(fine..=hi).normalize();
+                if inside(&x) {
+                    return Some(x);
+                }
+            }
+            // No room at the finest scale; the midpoint lies strictly
+            // inside when representable.
+            let mid = (lo / Decimal::TWO).checked_add(hi / Decimal::TWO)?;
+            (lo < mid && mid < hi).then_some(mid.normalize())
+        }
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+mod ordfloat {
+    use ordered_float::{NotNan, OrderedFloat};
+    use rand::Rng;
+
+    use crate::bound::FiniteBound;
+    use crate::numeric::{FloatSample, SampleElement};
+
+    macro_rules! ordered_float_sample_impl {
+        ($($t:ty), +) => {
+            $(
+                impl SampleElement for OrderedFloat<$t> {
+                    fn sample_between<R: Rng + ?Sized>(
+                        lhs: &FiniteBound<Self>,
+                        rhs: &FiniteBound<Self>,
+                        rng: &mut R,
+                    ) -> Option<Self> {
+                        <$t>::sample_float(lhs.is_open(), lhs.value().0, rhs.is_open(), rhs.value().0, rng)
+                            .map(OrderedFloat)
+                    }
+                }
+
+                impl SampleElement for NotNan<$t> {
+                    fn sample_between<R: Rng + ?Sized>(
+                        lhs: &FiniteBound<Self>,
+                        rhs: &FiniteBound<Self>,
+                        rng: &mut R,
+                    ) -> Option<Self> {
+                        <$t>::sample_float(lhs.is_open(), **lhs.value(), rhs.is_open(), **rhs.value(), rng)
+                            .and_then(|x| NotNan::new(x).ok())
+                    }
+                }
+            )+
+        }
+    }
+
+    ordered_float_sample_impl!(f32, f64);
+}
+
+#[cfg(feature = "fixed")]
+mod fixed_point {
+    use rand::Rng;
+
+    use crate::bound::FiniteBound;
+    use crate::numeric::SampleElement;
+
+    /// Fixed-point values are discrete over their bit patterns, so
+    /// sampling the bits is exactly uniform by cardinality.
+    macro_rules! fixed_sample_impl {
+        ($($t:ty,) +) => {
+            $(
+                impl<N: typenum::Unsigned> SampleElement for $t {
+                    fn sample_between<R: Rng + ?Sized>(
+                        lhs: &FiniteBound<Self>,
+                        rhs: &FiniteBound<Self>,
+                        rng: &mut R,
+                    ) -> Option<Self> {
+                        let lo = lhs.value().to_bits();
+                        let hi = rhs.value().to_bits();
+                        let lo = if lhs.is_open() { lo.checked_add(1)? } else { lo };
+                        let hi = if rhs.is_open() { hi.checked_sub(1)? } else { hi };
+                        (lo <= hi).then(|| Self::from_bits(rng.gen_range(lo..=hi)))
+                    }
+                }
+            )+
+        }
+    }
+
+    fixed_sample_impl!(
+        fixed::FixedI8<N>,
+        fixed::FixedU8<N>,
+        fixed::FixedI16<N>,
+        fixed::FixedU16<N>,
+        fixed::FixedI32<N>,
+        fixed::FixedU32<N>,
+        fixed::FixedI64<N>,
+        fixed::FixedU64<N>,
+        fixed::FixedI128<N>,
+        fixed::FixedU128<N>,
+    );
+}
+
+#[cfg(all(test, any(feature = "rust_decimal", feature = "fixed")))]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::bound::{BoundType, FiniteBound};
+    use crate::numeric::SampleElement;
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn decimal_open_bounds() {
+        use rust_decimal::Decimal;
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let lo = FiniteBound::new(BoundType::Open, Decimal::new(1, 0));
+        let hi = FiniteBound::new(BoundType::Open, Decimal::new(2, 0));
+        for _ in 0..200 {
+            let x = Decimal::sample_between(&lo, &hi, &mut rng).unwrap();
+            assert!(Decimal::ONE < x && x < Decimal::TWO, "{x}");
+            assert!(x.scale() > 0, "{x}");
+        }
+
+        let one = FiniteBound::new(BoundType::Closed, Decimal::ONE);
+        assert_eq!(
+            Decimal::sample_between(&one, &one, &mut rng),
+            Some(Decimal::ONE)
+        );
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn fixed_counts_bit_patterns() {
+        use fixed::types::I8F8;
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let lo = FiniteBound::new(BoundType::Open, I8F8::from_num(1));
+        let hi = FiniteBound::new(
+            BoundType::Closed,
+            I8F8::from_bits(I8F8::from_num(1).to_bits() + 2),
+        );
+        let mut seen = [0usize; 2];
+        for _ in 0..1000 {
+            let x = I8F8::sample_between(&lo, &hi, &mut rng).unwrap();
+            seen[(x.to_bits() - lo.value().to_bits() - 1) as usize] += 1;
+        }
+        assert!(seen.iter().all(|&n| n > 400), "{seen:?}");
+    }
+}
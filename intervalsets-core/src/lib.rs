@@ -344,6 +344,11 @@
 //! * bigdecimal: arbitrary precision total ordered decimals
 //! * num-bigint: arbitrary sized integers
 //!
+//! ## sampling
+//! * rand: uniform sampling between finite bounds via
+//!   [`SampleElement`](numeric::SampleElement), for the primitive types
+//!   and any enabled storage type.
+//!
 //! ## serialization
 //! * serde: implement [`Serialize`](::serde::Serialize), [`Deserialize`](::serde::Deserialize).
 //!   `Deserialize` requires `T: Element` and rejects NaN, swapped-order
@@ -351,7 +356,12 @@
 //!   ([`FiniteInterval`],
 //!   [`HalfInterval`],
 //!   [`EnumInterval`]). The `bound::ord::*` helper
-//!   types do not derive serde traits.
+//!   types do not derive serde traits. [`serde`](crate::serde) has
+//!   adapters for string and compact tuple encodings.
+//! * rkyv: derive rkyv's `Archive` and `Serialize` for the bounds and
+//!   interval types. `Deserialize` goes through the same validating
+//!   constructors as serde, so a bound or interval that breaks the
+//!   invariants fails to deserialize.
 //!
 //! # Diving Deeper
 //! * [Implement custom storage data types](numeric)
@@ -419,9 +429,12 @@ pub mod category;
 pub mod measure;
 pub mod try_cmp;
 
-mod display;
+pub mod display;
 mod from;
-mod parse;
+pub mod parse;
+mod quote;
+#[cfg(feature = "serde")]
+pub mod serde;
 
 mod empty;
 pub use empty::MaybeEmpty;
@@ -0,0 +1,490 @@
+use super::{Extent, Measure};
+use crate::bound::{FiniteBound, SetBounds, Side};
+use crate::error::MathError;
+use crate::factory::TrySatisfyFiniteInterval;
+use crate::numeric::{DiscreteElement, Element, Zero};
+use crate::ops::math::{TryAdd, TryMul, TrySub};
+use crate::sets::{EnumInterval, FiniteInterval, HalfInterval, MaybeDisjoint};
+use crate::MaybeEmpty;
+
+/// A user-defined additive measure, evaluated one interval at a time.
+///
+/// Implemented by [`Cdf`], [`Density`] and [`PointMass`]. Sets apply it
+/// through [`MeasureWith`], which sums over disjoint pieces.
+///
+/// # Contract
+///
+/// [`try_measure_bounds`](Self::try_measure_bounds) is only called for
+/// inhabited intervals, with bounds in canonical form (closed on
+/// discrete `T`). It follows the three-state contract of [`Measure`]:
+/// `Infinite` for an infinite measure, `Err` when the value cannot be
+/// computed or represented.
+pub trait CustomMeasure<T> {
+    /// The measure value type.
+    type Output: Zero + TryAdd<Output = Self::Output, Error: Into<MathError>>;
+
+    /// The measure of the interval between `left` and `right`, where
+    /// `None` is an unbounded side.
+    fn try_measure_bounds(
+        &self,
+        left: Option<&FiniteBound<T>>,
+        right: Option<&FiniteBound<T>>,
+    ) -> Result<Extent<Self::Output>, MathError>;
+}
+
+/// A measure given by its cumulative distribution function.
+///
+/// `at_or_below(x)` is `μ((-∞, x])` up to a constant, and `below(x)` is
+/// `μ((-∞, x))`. They differ only at atoms, so an atomless measure
+/// passes the same function twice via [`Cdf::new`]. An interval's
+/// measure is the difference of the two values at its bounds, picked
+/// by bound type: `μ([a, b)) = below(b) - below(a)`.
+///
+/// Unbounded sides are infinite unless [`with_limits`](Self::with_limits)
+/// supplies the values at `-∞` and `+∞`, as for a probability
+/// distribution.
+///
+/// ```
+/// use intervalsets_core::prelude::*;
+/// use intervalsets_core::measure::{Cdf, Extent, MeasureWith};
+///
+/// // Uniform probability on [0, 4].
+/// let cdf = |x: &f64| x.clamp(0.0, 4.0) / 4.0;
+/// let p = Cdf::new(cdf).with_limits(0.0, 1.0);
+/// assert_eq!(EnumInterval::closed(1.0, 2.0).measure_with(&p), Extent::Finite(0.25));
+/// assert_eq!(EnumInterval::closed_unbound(3.0).measure_with(&p), Extent::Finite(0.25));
+///
+/// // Without limits an unbounded side is infinite.
+/// let q = Cdf::new(cdf);
+/// assert_eq!(EnumInterval::closed_unbound(3.0).measure_with(&q), Extent::Infinite);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cdf<V, F, G> {
+    at_or_below: F,
+    below: G,
+    limits: Option<(V, V)>,
+}
+
+impl<V, F: Clone> Cdf<V, F, F> {
+    /// An atomless measure with cumulative function `f`.
+    pub fn new(f: F) -> Self {
+        Self::with_atoms(f.clone(), f)
+    }
+}
+
+impl<V, F, G> Cdf<V, F, G> {
+    /// A measure with atoms: `at_or_below(x) - below(x)` is the mass
+    /// of the point `x`.
+    pub fn with_atoms(at_or_below: F, below: G) -> Self {
+        Self {
+            at_or_below,
+            below,
+            limits: None,
+        }
+    }
+
+    /// Sets the limits of the cumulative function at `-∞` and `+∞`,
+    /// making unbounded sides finite.
+    pub fn with_limits(self, lower: V, upper: V) -> Self {
+        Self {
+            limits: Some((lower, upper)),
+            ..self
+        }
+    }
+}
+
+impl<T, V, F, G> CustomMeasure<T> for Cdf<V, F, G>
+where
+    V: Clone + Zero + TryAdd<Output = V, Error: Into<MathError>>,
+    V: TrySub<Output = V, Error: Into<MathError>>,
+    F: Fn(&T) -> V,
+    G: Fn(&T) -> V,
+{
+    type Output = V;
+
+    fn try_measure_bounds(
+        &self,
+        left: Option<&FiniteBound<T>>,
+        right: Option<&FiniteBound<T>>,
+    ) -> Result<Extent<V>, MathError> {
+        // An open left bound excludes the point, so the mass up to and
+        // including it is subtracted; a closed one keeps it.
+        let lo = match left {
+            Some(a) if a.is_open() => (self.at_or_below)(a.value()),
+            Some(a) => (self.below)(a.value()),
+            None => match &self.limits {
+                Some((lower, _)) => lower.clone(),
+                None => return Ok(Extent::Infinite),
+            },
+        };
+        let hi = match right {
+            Some(b) if b.is_open() => (self.below)(b.value()),
+            Some(b) => (self.at_or_below)(b.value()),
+            None => match &self.limits {
+                Some((_, upper)) => upper.clone(),
+                None => return Ok(Extent::Infinite),
+            },
+        };
+        hi.try_sub(lo).map(Extent::Finite).map_err(Into::into)
+    }
+}
+
+/// A piecewise-constant density over the natural [`Measure`].
+///
+/// `weights[i]` applies between `breakpoints[i - 1]` (inclusive) and
+/// `breakpoints[i]` (exclusive), with `-∞` and `+∞` at the ends. An
+/// interval's measure is the weighted sum of the natural measure of
+/// its overlap with each segment. An unbounded overlap is infinite
+/// unless its weight is zero.
+///
+/// ```
+/// use intervalsets_core::prelude::*;
+/// use intervalsets_core::measure::{Density, Extent, MeasureWith};
+///
+/// // Business hours: only 9..17 counts.
+/// let hours = Density::try_new(&[9.0, 17.0], &[0.0, 1.0, 0.0]).unwrap();
+/// assert_eq!(EnumInterval::closed(8.0, 10.0).measure_with(&hours), Extent::Finite(1.0));
+/// assert_eq!(EnumInterval::<f64>::unbounded().measure_with(&hours), Extent::Finite(8.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Density<'a, T, M> {
+    breakpoints: &'a [T],
+    weights: &'a [M],
+}
+
+impl<'a, T: PartialOrd, M> Density<'a, T, M> {
+    /// Creates a density from strictly increasing `breakpoints` and one
+    /// more weight than breakpoints.
+    ///
+    /// # Errors
+    ///
+    /// `MathError::Domain` if the lengths disagree or the breakpoints
+    /// are not strictly increasing.
+    pub fn try_new(breakpoints: &'a [T], weights: &'a [M]) -> Result<Self, MathError> {
+        let increasing = breakpoints.windows(2).all(|w| w[0] < w[1]);
+        if !increasing || weights.len() != breakpoints.len() + 1 {
+            return Err(MathError::Domain);
+        }
+        Ok(Self {
+            breakpoints,
+            weights,
+        })
+    }
+}
+
+impl<T, M> CustomMeasure<T> for Density<'_, T, M>
+where
+    T: Element + Clone,
+    T::Measure: Into<M>,
+    M: Clone + PartialEq + Zero + TryAdd<Output = M, Error: Into<MathError>>,
+    M: TryMul<Output = M, Error: Into<MathError>>,
+{
+    type Output = M;
+
+    fn try_measure_bounds(
+        &self,
+        left: Option<&FiniteBound<T>>,
+        right: Option<&FiniteBound<T>>,
+    ) -> Result<Extent<M>, MathError> {
+        let segment = |x: &FiniteBound<T>| self.breakpoints.partition_point(|b| b <= x.value());
+        let first = left.map_or(0, segment);
+        let last = right.map_or(self.breakpoints.len(), segment);
+
+        let mut total = Extent::Finite(M::zero());
+        for i in first..=last {
+            let weight = &self.weights[i];
+            if weight.is_zero() {
+                continue;
+            }
+            // Clip the interval to [breakpoints[i - 1], breakpoints[i]).
+            let lo = match (left, i.checked_sub(1).map(|j| &self.breakpoints[j])) {
+                (Some(a), Some(b)) => Some(FiniteBound::take_max_assume_valid(
+                    Side::Left,
+                    a.clone(),
+                    FiniteBound::try_closed(b.clone()).map_err(|_| MathError::Domain)?,
+                )),
+                (a, b) => a.cloned().or(b.map(|b| FiniteBound::closed(b.clone()))),
+            };
+            let hi = match (right, self.breakpoints.get(i)) {
+                (Some(a), Some(b)) => Some(FiniteBound::take_min_assume_valid(
+                    Side::Right,
+                    a.clone(),
+                    FiniteBound::try_open(b.clone()).map_err(|_| MathError::Domain)?,
+                )),
+                (a, b) => a.cloned().or(b.map(|b| FiniteBound::open(b.clone()))),
+            };
+            let part = match (lo, hi) {
+                (Some(lo), Some(hi)) => match FiniteInterval::try_satisfy_bounds(lo, hi) {
+                    Ok(overlap) => overlap.try_measure()?,
+                    Err(_) => return Err(MathError::Domain),
+                },
+                _ => Extent::Infinite,
+            };
+            let weighted = match part {
+                Extent::Finite(m) => {
+                    Extent::Finite(m.into().try_mul(weight.clone()).map_err(Into::into)?)
+                }
+                Extent::Infinite => Extent::Infinite,
+            };
+            total = total.try_binop_map(weighted, |a, b| a.try_add(b).map_err(Into::into))?;
+        }
+        Ok(total)
+    }
+}
+
+/// A measure on a discrete type given by the mass of each element.
+///
+/// An interval's measure is the sum of the masses of its members, so
+/// evaluation is linear in its cardinality. Unbounded intervals have
+/// infinitely many members and return `Err(MathError::Domain)`; use a
+/// [`Cdf`] with atoms for masses with unbounded support.
+///
+/// ```
+/// use intervalsets_core::prelude::*;
+/// use intervalsets_core::measure::{Extent, MeasureWith, PointMass};
+///
+/// let weekdays = PointMass::new(|day: &u32| u32::from(day % 7 < 5));
+/// assert_eq!(EnumInterval::closed(0, 13).measure_with(&weekdays), Extent::Finite(10));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointMass<F> {
+    mass: F,
+}
+
+impl<F> PointMass<F> {
+    /// A measure giving each element `x` the mass `mass(x)`.
+    pub fn new(mass: F) -> Self {
+        Self { mass }
+    }
+}
+
+impl<T, V, F> CustomMeasure<T> for PointMass<F>
+where
+    T: DiscreteElement + Clone,
+    V: Zero + TryAdd<Output = V, Error: Into<MathError>>,
+    F: Fn(&T) -> V,
+{
+    type Output = V;
+
+    fn try_measure_bounds(
+        &self,
+        left: Option<&FiniteBound<T>>,
+        right: Option<&FiniteBound<T>>,
+    ) -> Result<Extent<V>, MathError> {
+        let (Some(a), Some(b)) = (left, right) else {
+            return Err(MathError::Domain);
+        };
+        let mut total = V::zero();
+        let mut x = a.value().clone();
+        loop {
+            total = total.try_add((self.mass)(&x)).map_err(Into::into)?;
+            if x >= *b.value() {
+                return Ok(Extent::Finite(total));
+            }
+            x = x.try_adjacent(Side::Right).ok_or(MathError::Range)?;
+        }
+    }
+}
+
+/// Measure of a set under a user-defined [`CustomMeasure`].
+///
+/// The counterpart of [`Measure`] for measures other than the natural
+/// one. Sets are measured piece by piece and the pieces summed with
+/// [`TryAdd`]; the empty set measures zero. Bound types are passed
+/// through, so atoms at open or closed endpoints are counted exactly
+/// when the measure object distinguishes them.
+///
+/// # Three-state contract
+///
+/// As for [`Measure`]: `Ok(Finite)`, `Ok(Infinite)`, or `Err` when the
+/// measure object or the sum fails. `Infinite` from any piece
+/// propagates.
+pub trait MeasureWith<M> {
+    /// The measure value type.
+    type Output;
+    /// The error returned when the measure cannot be computed.
+    type Error: core::error::Error;
+
+    /// Compute the measure of this set under `measure`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_measure_with`](Self::try_measure_with) fails.
+    fn measure_with(&self, measure: &M) -> Extent<Self::Output> {
+        self.try_measure_with(measure)
+            .expect("MeasureWith::measure_with failed; use try_measure_with for panic-free")
+    }
+
+    /// Compute the measure of this set under `measure`.
+    fn try_measure_with(&self, measure: &M) -> Result<Extent<Self::Output>, Self::Error>;
+}
+
+/// Measures one interval, mapping the empty set to zero.
+pub(crate) fn measure_interval<T, S, M>(
+    set: &S,
+    measure: &M,
+) -> Result<Extent<M::Output>, MathError>
+where
+    S: SetBounds<T> + MaybeEmpty,
+    M: CustomMeasure<T>,
+{
+    if set.is_empty() {
+        return Ok(Extent::Finite(M::Output::zero()));
+    }
+    measure.try_measure_bounds(set.left(), set.right())
+}
+
+macro_rules! interval_measure_with {
+    ($($t:ident),*) => {
+        $(
+            impl<T, M: CustomMeasure<T>> MeasureWith<M> for $t<T> {
+                type Output = M::Output;
+                type Error = MathError;
+
+                fn try_measure_with(&self, measure: &M) -> Result<Extent<M::Output>, MathError> {
+                    measure_interval(self, measure)
+                }
+            }
+        )*
+    };
+}
+
+interval_measure_with!(FiniteInterval, HalfInterval, EnumInterval);
+
+impl<T, M: CustomMeasure<T>> MeasureWith<M> for MaybeDisjoint<T> {
+    type Output = M::Output;
+    type Error = MathError;
+
+    fn try_measure_with(&self, measure: &M) -> Result<Extent<M::Output>, MathError> {
+        match self {
+            Self::Connected(iv) => iv.try_measure_with(measure),
+            Self::Disjoint(a, b) => {
+                let am = a.try_measure_with(measure)?;
+                let bm = b.try_measure_with(measure)?;
+                am.try_binop_map(bm, |x, y| x.try_add(y).map_err(Into::into))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::{FiniteFactory, HalfBoundedFactory, UnboundedFactory};
+
+    #[test]
+    fn cdf_atoms_follow_bound_types() {
+        // A unit atom at every integer: the floor function counts them.
+        let at_or_below = |x: &f64| x.floor();
+        let below = |x: &f64| x.ceil() - 1.0;
+        let m = Cdf::with_atoms(at_or_below, below);
+        assert_eq!(
+            EnumInterval::closed(1.0, 3.0).measure_with(&m),
+            Extent::Finite(3.0)
+        );
+        assert_eq!(
+            EnumInterval::open(1.0, 3.0).measure_with(&m),
+            Extent::Finite(1.0)
+        );
+        assert_eq!(
+            EnumInterval::closed_open(1.0, 3.0).measure_with(&m),
+            Extent::Finite(2.0)
+        );
+        assert_eq!(
+            EnumInterval::open(0.5, 0.75).measure_with(&m),
+            Extent::Finite(0.0)
+        );
+        assert_eq!(
+            EnumInterval::closed_unbound(0.0).measure_with(&m),
+            Extent::Infinite
+        );
+    }
+
+    #[test]
+    fn empty_measures_zero() {
+        let m = Cdf::new(|x: &f64| *x);
+        assert_eq!(
+            FiniteInterval::empty().measure_with(&m),
+            Extent::Finite(0.0)
+        );
+        assert_eq!(MaybeDisjoint::empty().measure_with(&m), Extent::Finite(0.0));
+    }
+
+    #[test]
+    fn density_clips_to_segments() {
+        let d = Density::try_new(&[0, 10], &[2_u64, 3, 0]).unwrap();
+        // [-5, -1] at weight 2, [0, 4] at weight 3.
+        assert_eq!(
+            EnumInterval::closed(-5, 4).measure_with(&d),
+            Extent::Finite(10 + 15)
+        );
+        assert_eq!(
+            EnumInterval::closed(10, 20).measure_with(&d),
+            Extent::Finite(0)
+        );
+        assert_eq!(
+            EnumInterval::closed_unbound(0).measure_with(&d),
+            Extent::Finite(30)
+        );
+        assert_eq!(
+            EnumInterval::unbound_closed(0).measure_with(&d),
+            Extent::Infinite
+        );
+        assert_eq!(
+            Density::<i32, u64>::try_new(&[0, 10], &[1, 2]),
+            Err(MathError::Domain)
+        );
+        assert_eq!(
+            Density::<i32, u64>::try_new(&[10, 0], &[1, 2, 3]),
+            Err(MathError::Domain)
+        );
+    }
+
+    #[test]
+    fn density_open_bound_on_breakpoint() {
+        let d = Density::try_new(&[9.0, 17.0], &[0.0, 1.0, 5.0]).unwrap();
+        assert_eq!(
+            EnumInterval::closed_open(8.0, 9.0).measure_with(&d),
+            Extent::Finite(0.0)
+        );
+        assert_eq!(
+            EnumInterval::closed_open(10.0, 17.0).measure_with(&d),
+            Extent::Finite(7.0)
+        );
+        assert_eq!(
+            EnumInterval::closed(16.0, 18.0).measure_with(&d),
+            Extent::Finite(6.0)
+        );
+    }
+
+    #[test]
+    fn point_mass_sums_members() {
+        let m = PointMass::new(|x: &u8| u32::from(*x));
+        assert_eq!(
+            FiniteInterval::closed(1, 4).measure_with(&m),
+            Extent::Finite(10)
+        );
+        assert_eq!(
+            FiniteInterval::closed(255, 255).measure_with(&m),
+            Extent::Finite(255)
+        );
+        assert_eq!(
+            HalfInterval::closed_unbound(1).try_measure_with(&m),
+            Err(MathError::Domain)
+        );
+        let md = MaybeDisjoint::from_pair(EnumInterval::closed(0, 1), EnumInterval::closed(5, 6));
+        assert_eq!(md.measure_with(&m), Extent::Finite(12));
+    }
+
+    #[test]
+    fn counting_density_matches_measure() {
+        let d = Density::<i32, u64>::try_new(&[], &[1]).unwrap();
+        for x in [
+            EnumInterval::closed(-3, 7),
+            EnumInterval::empty(),
+            EnumInterval::unbounded(),
+        ] {
+            assert_eq!(x.try_measure_with(&d), x.try_measure());
+        }
+    }
+}
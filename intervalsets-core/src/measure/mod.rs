@@ -21,9 +21,14 @@
 //! For "diameter on any T" (`sup − inf` regardless of category), see
 //! [`Span`](crate::ops::Span) in the `ops/` module — Span is not a
 //! measure (it fails subadditivity on disjoint sets).
+//!
+//! Other measures — a CDF, a piecewise-constant density or point
+//! masses on a discrete type — are applied with [`MeasureWith`].
 
 mod extent;
 pub use extent::Extent;
+mod custom;
+pub use custom::{Cdf, CustomMeasure, Density, MeasureWith, PointMass};
 #[allow(clippy::module_inception)]
 mod measure;
 pub use measure::Measure;
@@ -166,6 +166,8 @@ pub use num_traits::{Bounded, CheckedSub, NumCast, ToPrimitive, Zero};
 
 mod element;
 mod midpoint;
+#[cfg(feature = "rand")]
+mod sample;
 mod saturating;
 
 pub use element::{
@@ -173,3 +175,7 @@ pub use element::{
     DiscreteKind, Element,
 };
 pub use midpoint::Midpointable;
+#[cfg(all(feature = "rand", feature = "ordered-float"))]
+pub(crate) use sample::FloatSample;
+#[cfg(feature = "rand")]
+pub use sample::SampleElement;
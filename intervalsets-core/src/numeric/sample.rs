@@ -0,0 +1,222 @@
+use rand::Rng;
+
+use super::Element;
+use crate::bound::FiniteBound;
+
+/// Draws uniformly distributed values between two finite bounds.
+///
+/// This is the element-level hook behind random sampling from sets
+/// (feature `rand`). Set types resolve emptiness and unboundedness
+/// first, then hand the two finite bounds of a piece to
+/// [`sample_between`](SampleElement::sample_between).
+///
+/// # Contract
+///
+/// Implementations should uphold the following:
+///
+/// 1. **Membership.** A returned value lies inside the interval
+///    described by `lhs` and `rhs`, honouring their
+///    [`BoundType`](crate::bound::BoundType)s: an open bound's limit
+///    is never returned.
+/// 2. **Uniformity.** Discrete types draw every member with equal
+///    probability. Continuous types draw with density uniform over
+///    the interval, up to the granularity of the representation.
+/// 3. **`None` means no member.** `None` is returned only when no
+///    representable value lies strictly inside the bounds — e.g.
+///    `(1, 2)` over integers, or an open float interval narrower
+///    than two ULPs. Callers map that case to an error.
+///
+/// Callers guarantee `lhs.value() <= rhs.value()`; the bounds come
+/// from a validated interval.
+///
+/// # Library impls
+///
+/// - **Integers** draw exactly uniformly by cardinality; open bounds
+///   step to the adjacent integer first.
+/// - **`f32` / `f64`** step open bounds to the next representable
+///   value and sample the closed range. Very wide ranges are halved
+///   first so the full finite range is supported.
+/// - **`Decimal`, `OrderedFloat<_>`, `NotNan<_>`, and `fixed`**
+///   types are covered when their storage feature is also enabled.
+pub trait SampleElement: Element {
+    /// Draws one value between `lhs` and `rhs`, or `None` if no
+    /// representable value lies between them.
+    fn sample_between<R: Rng + ?Sized>(
+        lhs: &FiniteBound<Self>,
+        rhs: &FiniteBound<Self>,
+        rng: &mut R,
+    ) -> Option<Self>;
+}
+
+macro_rules! integer_sample_impl {
+    ($($t:ty), +) => {
+        $(
+            impl SampleElement for $t {
+                fn sample_between<R: Rng + ?Sized>(
+                    lhs: &FiniteBound<Self>,
+                    rhs: &FiniteBound<Self>,
+                    rng: &mut R,
+                ) -> Option<Self> {
+                    let lo = if lhs.is_open() { lhs.value().checked_add(1)? } else { *lhs.value() };
+                    let hi = if rhs.is_open() { rhs.value().checked_sub(1)? } else { *rhs.value() };
+                    (lo <= hi).then(|| rng.gen_range(lo..=hi))
+                }
+            }
+        )+
+    }
+}
+
+integer_sample_impl!(u8, u16, u32, u64, u128, usize);
+integer_sample_impl!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! float_sample_impl {
+    ($($t:ident), +) => {
+        $(
+            impl SampleElement for $t {
+                fn sample_between<R: Rng + ?Sized>(
+                    lhs: &FiniteBound<Self>,
+                    rhs: &FiniteBound<Self>,
+                    rng: &mut R,
+                ) -> Option<Self> {
+                    $t::sample_float(lhs.is_open(), *lhs.value(), rhs.is_open(), *rhs.value(), rng)
+                }
+            }
+
+            impl FloatSample for $t {
+                fn sample_float<R: Rng + ?Sized>(
+                    lo_open: bool,
+                    lo: Self,
+                    hi_open: bool,
+                    hi: Self,
+                    rng: &mut R,
+                ) -> Option<Self> {
+                    let lo = if lo_open { lo.next_up() } else { lo };
+                    let hi = if hi_open { hi.next_down() } else { hi };
+                    // Also rejects the `INF` produced by stepping past `MAX`.
+                    if !lo.is_finite() || !hi.is_finite() || lo > hi {
+                        return None;
+                    }
+                    // `gen_range` divides the width by slightly less than
+                    // one, so it needs headroom below `MAX`. Halve wide
+                    // ranges with a fair coin (the halves have equal
+                    // measure) until they fit; at most two rounds.
+                    let (mut lo, mut hi) = (lo, hi);
+                    while hi / 2.0 - lo / 2.0 > $t::MAX / 4.0 {
+                        let mid = lo / 2.0 + hi / 2.0;
+                        if rng.gen::<bool>() {
+                            hi = mid;
+                        } else {
+                            lo = mid;
+                        }
+                    }
+                    Some(rng.gen_range(lo..=hi))
+                }
+            }
+        )+
+    }
+}
+
+/// Shared float sampling on raw `(open, value)` pairs so the
+/// `ordered-float` wrappers can delegate without rebuilding bounds.
+pub(crate) trait FloatSample: Sized {
+    fn sample_float<R: Rng + ?Sized>(
+        lo_open: bool,
+        lo: Self,
+        hi_open: bool,
+        hi: Self,
+        rng: &mut R,
+    ) -> Option<Self>;
+}
+
+float_sample_impl!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::bound::BoundType;
+
+    fn bound<T: Element>(bound_type: BoundType, value: T) -> FiniteBound<T> {
+        FiniteBound::new(bound_type, value)
+    }
+
+    #[test]
+    fn integers_honour_bound_types() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut seen = [0usize; 4];
+        for _ in 0..4000 {
+            let x = i32::sample_between(
+                &bound(BoundType::Open, 0),
+                &bound(BoundType::Closed, 3),
+                &mut rng,
+            )
+            .unwrap();
+            assert!((1..=3).contains(&x));
+            seen[x as usize] += 1;
+        }
+        assert_eq!(seen[0], 0);
+        // Each of the 3 members is drawn about 1333 times.
+        assert!(
+            seen[1..].iter().all(|&n| (1150..1520).contains(&n)),
+            "{seen:?}"
+        );
+    }
+
+    #[test]
+    fn integers_without_members() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut open = |a, b| {
+            i8::sample_between(
+                &bound(BoundType::Open, a),
+                &bound(BoundType::Open, b),
+                &mut rng,
+            )
+        };
+        assert_eq!(open(1, 2), None);
+        assert_eq!(open(i8::MAX, i8::MAX), None);
+    }
+
+    #[test]
+    fn floats_exclude_open_limits() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let lo = 1.0f64;
+        let hi = lo.next_up().next_up();
+        for _ in 0..100 {
+            let x = f64::sample_between(
+                &bound(BoundType::Open, lo),
+                &bound(BoundType::Open, hi),
+                &mut rng,
+            );
+            assert_eq!(x, Some(lo.next_up()));
+        }
+        let none = f64::sample_between(
+            &bound(BoundType::Open, lo),
+            &bound(BoundType::Open, lo.next_up()),
+            &mut rng,
+        );
+        assert_eq!(none, None);
+    }
+
+    #[test]
+    fn floats_full_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let (mut neg, mut pos) = (0, 0);
+        for _ in 0..1000 {
+            let x = f64::sample_between(
+                &bound(BoundType::Closed, f64::MIN),
+                &bound(BoundType::Closed, f64::MAX),
+                &mut rng,
+            )
+            .unwrap();
+            assert!(x.is_finite());
+            if x < 0.0 {
+                neg += 1;
+            } else {
+                pos += 1;
+            }
+        }
+        assert!(neg > 400 && pos > 400);
+    }
+}
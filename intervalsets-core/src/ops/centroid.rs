@@ -0,0 +1,393 @@
+//! Set-level [`Centroid`] trait — measure-weighted center of mass.
+//!
+//! Where [`Midpoint`](super::Midpoint) answers with the middle of the
+//! convex hull, `centroid` weights every piece by its natural
+//! [`Measure`](crate::measure::Measure): `[0, 1] ∪ [9, 10]` has its
+//! centroid at `5`, but `[0, 1] ∪ [7, 10]` has it at `6.5`. The
+//! centroid can still fall into a gap, so the trait also offers
+//! [`representative`](Centroid::representative), which always returns
+//! a member of the set.
+//!
+//! The centroid is located through measures alone. The piece centers
+//! are weighted in `T::Measure` arithmetic. The resulting offset is
+//! then turned back into a `T` with the same ulp-stable midpoint
+//! search used by [`PartitionEqual`](super::PartitionEqual). No `T`
+//! arithmetic is needed, so integer sets do not overflow `T` itself.
+
+use core::convert::Infallible;
+
+use super::{cut_core, Contains, Split};
+use crate::bound::{FiniteBound, SetBounds, Side};
+use crate::empty::MaybeEmpty;
+use crate::error::MathError;
+use crate::numeric::{Element, Midpointable, NumCast, Zero};
+use crate::ops::math::{TryAdd, TryDiv, TryMul};
+use crate::sets::{EnumInterval, FiniteInterval, HalfInterval, MaybeDisjoint};
+
+/// Measure-weighted center of mass of a set, and a member near it.
+///
+/// `centroid` is `∫ x dμ / μ(S)` for the natural measure `μ`, which is
+/// cardinality on discrete `T` and width on continuous `T`.
+///
+/// # Contract
+///
+/// - **Discrete `T`:** the centroid is the mean of the members,
+///   rounded down to an element.
+/// - **Continuous `T`:** the centroid is within one ulp of the exact
+///   value. Sets of measure zero, such as a few singletons, give every
+///   piece equal weight.
+/// - For a single connected interval the centroid is the
+///   [`Midpoint`](super::Midpoint) on continuous `T`, and the midpoint
+///   rounded down on discrete `T`.
+/// - `representative` is a member of the set. It is the centroid if
+///   that is a member, and otherwise the nearest piece endpoint to it,
+///   ties going left. If that endpoint is open, the midpoint of its
+///   piece is returned instead.
+///
+/// # Errors
+///
+/// - `Err(MathError::Domain)` for shapes without a center of mass:
+///   empty, half-bounded, or unbounded sets. `representative` also
+///   returns it when the chosen piece holds no representable member,
+///   e.g. an open float interval one ulp wide.
+/// - `Err(MathError::Range)` if a piece measure or the weighted sum
+///   overflows `T::Measure`. Float sums that turn non-finite surface
+///   as `Err(MathError::Domain)`.
+///
+/// # Tier
+///
+/// Tier 3a (total, panic-free), named without `try_` for the same
+/// reasons as [`Midpoint`](super::Midpoint).
+///
+/// # Example
+///
+/// ```
+/// use intervalsets_core::prelude::*;
+/// use intervalsets_core::ops::Centroid;
+///
+/// let x = MaybeDisjoint::from_pair(
+///     EnumInterval::closed(0, 8),
+///     EnumInterval::closed(20, 20),
+/// );
+/// // Ten members with sum 56.
+/// assert_eq!(x.centroid(), Ok(5));
+///
+/// let x = MaybeDisjoint::from_pair(
+///     EnumInterval::closed(0.0, 1.0),
+///     EnumInterval::closed(9.0, 10.0),
+/// );
+/// assert_eq!(x.centroid(), Ok(5.0));
+/// assert_eq!(x.representative(), Ok(1.0));
+/// ```
+pub trait Centroid<T> {
+    /// The error returned for shapes without a centroid.
+    type Error: core::error::Error;
+
+    /// The center of mass of the set, which may lie in a gap.
+    fn centroid(&self) -> Result<T, Self::Error>;
+
+    /// A member of the set at or near the centroid.
+    fn representative(&self) -> Result<T, Self::Error>;
+}
+
+/// Centroid of `pieces`, which must be inhabited, fully bounded, and
+/// in ascending order.
+///
+/// Writing `m_i` for the measure of piece `[a_i, b_i]` and
+/// `s_i = μ[lo, a_i] + μ[lo, b_i]`, the offset
+/// `q = Σ m_i·s_i / (2·Σ m_i)` equals `μ[lo, c]` at the centroid `c`
+/// on both discrete and continuous `T`. The `+1` of the inclusive
+/// discrete count appears on both sides and cancels out.
+#[doc(hidden)]
+pub fn centroid_core<'a, T, I>(pieces: I) -> Result<T, MathError>
+where
+    T: Element + Clone + Midpointable<Error = Infallible> + 'a,
+    T::Measure: PartialOrd + NumCast + TryMul<Output = T::Measure> + TryDiv<Output = T::Measure>,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+    <T::Measure as TryMul>::Error: Into<MathError>,
+    <T::Measure as TryDiv>::Error: Into<MathError>,
+    I: IntoIterator<Item = &'a EnumInterval<T>>,
+    I::IntoIter: Clone,
+{
+    let pieces = pieces.into_iter();
+    let (lo, hi) = hull_bounds(pieces.clone())?;
+    let span = |x: &T| T::try_measure_finite(&lo, x).ok_or(MathError::Range);
+    let two: T::Measure = NumCast::from(2).ok_or(MathError::Range)?;
+
+    let mut count = 0_usize;
+    let mut total = <T::Measure as Zero>::zero();
+    // Deferred: a single piece never needs the moment, and its
+    // product would overflow first on a full-range interval.
+    let mut moment = Ok(<T::Measure as Zero>::zero());
+    let mut unweighted = <T::Measure as Zero>::zero();
+    for piece in pieces {
+        let (a, b) = (piece.lval(), piece.rval());
+        let (a, b) = (a.ok_or(MathError::Domain)?, b.ok_or(MathError::Domain)?);
+        let m = T::try_measure_finite(a, b).ok_or(MathError::Range)?;
+        let s = add(span(a)?, span(b)?)?;
+        count += 1;
+        total = add(total, m.clone())?;
+        moment = moment.and_then(|acc| add(acc, m.clone().try_mul(s.clone()).map_err(Into::into)?));
+        unweighted = add(unweighted, s)?;
+    }
+
+    let q = if count == 1 {
+        if lo.try_adjacent(Side::Right).is_none() {
+            // Continuous: the exact midpoint, rather than the search's
+            // answer from below, which can be an ulp short of it.
+            let Ok(mid) = T::midpoint(lo, hi);
+            return Ok(mid);
+        }
+        unweighted.try_div(two).map_err(Into::into)?
+    } else if total > <T::Measure as Zero>::zero() {
+        let denom = total.try_mul(two).map_err(Into::into)?;
+        moment?.try_div(denom).map_err(Into::into)?
+    } else {
+        let count: T::Measure = NumCast::from(count).ok_or(MathError::Range)?;
+        let denom = count.try_mul(two).map_err(Into::into)?;
+        unweighted.try_div(denom).map_err(Into::into)?
+    };
+
+    let hull = FiniteInterval::new(
+        FiniteBound::closed(lo.clone()),
+        FiniteBound::closed(hi.clone()),
+    );
+    let (c, _, _) = cut_core(lo, hi, &q, |x| hull.clone().split(x, Side::Left));
+    Ok(c)
+}
+
+/// Member of `pieces` nearest to `c`; see [`Centroid`] for the rules.
+#[doc(hidden)]
+pub fn representative_core<'a, T, I>(pieces: I, c: T) -> Result<T, MathError>
+where
+    T: Element + Clone + Midpointable<Error = Infallible> + 'a,
+    T::Measure: PartialOrd,
+    I: IntoIterator<Item = &'a EnumInterval<T>>,
+{
+    let mut before = None;
+    let mut after = None;
+    for piece in pieces {
+        if piece.contains(&c) {
+            return Ok(c);
+        }
+        match piece.rval() {
+            Some(r) if *r <= c => before = Some(piece),
+            _ => {
+                after = Some(piece);
+                break;
+            }
+        }
+    }
+
+    let gap = |x: &T, y: &T| T::try_measure_finite(x, y).ok_or(MathError::Range);
+    let (piece, side) = match (before, after) {
+        (Some(l), Some(r)) => {
+            let dl = gap(l.rval().ok_or(MathError::Domain)?, &c)?;
+            let dr = gap(&c, r.lval().ok_or(MathError::Domain)?)?;
+            if dl <= dr {
+                (l, Side::Right)
+            } else {
+                (r, Side::Left)
+            }
+        }
+        (Some(l), None) => (l, Side::Right),
+        (None, Some(r)) => (r, Side::Left),
+        (None, None) => return Err(MathError::Domain),
+    };
+
+    let bound = piece.bound(side).ok_or(MathError::Domain)?;
+    if bound.is_closed() {
+        return Ok(bound.value().clone());
+    }
+    let (a, b) = (piece.lval(), piece.rval());
+    let (a, b) = (a.ok_or(MathError::Domain)?, b.ok_or(MathError::Domain)?);
+    let Ok(mid) = T::midpoint(a.clone(), b.clone());
+    if piece.contains(&mid) {
+        Ok(mid)
+    } else {
+        Err(MathError::Domain)
+    }
+}
+
+fn hull_bounds<'a, T, I>(mut pieces: I) -> Result<(T, T), MathError>
+where
+    T: Clone + 'a,
+    I: Iterator<Item = &'a EnumInterval<T>>,
+{
+    let first = pieces.next().ok_or(MathError::Domain)?;
+    let last = pieces.last().unwrap_or(first);
+    match (first.lval(), last.rval()) {
+        (Some(lo), Some(hi)) => Ok((lo.clone(), hi.clone())),
+        _ => Err(MathError::Domain),
+    }
+}
+
+fn add<M: TryAdd<Output = M>>(a: M, b: M) -> Result<M, MathError>
+where
+    M::Error: Into<MathError>,
+{
+    a.try_add(b).map_err(Into::into)
+}
+
+macro_rules! centroid_impl {
+    ($t:ty, $n:literal, $pieces:expr) => {
+        impl<T> Centroid<T> for $t
+        where
+            T: Element + Clone + Midpointable<Error = Infallible>,
+            T::Measure:
+                PartialOrd + NumCast + TryMul<Output = T::Measure> + TryDiv<Output = T::Measure>,
+            <T::Measure as TryAdd>::Error: Into<MathError>,
+            <T::Measure as TryMul>::Error: Into<MathError>,
+            <T::Measure as TryDiv>::Error: Into<MathError>,
+        {
+            type Error = MathError;
+
+            fn centroid(&self) -> Result<T, MathError> {
+                let pieces: for<'s> fn(&'s Self) -> [Option<&'s EnumInterval<T>>; $n] = $pieces;
+                centroid_core(pieces(self).into_iter().flatten())
+            }
+
+            fn representative(&self) -> Result<T, MathError> {
+                let pieces: for<'s> fn(&'s Self) -> [Option<&'s EnumInterval<T>>; $n] = $pieces;
+                let c = centroid_core(pieces(self).into_iter().flatten())?;
+                representative_core(pieces(self).into_iter().flatten(), c)
+            }
+        }
+    };
+}
+
+centroid_impl!(EnumInterval<T>, 1, |x| [x.is_inhabited().then_some(x)]);
+
+centroid_impl!(MaybeDisjoint<T>, 2, |x| match x {
+    MaybeDisjoint::Connected(a) => [a.is_inhabited().then_some(a), None],
+    MaybeDisjoint::Disjoint(a, b) => [Some(a), Some(b)],
+});
+
+impl<T> Centroid<T> for FiniteInterval<T>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd + NumCast + TryMul<Output = T::Measure> + TryDiv<Output = T::Measure>,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+    <T::Measure as TryMul>::Error: Into<MathError>,
+    <T::Measure as TryDiv>::Error: Into<MathError>,
+{
+    type Error = MathError;
+
+    fn centroid(&self) -> Result<T, MathError> {
+        EnumInterval::from(self.clone()).centroid()
+    }
+
+    fn representative(&self) -> Result<T, MathError> {
+        EnumInterval::from(self.clone()).representative()
+    }
+}
+
+impl<T> Centroid<T> for HalfInterval<T> {
+    type Error = MathError;
+
+    /// Always `Err(MathError::Domain)`: a half-bounded interval has
+    /// infinite measure.
+    fn centroid(&self) -> Result<T, MathError> {
+        Err(MathError::Domain)
+    }
+
+    /// Always `Err(MathError::Domain)`; see [`Self::centroid`].
+    fn representative(&self) -> Result<T, MathError> {
+        Err(MathError::Domain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::{FiniteFactory, HalfBoundedFactory};
+
+    fn pair<T: Element>(a: EnumInterval<T>, b: EnumInterval<T>) -> MaybeDisjoint<T> {
+        MaybeDisjoint::from_pair(a, b)
+    }
+
+    #[test]
+    fn single_interval_matches_midpoint() {
+        assert_eq!(FiniteInterval::closed(0, 10).centroid(), Ok(5));
+        assert_eq!(FiniteInterval::closed(0, 9).centroid(), Ok(4));
+        assert_eq!(FiniteInterval::closed(-9, 0).centroid(), Ok(-5));
+        assert_eq!(FiniteInterval::open(0.0, 10.0).centroid(), Ok(5.0));
+        assert_eq!(EnumInterval::singleton(3.5).centroid(), Ok(3.5));
+
+        let full = FiniteInterval::closed(i32::MIN, i32::MAX);
+        assert_eq!(full.centroid(), Ok(-1));
+        assert_eq!(FiniteInterval::closed(0_u8, 255).centroid(), Ok(127));
+    }
+
+    #[test]
+    fn weights_by_measure() {
+        let x = pair(
+            EnumInterval::closed(0.0, 8.0),
+            EnumInterval::closed(9.0, 10.0),
+        );
+        let c = x.centroid().unwrap();
+        // (8·4 + 1·9.5) / 9
+        assert!((c - 41.5_f64 / 9.0).abs() < 1e-12, "{c}");
+
+        let x = pair(EnumInterval::closed(0, 2), EnumInterval::closed(10, 10));
+        assert_eq!(x.centroid(), Ok(3));
+        assert_eq!(x.representative(), Ok(2));
+    }
+
+    #[test]
+    fn zero_measure_weights_pieces_equally() {
+        let x = pair(EnumInterval::singleton(1.0), EnumInterval::singleton(4.0));
+        assert_eq!(x.centroid(), Ok(2.5));
+        assert_eq!(x.representative(), Ok(1.0));
+    }
+
+    #[test]
+    fn representative_is_member() {
+        let x = pair(
+            EnumInterval::closed(0.0, 1.0),
+            EnumInterval::closed(7.0, 10.0),
+        );
+        assert_eq!(x.centroid(), Ok(6.5));
+        assert_eq!(x.representative(), Ok(7.0));
+
+        let x = pair(
+            EnumInterval::closed(0.0, 1.0),
+            EnumInterval::open(7.0, 10.0),
+        );
+        assert_eq!(x.representative(), Ok(8.5));
+
+        let x = FiniteInterval::closed(2, 6);
+        assert_eq!(x.representative(), Ok(4));
+    }
+
+    #[test]
+    fn shapes_without_centroid() {
+        assert_eq!(
+            FiniteInterval::<i32>::empty().centroid(),
+            Err(MathError::Domain)
+        );
+        assert_eq!(
+            MaybeDisjoint::<i32>::empty().representative(),
+            Err(MathError::Domain)
+        );
+
+        let half = HalfInterval::<i32>::left(FiniteBound::closed(0));
+        assert_eq!(Centroid::<i32>::centroid(&half), Err(MathError::Domain));
+
+        let x = pair(EnumInterval::closed(0, 1), EnumInterval::closed_unbound(5));
+        assert_eq!(x.centroid(), Err(MathError::Domain));
+        assert_eq!(
+            EnumInterval::<i32>::Unbounded.centroid(),
+            Err(MathError::Domain)
+        );
+    }
+
+    #[test]
+    fn moment_overflow_is_reported() {
+        let x = pair(
+            EnumInterval::closed(f64::MIN, 0.0),
+            EnumInterval::closed(1.0, f64::MAX),
+        );
+        assert_eq!(x.centroid(), Err(MathError::Range));
+    }
+}
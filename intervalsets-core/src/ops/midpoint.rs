@@ -4,7 +4,7 @@
 //! `(inf(S) + sup(S)) / 2`. For a single connected interval this is
 //! the interval's midpoint; for multi-piece sets the result may lie in
 //! a gap. Callers wanting a point guaranteed to lie in some component
-//! should reach for `Centroid` (planned).
+//! should reach for [`Centroid::representative`](super::Centroid::representative).
 //!
 //! `Err(MathError::Domain)` covers shapes that have no defined hull
 //! midpoint — empty, half-bounded, or unbounded sets. For an inhabited
@@ -24,8 +24,8 @@ use crate::sets::{EnumInterval, FiniteInterval, HalfInterval, MaybeDisjoint};
 /// interval's midpoint; for multi-piece sets ([`MaybeDisjoint`],
 /// `IntervalSet` in the wrapper crate) it is the midpoint of the
 /// convex hull and **may lie in a gap** — i.e. not be a member of `S`.
-/// Callers wanting an in-component result should use `Centroid`
-/// (planned).
+/// Callers wanting an in-component result should use
+/// [`Centroid::representative`](super::Centroid::representative).
 ///
 /// # Errors
 ///
@@ -111,7 +111,7 @@ where
     /// spanning `a`'s left bound to `b`'s right bound — which **may
     /// lie in the gap between the pieces** (i.e. not be a member of
     /// the set). Callers needing a point guaranteed to lie in some
-    /// component should use the planned `Centroid` trait.
+    /// component should use [`Centroid`](super::Centroid).
     ///
     /// `Err(MathError::Domain)` when the hull is unbounded (any piece
     /// is half/unbounded) or when the set is empty.
@@ -202,7 +202,7 @@ mod tests {
         // midpoint is `7`. The point lies in the gap `(5, 10)` — i.e.
         // is NOT a member of the set — which is documented behavior
         // for hull-midpoint semantics. Callers wanting an in-component
-        // result need `Centroid` (planned).
+        // result need `Centroid::representative`.
         let x =
             MaybeDisjoint::from_pair(EnumInterval::closed(0_i32, 5), EnumInterval::closed(10, 15));
         assert_eq!(x.midpoint(), Ok(7));
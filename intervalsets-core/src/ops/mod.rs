@@ -145,6 +145,18 @@ pub use bisect::bisect_core;
 pub use bisect::{Bisect, Bisection};
 mod midpoint;
 pub use midpoint::Midpoint;
+mod nearest;
+pub use nearest::Nearest;
+#[doc(hidden)]
+pub use nearest::{nearest_sorted, next_sorted, prev_sorted};
+mod partition;
+#[doc(hidden)]
+pub use partition::cut_core;
+mod centroid;
+pub use centroid::Centroid;
+#[doc(hidden)]
+pub use centroid::{centroid_core, representative_core};
+pub use partition::{EqualParts, PartitionEqual};
 mod union;
 pub use union::Union;
 
@@ -0,0 +1,346 @@
+//! Set-level [`Nearest`] trait — projecting a value onto a set.
+//!
+//! Every query answers with a [`FiniteBound`]: a **closed** bound is a
+//! member of the set, an **open** bound is a limit that is *not* a
+//! member. An open answer only arises on continuous `T`, where a value
+//! outside `(0.0, 1.0)` has no nearest member. It gets the infimum or
+//! supremum instead, flagged as open. On discrete `T` an open piece
+//! endpoint is stepped inward with [`Element::try_adjacent`], so the
+//! answer is a closed member, with one exception: a half interval open
+//! at the limit of `T`, such as `(i8::MAX, ..)`, has no member to step
+//! to and answers with its open bound.
+
+use crate::bound::{FiniteBound, SetBounds, Side};
+use crate::empty::MaybeEmpty;
+use crate::numeric::Element;
+use crate::ops::Contains;
+use crate::sets::{EnumInterval, FiniteInterval, HalfInterval, MaybeDisjoint};
+
+/// Nearest members of a set to a value.
+///
+/// # Contract
+///
+/// For a query value `x`:
+///
+/// - If `x` is a member, every query returns `FiniteBound::closed(x)`.
+/// - Otherwise the answer is the nearest piece endpoint in the
+///   requested direction. It is closed if that endpoint is a member
+///   and open if it is only a limit; see the
+///   [module docs](self).
+/// - `nearest` compares both directions by
+///   [`Element::try_measure_finite`], so discrete distances count
+///   elements. Ties go to the lower value; a distance too large for
+///   `T::Measure` counts as the farther one.
+/// - `None` means there is nothing in that direction: the set is
+///   empty, or for the directed queries no piece lies past `x`.
+///
+/// # Example
+///
+/// ```
+/// use intervalsets_core::prelude::*;
+/// use intervalsets_core::ops::Nearest;
+///
+/// let x = MaybeDisjoint::from_pair(
+///     EnumInterval::closed(0, 10),
+///     EnumInterval::closed(20, 30),
+/// );
+/// assert_eq!(x.nearest(&14), Some(FiniteBound::closed(10)));
+/// assert_eq!(x.next_member_at_or_after(&14), Some(FiniteBound::closed(20)));
+/// assert_eq!(x.clamp_into(17), Some(20));
+///
+/// let x = EnumInterval::open(0.0, 1.0);
+/// assert_eq!(x.nearest(&2.0), Some(FiniteBound::open(1.0)));
+/// ```
+pub trait Nearest<T> {
+    /// The member nearest to `x`, or the limit nearest to it if that
+    /// is not attained.
+    fn nearest(&self, x: &T) -> Option<FiniteBound<T>>;
+
+    /// The smallest member `>= x`, or the infimum above `x` if it is
+    /// not attained.
+    fn next_member_at_or_after(&self, x: &T) -> Option<FiniteBound<T>>;
+
+    /// The largest member `<= x`, or the supremum below `x` if it is
+    /// not attained.
+    fn prev_member_at_or_before(&self, x: &T) -> Option<FiniteBound<T>>;
+
+    /// Clamps `x` into the set: `x` itself if it is a member, otherwise
+    /// the value of [`nearest`](Self::nearest). The result may be an
+    /// open limit on continuous `T`; use `nearest` to tell.
+    fn clamp_into(&self, x: T) -> Option<T> {
+        self.nearest(&x).map(|b| b.into_raw().1)
+    }
+}
+
+/// Where a value sits relative to a sorted run of pieces.
+enum Located<'a, T> {
+    Inside,
+    /// The right bound of the piece before and the left bound of the
+    /// piece after, when they exist.
+    Between(Option<&'a FiniteBound<T>>, Option<&'a FiniteBound<T>>),
+}
+
+fn left_of<T: PartialOrd, P: SetBounds<T>>(piece: &P, x: &T) -> bool {
+    piece
+        .right()
+        .is_some_and(|b| !b.contains_assume_valid(Side::Right, x))
+}
+
+fn locate<'a, T, P, I>(pieces: I, x: &T) -> Located<'a, T>
+where
+    T: PartialOrd + 'a,
+    P: SetBounds<T> + MaybeEmpty + for<'x> Contains<&'x T> + 'a,
+    I: IntoIterator<Item = &'a P>,
+{
+    let mut before = None;
+    for piece in pieces {
+        if piece.is_empty() {
+            continue;
+        }
+        if piece.contains(x) {
+            return Located::Inside;
+        }
+        if left_of(piece, x) {
+            before = piece.right();
+        } else {
+            return Located::Between(before, piece.left());
+        }
+    }
+    Located::Between(before, None)
+}
+
+/// Pieces `[i - 1, i]` around `x`, found by binary search, where `i`
+/// is the first piece not entirely left of `x`.
+fn around<'a, T, P>(pieces: &'a [P], x: &T) -> &'a [P]
+where
+    T: PartialOrd,
+    P: SetBounds<T>,
+{
+    let i = pieces.partition_point(|p| left_of(p, x));
+    &pieces[i.saturating_sub(1)..(i + 1).min(pieces.len())]
+}
+
+/// The member at `bound`, stepping inward (towards `inward`) past an
+/// open endpoint when `T` is discrete. An open bound at the limit of a
+/// discrete `T` has no member inward of it and is returned as is.
+fn member_at<T: Element + Clone>(bound: &FiniteBound<T>, inward: Side) -> FiniteBound<T> {
+    if bound.is_closed() {
+        return bound.clone();
+    }
+    match bound.value().try_adjacent(inward) {
+        Some(v) => FiniteBound::closed(v),
+        None => bound.clone(),
+    }
+}
+
+fn resolve_nearest<T>(x: &T, at: Located<'_, T>) -> Option<FiniteBound<T>>
+where
+    T: Element + Clone,
+    T::Measure: PartialOrd,
+{
+    let (before, after) = match at {
+        Located::Inside => return Some(FiniteBound::closed(x.clone())),
+        Located::Between(before, after) => (before, after),
+    };
+    let below = before.map(|b| member_at(b, Side::Left));
+    let above = after.map(|b| member_at(b, Side::Right));
+    match (below, above) {
+        (Some(lo), Some(hi)) => {
+            let d_lo = T::try_measure_finite(lo.value(), x);
+            let d_hi = T::try_measure_finite(x, hi.value());
+            let take_hi = match (d_lo, d_hi) {
+                (Some(l), Some(h)) => h < l,
+                (None, Some(_)) => true,
+                _ => false,
+            };
+            Some(if take_hi { hi } else { lo })
+        }
+        (lo, hi) => lo.or(hi),
+    }
+}
+
+fn resolve_next<T: Element + Clone>(x: &T, at: Located<'_, T>) -> Option<FiniteBound<T>> {
+    match at {
+        Located::Inside => Some(FiniteBound::closed(x.clone())),
+        Located::Between(_, after) => after.map(|b| member_at(b, Side::Right)),
+    }
+}
+
+fn resolve_prev<T: Element + Clone>(x: &T, at: Located<'_, T>) -> Option<FiniteBound<T>> {
+    match at {
+        Located::Inside => Some(FiniteBound::closed(x.clone())),
+        Located::Between(before, _) => before.map(|b| member_at(b, Side::Left)),
+    }
+}
+
+/// [`Nearest::nearest`] over sorted, disjoint `pieces`; O(log n).
+#[doc(hidden)]
+pub fn nearest_sorted<T, P>(pieces: &[P], x: &T) -> Option<FiniteBound<T>>
+where
+    T: Element + Clone,
+    T::Measure: PartialOrd,
+    P: SetBounds<T> + MaybeEmpty + for<'x> Contains<&'x T>,
+{
+    resolve_nearest(x, locate(around(pieces, x), x))
+}
+
+/// [`Nearest::next_member_at_or_after`] over sorted, disjoint
+/// `pieces`; O(log n).
+#[doc(hidden)]
+pub fn next_sorted<T, P>(pieces: &[P], x: &T) -> Option<FiniteBound<T>>
+where
+    T: Element + Clone,
+    P: SetBounds<T> + MaybeEmpty + for<'x> Contains<&'x T>,
+{
+    resolve_next(x, locate(around(pieces, x), x))
+}
+
+/// [`Nearest::prev_member_at_or_before`] over sorted, disjoint
+/// `pieces`; O(log n).
+#[doc(hidden)]
+pub fn prev_sorted<T, P>(pieces: &[P], x: &T) -> Option<FiniteBound<T>>
+where
+    T: Element + Clone,
+    P: SetBounds<T> + MaybeEmpty + for<'x> Contains<&'x T>,
+{
+    resolve_prev(x, locate(around(pieces, x), x))
+}
+
+macro_rules! nearest_single_impl {
+    ($($t:ident), +) => {
+        $(
+            impl<T> Nearest<T> for $t<T>
+            where
+                T: Element + Clone,
+                T::Measure: PartialOrd,
+            {
+                fn nearest(&self, x: &T) -> Option<FiniteBound<T>> {
+                    nearest_sorted(core::slice::from_ref(self), x)
+                }
+
+                fn next_member_at_or_after(&self, x: &T) -> Option<FiniteBound<T>> {
+                    next_sorted(core::slice::from_ref(self), x)
+                }
+
+                fn prev_member_at_or_before(&self, x: &T) -> Option<FiniteBound<T>> {
+                    prev_sorted(core::slice::from_ref(self), x)
+                }
+            }
+        )+
+    };
+}
+
+nearest_single_impl!(FiniteInterval, HalfInterval, EnumInterval);
+
+impl<T> Nearest<T> for MaybeDisjoint<T>
+where
+    T: Element + Clone,
+    T::Measure: PartialOrd,
+{
+    fn nearest(&self, x: &T) -> Option<FiniteBound<T>> {
+        match self {
+            Self::Connected(a) => a.nearest(x),
+            Self::Disjoint(a, b) => resolve_nearest(x, locate([a, b], x)),
+        }
+    }
+
+    fn next_member_at_or_after(&self, x: &T) -> Option<FiniteBound<T>> {
+        match self {
+            Self::Connected(a) => a.next_member_at_or_after(x),
+            Self::Disjoint(a, b) => resolve_next(x, locate([a, b], x)),
+        }
+    }
+
+    fn prev_member_at_or_before(&self, x: &T) -> Option<FiniteBound<T>> {
+        match self {
+            Self::Connected(a) => a.prev_member_at_or_before(x),
+            Self::Disjoint(a, b) => resolve_prev(x, locate([a, b], x)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bound::BoundType;
+    use crate::factory::FiniteFactory;
+
+    fn closed<T: Element>(x: T) -> Option<FiniteBound<T>> {
+        Some(FiniteBound::closed(x))
+    }
+
+    fn open<T: Element>(x: T) -> Option<FiniteBound<T>> {
+        Some(FiniteBound::open(x))
+    }
+
+    #[test]
+    fn interval_members_and_outside() {
+        let x = FiniteInterval::closed(0, 10);
+        assert_eq!(x.nearest(&5), closed(5));
+        assert_eq!(x.nearest(&-3), closed(0));
+        assert_eq!(x.nearest(&13), closed(10));
+        assert_eq!(x.next_member_at_or_after(&-3), closed(0));
+        assert_eq!(x.next_member_at_or_after(&13), None);
+        assert_eq!(x.prev_member_at_or_before(&-3), None);
+        assert_eq!(x.prev_member_at_or_before(&13), closed(10));
+        assert_eq!(x.clamp_into(42), Some(10));
+        assert_eq!(FiniteInterval::<i32>::empty().nearest(&0), None);
+    }
+
+    #[test]
+    fn continuous_open_bounds_are_flagged() {
+        let x = FiniteInterval::open(0.0, 1.0);
+        assert_eq!(x.nearest(&0.5), closed(0.5));
+        assert_eq!(x.nearest(&-1.0), open(0.0));
+        assert_eq!(x.nearest(&1.0), open(1.0));
+        assert_eq!(x.prev_member_at_or_before(&7.0), open(1.0));
+        assert_eq!(x.clamp_into(7.0), Some(1.0));
+
+        let x = HalfInterval::right(FiniteBound::open(0.0));
+        assert_eq!(x.nearest(&0.0), open(0.0));
+        assert_eq!(x.nearest(&-5.0), closed(-5.0));
+    }
+
+    #[test]
+    fn discrete_open_bounds_step_inward() {
+        // Stored bounds are normalized closed; build the raw open form
+        // directly to exercise the adjacency step.
+        let b = FiniteBound::new(BoundType::Open, 4);
+        assert_eq!(member_at(&b, Side::Left), FiniteBound::closed(3));
+        assert_eq!(member_at(&b, Side::Right), FiniteBound::closed(5));
+
+        let x = FiniteInterval::open(0, 10);
+        assert_eq!(x.nearest(&0), closed(1));
+        assert_eq!(x.nearest(&10), closed(9));
+    }
+
+    #[test]
+    fn disjoint_gaps() {
+        let x = MaybeDisjoint::from_pair(EnumInterval::closed(0, 3), EnumInterval::closed(7, 9));
+        assert_eq!(x.nearest(&4), closed(3));
+        assert_eq!(x.nearest(&6), closed(7));
+        // Tie: 5 is two steps from both 3 and 7.
+        assert_eq!(x.nearest(&5), closed(3));
+        assert_eq!(x.next_member_at_or_after(&5), closed(7));
+        assert_eq!(x.prev_member_at_or_before(&5), closed(3));
+        assert_eq!(x.next_member_at_or_after(&12), None);
+
+        let x = MaybeDisjoint::from_pair(
+            EnumInterval::closed_open(0.0, 1.0),
+            EnumInterval::open_closed(2.0, 3.0),
+        );
+        assert_eq!(x.nearest(&1.2), open(1.0));
+        assert_eq!(x.nearest(&1.9), open(2.0));
+        assert_eq!(x.next_member_at_or_after(&1.0), open(2.0));
+    }
+
+    #[test]
+    fn extreme_values() {
+        let x = MaybeDisjoint::from_pair(
+            EnumInterval::closed(u128::MIN, 0),
+            EnumInterval::closed(u128::MAX, u128::MAX),
+        );
+        assert_eq!(x.nearest(&u128::MAX.wrapping_sub(1)), closed(u128::MAX));
+        assert_eq!(x.nearest(&1), closed(0));
+    }
+}
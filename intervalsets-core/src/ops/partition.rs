@@ -0,0 +1,461 @@
+//! N-way partitioning by the natural [`Measure`](crate::measure::Measure)
+//! of a set. Public surface: [`PartitionEqual`], [`EqualParts`].
+//!
+//! Generalizes [`Bisect`](super::Bisect) from two halves to `n`
+//! parts. Cuts are placed one at a time, left to right: each cut
+//! takes the target share of what is still unassigned, so rounding on
+//! discrete types never accumulates and the last part absorbs the
+//! remainder.
+
+use core::cmp::Ordering;
+use core::convert::Infallible;
+
+use super::Split;
+use crate::bound::{SetBounds, Side};
+use crate::error::MathError;
+use crate::measure::{Extent, Measure};
+use crate::numeric::{Element, Midpointable, NumCast, Zero};
+use crate::ops::math::{TryAdd, TryDiv, TryMul, TrySub};
+use crate::sets::{EnumInterval, FiniteInterval, MaybeDisjoint};
+
+/// Split a set into parts of (near) equal natural
+/// [`Measure`](crate::measure::Measure) — cardinality on discrete `T`,
+/// Lebesgue width on continuous `T`.
+///
+/// The parts are disjoint, in ascending order, and cover the set.
+/// They are produced lazily by an [`EqualParts`] iterator, so the
+/// trait is available without allocation.
+///
+/// # Balance
+///
+/// - **Discrete `T`:** part sizes differ by at most one element for
+///   [`partition_equal`](Self::partition_equal). With weights, part
+///   `k` receives `floor(R · w_k / W)` elements, where `R` and `W` are
+///   the measure and weight not yet assigned; the last part takes the
+///   remainder.
+/// - **Continuous `T`:** each cut is found with the ulp-stable
+///   midpoint search used by `bisect_core`, so part measures are
+///   within one ulp of their targets.
+///
+/// # Errors
+///
+/// `Err(MathError::Domain)` for shapes without a finite measure to
+/// share out — empty, half-bounded, or unbounded sets — and for
+/// `n == 0`, an empty weight list, a negative weight, or weights
+/// summing to zero. `Err(MathError::Range)` if the weighted targets
+/// would overflow `T::Measure`.
+///
+/// # Example
+///
+/// ```
+/// use intervalsets_core::prelude::*;
+/// use intervalsets_core::ops::PartitionEqual;
+///
+/// let x = FiniteInterval::closed(0, 9);
+/// let parts: Vec<_> = x.partition_equal(3).unwrap().collect();
+/// assert_eq!(
+///     parts,
+///     [
+///         FiniteInterval::closed(0, 2),
+///         FiniteInterval::closed(3, 5),
+///         FiniteInterval::closed(6, 9),
+///     ]
+/// );
+///
+/// let parts: Vec<_> = x.partition_weighted(&[1_u64, 4]).unwrap().collect();
+/// assert_eq!(parts, [FiniteInterval::closed(0, 1), FiniteInterval::closed(2, 9)]);
+/// ```
+pub trait PartitionEqual<T: Element>: Sized {
+    /// Split into `n` parts of equal measure.
+    fn partition_equal(&self, n: usize) -> Result<EqualParts<'static, T, Self>, MathError>;
+
+    /// Split into `weights.len()` parts with measures proportional to
+    /// `weights`. A zero weight yields an empty part.
+    fn partition_weighted<'w>(
+        &self,
+        weights: &'w [T::Measure],
+    ) -> Result<EqualParts<'w, T, Self>, MathError>;
+}
+
+/// Iterator over the parts produced by [`PartitionEqual`].
+///
+/// Each call to `next` places one cut by measure search over the
+/// unassigned remainder of the set.
+#[derive(Debug, Clone)]
+pub struct EqualParts<'w, T: Element, S> {
+    rest: Option<S>,
+    lo: T,
+    hi: T,
+    plan: Plan<'w, T::Measure>,
+}
+
+#[derive(Debug, Clone)]
+enum Plan<'w, M> {
+    Equal(usize),
+    /// The weights still ahead and their sum.
+    Weighted(&'w [M], M),
+}
+
+impl<'w, T, S> EqualParts<'w, T, S>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd
+        + NumCast
+        + TryMul<Output = T::Measure>
+        + TryDiv<Output = T::Measure>
+        + TrySub<Output = T::Measure>,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+    <T::Measure as TryMul>::Error: Into<MathError>,
+    <T::Measure as TryDiv>::Error: Into<MathError>,
+    <T::Measure as TrySub>::Error: Into<MathError>,
+    S: Clone + Split<T, Output = S> + Measure<Output = T::Measure, Error = MathError>,
+{
+    /// Validates a set whose hull is `bracket` for `n` equal parts.
+    #[doc(hidden)]
+    pub fn try_equal(set: S, bracket: Option<(T, T)>, n: usize) -> Result<Self, MathError> {
+        if n == 0 {
+            return Err(MathError::Domain);
+        }
+        Self::total(&set)?;
+        // Division by the part count is the only arithmetic.
+        <T::Measure as NumCast>::from(n).ok_or(MathError::Range)?;
+        Self::build(set, bracket, Plan::Equal(n))
+    }
+
+    /// Validates a set whose hull is `bracket` for weighted parts.
+    #[doc(hidden)]
+    pub fn try_weighted(
+        set: S,
+        bracket: Option<(T, T)>,
+        weights: &'w [T::Measure],
+    ) -> Result<Self, MathError> {
+        let zero = <T::Measure as Zero>::zero();
+        let mut sum = zero.clone();
+        let mut max = zero.clone();
+        for w in weights {
+            if w.partial_cmp(&zero).is_none_or(Ordering::is_lt) {
+                return Err(MathError::Domain);
+            }
+            if *w > max {
+                max = w.clone();
+            }
+            sum = sum.try_add(w.clone()).map_err(Into::into)?;
+        }
+        if sum.partial_cmp(&zero) != Some(Ordering::Greater) {
+            return Err(MathError::Domain);
+        }
+        // Every later target is `R · w / W` with `R <= total` and
+        // `w <= max`, so checking the largest product up front keeps
+        // iteration infallible.
+        let total = Self::total(&set)?;
+        total.try_mul(max).map_err(Into::into)?;
+        Self::build(set, bracket, Plan::Weighted(weights, sum))
+    }
+
+    fn total(set: &S) -> Result<T::Measure, MathError> {
+        match set.try_measure()? {
+            Extent::Finite(m) => Ok(m),
+            Extent::Infinite => Err(MathError::Domain),
+        }
+    }
+
+    fn build(
+        set: S,
+        bracket: Option<(T, T)>,
+        plan: Plan<'w, T::Measure>,
+    ) -> Result<Self, MathError> {
+        let (lo, hi) = bracket.ok_or(MathError::Domain)?;
+        Ok(Self {
+            rest: Some(set),
+            lo,
+            hi,
+            plan,
+        })
+    }
+
+    /// Measure share for the next part, or `None` if it is the last.
+    fn next_target(&mut self, remaining: &T::Measure) -> Option<T::Measure> {
+        match &mut self.plan {
+            Plan::Equal(n) => {
+                if *n <= 1 {
+                    return None;
+                }
+                let parts = <T::Measure as NumCast>::from(*n).expect("checked in try_equal");
+                *n -= 1;
+                Some(
+                    remaining
+                        .clone()
+                        .try_div(parts)
+                        .map_err(Into::into)
+                        .expect("nonzero divisor"),
+                )
+            }
+            Plan::Weighted(weights, sum) => {
+                let (w, rest) = weights.split_first()?;
+                if rest.is_empty() {
+                    return None;
+                }
+                *weights = rest;
+                let zero = <T::Measure as Zero>::zero();
+                if (*sum).partial_cmp(&zero) != Some(Ordering::Greater) {
+                    // Only zero weights remain ahead of the last part.
+                    return Some(zero);
+                }
+                let scaled = remaining
+                    .clone()
+                    .try_mul(w.clone())
+                    .map_err(Into::into)
+                    .expect("checked in try_weighted");
+                let whole = sum.clone();
+                *sum = whole
+                    .clone()
+                    .try_sub(w.clone())
+                    .map_err(Into::into)
+                    .expect("w is part of the sum");
+                Some(
+                    scaled
+                        .try_div(whole)
+                        .map_err(Into::into)
+                        .expect("nonzero sum"),
+                )
+            }
+        }
+    }
+}
+
+impl<T, S> Iterator for EqualParts<'_, T, S>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd
+        + NumCast
+        + TryMul<Output = T::Measure>
+        + TryDiv<Output = T::Measure>
+        + TrySub<Output = T::Measure>,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+    <T::Measure as TryMul>::Error: Into<MathError>,
+    <T::Measure as TryDiv>::Error: Into<MathError>,
+    <T::Measure as TrySub>::Error: Into<MathError>,
+    S: Clone + Split<T, Output = S> + Measure<Output = T::Measure, Error = MathError>,
+{
+    type Item = S;
+
+    fn next(&mut self) -> Option<S> {
+        let rest = self.rest.take()?;
+        let remaining = match rest.measure() {
+            Extent::Finite(m) => m,
+            Extent::Infinite => unreachable!("validated finite at construction"),
+        };
+        let Some(target) = self.next_target(&remaining) else {
+            return Some(rest);
+        };
+        let (left, right) = if target > <T::Measure as Zero>::zero() {
+            let (cut, left, right) = cut_core(self.lo.clone(), self.hi.clone(), &target, |m| {
+                rest.clone().split(m, Side::Left)
+            });
+            self.lo = cut;
+            (left, right)
+        } else {
+            // Everything strictly left of the bracket: an empty part.
+            rest.split(self.lo.clone(), Side::Right)
+        };
+        self.rest = Some(right);
+        Some(left)
+    }
+}
+
+/// Smallest cut `m` in `[lo, hi]` whose left split has measure at
+/// least `target`; `split(hi)` must reach it. Same termination rule
+/// as [`bisect_core`](super::bisect_core): stop when the midpoint
+/// stabilizes (adjacent integers, adjacent floats).
+#[doc(hidden)]
+pub fn cut_core<T, M, S, Sp>(mut lo: T, mut hi: T, target: &M, split: Sp) -> (T, S, S)
+where
+    T: Clone + PartialEq + Midpointable<Error = Infallible>,
+    M: PartialOrd,
+    S: Measure<Output = M>,
+    Sp: Fn(T) -> (S, S),
+{
+    let reached = |s: &S| match s.measure() {
+        Extent::Finite(m) => m >= *target,
+        Extent::Infinite => true,
+    };
+
+    let (l, r) = split(lo.clone());
+    if reached(&l) {
+        return (lo, l, r);
+    }
+    let mut best = split(hi.clone());
+    loop {
+        let m = T::midpoint(lo.clone(), hi.clone()).unwrap();
+        if m == lo || m == hi {
+            return (hi, best.0, best.1);
+        }
+        let (l, r) = split(m.clone());
+        if reached(&l) {
+            hi = m;
+            best = (l, r);
+        } else {
+            lo = m;
+        }
+    }
+}
+
+fn finite_bounds<T: Clone, S: SetBounds<T>>(s: &S) -> Option<(T, T)> {
+    Some((s.lval()?.clone(), s.rval()?.clone()))
+}
+
+macro_rules! partition_equal_impl {
+    ($t:ident, $bracket:expr) => {
+        impl<T> PartitionEqual<T> for $t<T>
+        where
+            T: Element + Clone + Midpointable<Error = Infallible>,
+            T::Measure: PartialOrd
+                + NumCast
+                + TryMul<Output = T::Measure>
+                + TryDiv<Output = T::Measure>
+                + TrySub<Output = T::Measure>,
+            <T::Measure as TryAdd>::Error: Into<MathError>,
+            <T::Measure as TryMul>::Error: Into<MathError>,
+            <T::Measure as TryDiv>::Error: Into<MathError>,
+            <T::Measure as TrySub>::Error: Into<MathError>,
+        {
+            fn partition_equal(&self, n: usize) -> Result<EqualParts<'static, T, Self>, MathError> {
+                let bracket: fn(&Self) -> Option<(T, T)> = $bracket;
+                EqualParts::try_equal(self.clone(), bracket(self), n)
+            }
+
+            fn partition_weighted<'w>(
+                &self,
+                weights: &'w [T::Measure],
+            ) -> Result<EqualParts<'w, T, Self>, MathError> {
+                let bracket: fn(&Self) -> Option<(T, T)> = $bracket;
+                EqualParts::try_weighted(self.clone(), bracket(self), weights)
+            }
+        }
+    };
+}
+
+partition_equal_impl!(FiniteInterval, |s| finite_bounds(s));
+partition_equal_impl!(EnumInterval, |s| finite_bounds(s));
+partition_equal_impl!(MaybeDisjoint, |s| finite_bounds(&s.hull()));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+
+    fn sizes<S>(parts: impl Iterator<Item = S>) -> [u128; 8]
+    where
+        S: Measure,
+        S::Output: Into<u128>,
+    {
+        let mut out = [u128::MAX; 8];
+        for (i, p) in parts.enumerate() {
+            out[i] = p.measure().finite().into();
+        }
+        out
+    }
+
+    #[test]
+    fn discrete_parts_within_one() {
+        let x = FiniteInterval::closed(0_i64, 99);
+        let s = sizes(x.partition_equal(7).unwrap());
+        assert_eq!(s[..7].iter().sum::<u128>(), 100);
+        assert!(s[..7].iter().all(|&n| n == 14 || n == 15), "{s:?}");
+        assert_eq!(s[7], u128::MAX);
+    }
+
+    #[test]
+    fn parts_cover_in_order() {
+        let x = FiniteInterval::closed(0_i32, 9);
+        let mut next = 0;
+        for p in x.partition_equal(4).unwrap() {
+            let (l, r) = p.view_raw().unwrap();
+            assert_eq!(*l.value(), next);
+            next = r.value() + 1;
+        }
+        assert_eq!(next, 10);
+    }
+
+    #[test]
+    fn more_parts_than_members() {
+        let x = FiniteInterval::closed(0_u8, 2);
+        let s = sizes(x.partition_equal(5).unwrap());
+        assert_eq!(&s[..5], &[0, 0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn disjoint_pieces() {
+        let x = MaybeDisjoint::from_pair(
+            EnumInterval::closed(0_i64, 4),
+            EnumInterval::closed(100, 104),
+        );
+        let parts: [_; 2] = {
+            let mut it = x.partition_equal(2).unwrap();
+            [it.next().unwrap(), it.next().unwrap()]
+        };
+        assert_eq!(
+            parts[0],
+            MaybeDisjoint::from_interval(EnumInterval::closed(0, 4))
+        );
+        assert_eq!(
+            parts[1],
+            MaybeDisjoint::from_interval(EnumInterval::closed(100, 104))
+        );
+    }
+
+    #[test]
+    fn continuous_parts_balance() {
+        let x = MaybeDisjoint::from_pair(
+            EnumInterval::closed(0.0_f64, 1.0),
+            EnumInterval::closed(5.0, 15.0),
+        );
+        let mut n = 0;
+        for p in x.partition_equal(4).unwrap() {
+            let m = p.measure().finite();
+            assert!((m - 2.75).abs() < 1e-12, "{m}");
+            n += 1;
+        }
+        assert_eq!(n, 4);
+    }
+
+    #[test]
+    fn weighted_parts() {
+        let x = FiniteInterval::closed(0.0_f64, 10.0);
+        let w = [1.0, 0.0, 3.0];
+        let mut it = x.partition_weighted(&w).unwrap();
+        let a = it.next().unwrap();
+        let b = it.next().unwrap();
+        let c = it.next().unwrap();
+        assert!(it.next().is_none());
+        assert!((a.measure().finite() - 2.5).abs() < 1e-12);
+        assert_eq!(b, FiniteInterval::empty());
+        assert!((c.measure().finite() - 7.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let x = FiniteInterval::closed(0_i32, 9);
+        assert_eq!(x.partition_equal(0).err(), Some(MathError::Domain));
+        assert_eq!(x.partition_weighted(&[]).err(), Some(MathError::Domain));
+        assert_eq!(x.partition_weighted(&[0, 0]).err(), Some(MathError::Domain));
+        assert_eq!(
+            x.partition_weighted(&[1, u64::MAX]).err(),
+            Some(MathError::Range)
+        );
+        assert_eq!(
+            FiniteInterval::<i32>::empty().partition_equal(2).err(),
+            Some(MathError::Domain)
+        );
+        assert_eq!(
+            EnumInterval::closed_unbound(0.0).partition_equal(2).err(),
+            Some(MathError::Domain)
+        );
+        assert_eq!(
+            FiniteInterval::closed(0.0, 1.0)
+                .partition_weighted(&[1.0, -1.0])
+                .err(),
+            Some(MathError::Domain)
+        );
+    }
+}
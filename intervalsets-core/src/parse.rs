@@ -4,13 +4,200 @@
 //! Grammar matches what `Display` emits, so `s.parse::<EnumInterval<T>>()`
 //! round-trips against `format!("{x}")`. See the table on
 //! [`EnumInterval`](crate::sets::EnumInterval)'s `FromStr` impl below.
+//!
+//! Other dialects (ISO 31-11 brackets, `∞`, Rust ranges, ...) are
+//! parsed through [`FromNotation`] with a [`Notation`] choosing which
+//! are accepted.
 
+use core::ops::Range;
 use core::str::FromStr;
 
-use crate::error::ParseIntervalError;
+use crate::bound::{BoundType, Side};
+use crate::error::{Expected, ParseIntervalError, SyntaxError};
 use crate::factory::{TryFiniteFactory, TryHalfBoundedFactory, UnboundedFactory};
 use crate::numeric::Element;
-use crate::sets::{EnumInterval, FiniteInterval, HalfInterval};
+use crate::quote::{top_level, unquote};
+use crate::sets::{EnumInterval, FiniteInterval, HalfInterval, MaybeDisjoint};
+
+/// The interval dialects accepted by [`FromNotation`].
+///
+/// [`Notation::DISPLAY`] (the default) is the `Display` grammar that
+/// `FromStr` accepts. Each option adds a dialect on top of it:
+///
+/// | Option                | Accepts                                   |
+/// |-----------------------|-------------------------------------------|
+/// | `iso_brackets`        | ISO 31-11 reversed brackets: `]0, 10[`    |
+/// | `unicode_infinity`    | `-∞` / `−∞` on the left, `∞` / `+∞` on the right |
+/// | `inf_text`            | `-inf` / `-infinity` on the left, `inf` / `+inf` / `infinity` on the right, any case |
+/// | `rust_ranges`         | `0..10`, `0..=10`, `0..`, `..10`, `..=10`, `..` |
+/// | `semicolon`           | `;` as the separator: `[0; 10]`           |
+/// | `unicode_sets`        | `∪` between pieces, `∅` for empty, sets without braces |
+/// | `latex`               | `\infty` / `-\infty`, `\cup` between pieces, `\emptyset`, sets without braces |
+///
+/// With `semicolon`, a body containing `;` splits there, so elements
+/// may use decimal commas: `[0,5; 1,5]`.
+///
+/// Each [`Style`](crate::display::Style) writes text that its
+/// [`notation`](crate::display::Style::notation) reads back.
+///
+/// ```
+/// use intervalsets_core::prelude::*;
+/// use intervalsets_core::parse::{FromNotation, Notation};
+///
+/// let iso = Notation::DISPLAY.with_iso_brackets(true).with_unicode_infinity(true);
+/// let x = EnumInterval::<i32>::from_notation("]-∞, 5]", &iso).unwrap();
+/// assert_eq!(x, EnumInterval::unbound_closed(5));
+///
+/// let x = EnumInterval::<f64>::from_notation("0.5..=2.5", &Notation::ALL).unwrap();
+/// assert_eq!(x, EnumInterval::closed(0.5, 2.5));
+///
+/// assert!(EnumInterval::<i32>::from_notation("0..10", &Notation::DISPLAY).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Notation {
+    iso_brackets: bool,
+    unicode_infinity: bool,
+    inf_text: bool,
+    pub(crate) rust_ranges: bool,
+    semicolon: bool,
+    unicode_sets: bool,
+    latex: bool,
+}
+
+impl Notation {
+    /// Only the `Display` grammar.
+    pub const DISPLAY: Self = Self {
+        iso_brackets: false,
+        unicode_infinity: false,
+        inf_text: false,
+        rust_ranges: false,
+        semicolon: false,
+        unicode_sets: false,
+        latex: false,
+    };
+
+    /// Every dialect.
+    pub const ALL: Self = Self {
+        iso_brackets: true,
+        unicode_infinity: true,
+        inf_text: true,
+        rust_ranges: true,
+        semicolon: true,
+        unicode_sets: true,
+        latex: true,
+    };
+
+    /// Accept ISO 31-11 reversed brackets for open bounds.
+    pub const fn with_iso_brackets(self, on: bool) -> Self {
+        Self {
+            iso_brackets: on,
+            ..self
+        }
+    }
+
+    /// Accept `∞` for an unbounded side.
+    pub const fn with_unicode_infinity(self, on: bool) -> Self {
+        Self {
+            unicode_infinity: on,
+            ..self
+        }
+    }
+
+    /// Accept `inf` / `infinity` for an unbounded side.
+    pub const fn with_inf_text(self, on: bool) -> Self {
+        Self {
+            inf_text: on,
+            ..self
+        }
+    }
+
+    /// Accept Rust range syntax.
+    pub const fn with_rust_ranges(self, on: bool) -> Self {
+        Self {
+            rust_ranges: on,
+            ..self
+        }
+    }
+
+    /// Accept `;` as the bound separator.
+    pub const fn with_semicolon(self, on: bool) -> Self {
+        Self {
+            semicolon: on,
+            ..self
+        }
+    }
+
+    /// Accept `∪` and `∅` in sets, and sets without braces.
+    pub const fn with_unicode_sets(self, on: bool) -> Self {
+        Self {
+            unicode_sets: on,
+            ..self
+        }
+    }
+
+    /// Accept LaTeX `\infty`, `\cup` and `\emptyset`, and sets without
+    /// braces.
+    pub const fn with_latex(self, on: bool) -> Self {
+        Self { latex: on, ..self }
+    }
+
+    fn is_opener(&self, c: char) -> bool {
+        matches!(c, '[' | '(') || (self.iso_brackets && c == ']')
+    }
+
+    fn is_closer(&self, c: char) -> bool {
+        matches!(c, ']' | ')') || (self.iso_brackets && c == '[')
+    }
+
+    fn is_empty_set(&self, text: &str) -> bool {
+        text == "{}" || (self.unicode_sets && text == "∅") || (self.latex && text == "\\emptyset")
+    }
+
+    fn bare_sets(&self) -> bool {
+        self.unicode_sets || self.latex
+    }
+
+    /// The length of the union separator starting `text`, if any.
+    fn union_len(&self, text: &str) -> Option<usize> {
+        let sep = |on: bool, sep: &str| (on && text.starts_with(sep)).then_some(sep.len());
+        sep(true, "U")
+            .or_else(|| sep(self.unicode_sets, "∪"))
+            .or_else(|| sep(self.latex, "\\cup"))
+    }
+
+    /// `None` if `text` marks an unbounded `side`, else the element text.
+    pub(crate) fn finite_text<'a>(&self, text: &'a str, side: Side) -> Option<&'a str> {
+        let unicode: &[&str] = match side {
+            Side::Left => &["-∞", "\u{2212}∞"],
+            Side::Right => &["∞", "+∞"],
+        };
+        let words: &[&str] = match side {
+            Side::Left => &["-inf", "-infinity"],
+            Side::Right => &["inf", "+inf", "infinity", "+infinity"],
+        };
+        let latex: &[&str] = match side {
+            Side::Left => &["-\\infty"],
+            Side::Right => &["\\infty", "+\\infty"],
+        };
+        let unbounded = text == ".."
+            || (self.unicode_infinity && unicode.contains(&text))
+            || (self.inf_text && words.iter().any(|w| w.eq_ignore_ascii_case(text)))
+            || (self.latex && latex.contains(&text));
+        (!unbounded).then_some(text)
+    }
+}
+
+/// Parsing under a chosen [`Notation`].
+///
+/// `FromStr` on the same types is `from_notation` with
+/// [`Notation::DISPLAY`].
+pub trait FromNotation: Sized {
+    /// The error returned for rejected input.
+    type Err;
+
+    /// Parses `s`, accepting the dialects enabled in `notation`.
+    fn from_notation(s: &str, notation: &Notation) -> Result<Self, Self::Err>;
+}
 
 /// Parses an [`EnumInterval`] from its `Display` form.
 ///
@@ -31,6 +218,12 @@ use crate::sets::{EnumInterval, FiniteInterval, HalfInterval};
 ///
 /// Whitespace around delimiters and the comma is ignored.
 ///
+/// An element may be quoted, `["a, b", c]`, and `Display` quotes any
+/// element whose text holds a separator, bracket or quote, so every
+/// `T: Display + FromStr` round-trips. Text containing `"` is fenced
+/// with `#`s like a Rust raw string: `#"say "hi""#`. Unquoted elements
+/// may also nest separators in balanced brackets: `[(1, 2), (3, 4)]`.
+///
 /// Note: the unbounded side **must** use an open delimiter (`(` on
 /// the left, `)` on the right) — `[.., x]` is a syntax error, since
 /// infinity is never "included." This matches `Display`'s output.
@@ -75,64 +268,48 @@ where
     type Err = ParseIntervalError<<T as FromStr>::Err>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
+        Self::from_notation(s, &Notation::DISPLAY)
+    }
+}
 
-        if s == "{}" {
+impl<T> FromNotation for EnumInterval<T>
+where
+    T: Element + FromStr,
+{
+    type Err = ParseIntervalError<<T as FromStr>::Err>;
+
+    fn from_notation(s: &str, notation: &Notation) -> Result<Self, Self::Err> {
+        let t = s.trim();
+
+        if notation.is_empty_set(t) {
             return Ok(Self::empty());
         }
 
         // `{...}` (other than `{}`) is the set form for `MaybeDisjoint` /
         // `IntervalSet`; a single interval can't be parsed from it.
-        if s.starts_with('{') {
-            return Err(ParseIntervalError::Syntax);
+        if t.starts_with('{') {
+            return Err(syntax(Expected::Interval, span_of(s, t)));
         }
 
-        let (open, body, close) = peel(s).ok_or(ParseIntervalError::Syntax)?;
-        let (lhs_str, rhs_str) = split_at_comma(body).ok_or(ParseIntervalError::Syntax)?;
-        let lhs_str = lhs_str.trim();
-        let rhs_str = rhs_str.trim();
+        if notation.rust_ranges && !t.starts_with(|c| notation.is_opener(c)) {
+            return parse_range(s, t);
+        }
 
-        match (lhs_str, rhs_str) {
-            ("..", "..") => {
-                if open != '(' || close != ')' {
-                    return Err(ParseIntervalError::Syntax);
-                }
-                Ok(Self::unbounded())
-            }
-            ("..", r) => {
-                if open != '(' {
-                    return Err(ParseIntervalError::Syntax);
-                }
-                let val = T::from_str(r).map_err(ParseIntervalError::Element)?;
-                match close {
-                    ']' => Ok(Self::try_unbound_closed(val)?),
-                    ')' => Ok(Self::try_unbound_open(val)?),
-                    _ => Err(ParseIntervalError::Syntax),
-                }
-            }
-            (l, "..") => {
-                if close != ')' {
-                    return Err(ParseIntervalError::Syntax);
-                }
-                let val = T::from_str(l).map_err(ParseIntervalError::Element)?;
-                match open {
-                    '[' => Ok(Self::try_closed_unbound(val)?),
-                    '(' => Ok(Self::try_open_unbound(val)?),
-                    _ => Err(ParseIntervalError::Syntax),
-                }
-            }
-            (l, r) => {
-                let lhs = T::from_str(l).map_err(ParseIntervalError::Element)?;
-                let rhs = T::from_str(r).map_err(ParseIntervalError::Element)?;
-                match (open, close) {
-                    ('[', ']') => Ok(Self::try_closed(lhs, rhs)?),
-                    ('(', ')') => Ok(Self::try_open(lhs, rhs)?),
-                    ('[', ')') => Ok(Self::try_closed_open(lhs, rhs)?),
-                    ('(', ']') => Ok(Self::try_open_closed(lhs, rhs)?),
-                    _ => Err(ParseIntervalError::Syntax),
-                }
-            }
+        let (open, body, close) = peel(s, t, notation)?;
+        let (lhs_str, rhs_str) = split_bounds(s, body, notation)?;
+        let lhs = notation.finite_text(lhs_str.trim(), Side::Left);
+        let rhs = notation.finite_text(rhs_str.trim(), Side::Right);
+        // Infinity is never "included": an unbounded side needs an open
+        // delimiter.
+        if lhs.is_none() && open == BoundType::Closed {
+            let side = Expected::OpenOnUnboundedSide(Side::Left);
+            return Err(syntax(side, span_of(s, &t[..1])));
         }
+        if rhs.is_none() && close == BoundType::Closed {
+            let side = Expected::OpenOnUnboundedSide(Side::Right);
+            return Err(syntax(side, span_of(s, &t[t.len() - 1..])));
+        }
+        build(lhs.map(|l| (l, open)), rhs.map(|r| (r, close)))
     }
 }
 
@@ -146,8 +323,20 @@ where
     type Err = ParseIntervalError<<T as FromStr>::Err>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let inner = EnumInterval::<T>::from_str(s)?;
-        FiniteInterval::try_from(inner).map_err(|_| ParseIntervalError::Syntax)
+        Self::from_notation(s, &Notation::DISPLAY)
+    }
+}
+
+impl<T> FromNotation for FiniteInterval<T>
+where
+    T: Element + FromStr,
+{
+    type Err = ParseIntervalError<<T as FromStr>::Err>;
+
+    fn from_notation(s: &str, notation: &Notation) -> Result<Self, Self::Err> {
+        let inner = EnumInterval::<T>::from_notation(s, notation)?;
+        FiniteInterval::try_from(inner)
+            .map_err(|_| syntax(Expected::FiniteInterval, span_of(s, s.trim())))
     }
 }
 
@@ -161,32 +350,283 @@ where
     type Err = ParseIntervalError<<T as FromStr>::Err>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let inner = EnumInterval::<T>::from_str(s)?;
-        HalfInterval::try_from(inner).map_err(|_| ParseIntervalError::Syntax)
+        Self::from_notation(s, &Notation::DISPLAY)
     }
 }
 
-fn peel(s: &str) -> Option<(char, &str, char)> {
-    let mut chars = s.chars();
-    let open = chars.next()?;
-    if open != '[' && open != '(' {
-        return None;
+impl<T> FromNotation for HalfInterval<T>
+where
+    T: Element + FromStr,
+{
+    type Err = ParseIntervalError<<T as FromStr>::Err>;
+
+    fn from_notation(s: &str, notation: &Notation) -> Result<Self, Self::Err> {
+        let inner = EnumInterval::<T>::from_notation(s, notation)?;
+        HalfInterval::try_from(inner)
+            .map_err(|_| syntax(Expected::HalfInterval, span_of(s, s.trim())))
     }
-    let close = s.chars().next_back()?;
-    if close != ']' && close != ')' {
-        return None;
+}
+
+/// Parses a [`MaybeDisjoint`] from its `Display` form: a single
+/// interval, optionally in braces, or two pieces as `{a U b}`.
+/// Connected pieces are merged, as by [`MaybeDisjoint::from_pair`];
+/// more than two pieces yield [`ParseIntervalError::Syntax`].
+///
+/// ```
+/// use intervalsets_core::prelude::*;
+///
+/// let x: MaybeDisjoint<i32> = "{[10, 15] U [0, 5]}".parse().unwrap();
+/// assert_eq!(x, MaybeDisjoint::from_pair(EnumInterval::closed(0, 5), EnumInterval::closed(10, 15)));
+///
+/// let x: MaybeDisjoint<i32> = "[0, 5]".parse().unwrap();
+/// assert_eq!(x, MaybeDisjoint::from(EnumInterval::closed(0, 5)));
+/// ```
+impl<T> FromStr for MaybeDisjoint<T>
+where
+    T: Element + FromStr,
+{
+    type Err = ParseIntervalError<<T as FromStr>::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_notation(s, &Notation::DISPLAY)
     }
-    let body_start = open.len_utf8();
-    let body_end = s.len() - close.len_utf8();
-    if body_start > body_end {
-        return None;
+}
+
+impl<T> FromNotation for MaybeDisjoint<T>
+where
+    T: Element + FromStr,
+{
+    type Err = ParseIntervalError<<T as FromStr>::Err>;
+
+    fn from_notation(s: &str, notation: &Notation) -> Result<Self, Self::Err> {
+        let Some(mut pieces) = set_pieces(s, notation) else {
+            return EnumInterval::from_notation(s, notation).map(Self::from_interval);
+        };
+        let parse = |piece: Option<(usize, &str)>| {
+            piece
+                .map(|(at, p)| parse_piece::<EnumInterval<T>, _>(at, p, notation))
+                .transpose()
+        };
+        let a = parse(pieces.next())?;
+        let b = parse(pieces.next())?;
+        if let Some((at, extra)) = pieces.next() {
+            return Err(syntax(Expected::AtMostTwoPieces, at..at + extra.len()));
+        }
+        Ok(Self::new(a, b))
     }
-    Some((open, &s[body_start..body_end], close))
 }
 
-fn split_at_comma(body: &str) -> Option<(&str, &str)> {
-    let idx = body.find(',')?;
-    Some((&body[..idx], &body[idx + 1..]))
+/// Builds an interval from element text, `None` being an unbounded
+/// side.
+#[allow(clippy::type_complexity)]
+fn build<T>(
+    lhs: Option<(&str, BoundType)>,
+    rhs: Option<(&str, BoundType)>,
+) -> Result<EnumInterval<T>, ParseIntervalError<<T as FromStr>::Err>>
+where
+    T: Element + FromStr,
+{
+    let elem = |text: &str| T::from_str(unquote(text)).map_err(ParseIntervalError::Element);
+    use BoundType::{Closed, Open};
+    Ok(match (lhs, rhs) {
+        (None, None) => EnumInterval::unbounded(),
+        (None, Some((r, Closed))) => EnumInterval::try_unbound_closed(elem(r)?)?,
+        (None, Some((r, Open))) => EnumInterval::try_unbound_open(elem(r)?)?,
+        (Some((l, Closed)), None) => EnumInterval::try_closed_unbound(elem(l)?)?,
+        (Some((l, Open)), None) => EnumInterval::try_open_unbound(elem(l)?)?,
+        (Some((l, lk)), Some((r, rk))) => {
+            let (l, r) = (elem(l)?, elem(r)?);
+            match (lk, rk) {
+                (Closed, Closed) => EnumInterval::try_closed(l, r)?,
+                (Open, Open) => EnumInterval::try_open(l, r)?,
+                (Closed, Open) => EnumInterval::try_closed_open(l, r)?,
+                (Open, Closed) => EnumInterval::try_open_closed(l, r)?,
+            }
+        }
+    })
+}
+
+/// Parses Rust range syntax: `a..b`, `a..=b`, `a..`, `..b`, `..=b`, `..`.
+/// `t` is the trimmed input within `s`.
+fn parse_range<T>(
+    s: &str,
+    t: &str,
+) -> Result<EnumInterval<T>, ParseIntervalError<<T as FromStr>::Err>>
+where
+    T: Element + FromStr,
+{
+    let dots = top_level(t, true)
+        .zip(top_level(t, true).skip(1))
+        .find(|&((i, a), (j, b))| a == '.' && b == '.' && j == i + 1);
+    let Some(((idx, _), _)) = dots else {
+        let first = t.chars().next().map_or(0, char::len_utf8);
+        return Err(syntax(Expected::OpenDelimiter, span_of(s, &t[..first])));
+    };
+    let lhs = t[..idx].trim();
+    let (rhs, close) = match t[idx + 2..].strip_prefix('=') {
+        Some(rhs) => (rhs.trim(), BoundType::Closed),
+        None => (t[idx + 2..].trim(), BoundType::Open),
+    };
+    if rhs.is_empty() && close == BoundType::Closed {
+        return Err(syntax(Expected::RangeEnd, span_of(s, &t[t.len()..])));
+    }
+    let lhs = (!lhs.is_empty()).then_some((lhs, BoundType::Closed));
+    let rhs = (!rhs.is_empty()).then_some((rhs, close));
+    build(lhs, rhs)
+}
+
+/// Splits the delimiters off the trimmed input `t` within `s`.
+fn peel<'a, E>(
+    s: &str,
+    t: &'a str,
+    notation: &Notation,
+) -> Result<(BoundType, &'a str, BoundType), ParseIntervalError<E>> {
+    let Some(first) = t.chars().next() else {
+        return Err(syntax(Expected::OpenDelimiter, span_of(s, t)));
+    };
+    let open = match first {
+        '[' => BoundType::Closed,
+        '(' => BoundType::Open,
+        ']' if notation.iso_brackets => BoundType::Open,
+        _ => {
+            return Err(syntax(
+                Expected::OpenDelimiter,
+                span_of(s, &t[..first.len_utf8()]),
+            ))
+        }
+    };
+    let body_start = first.len_utf8();
+    let last = t.chars().next_back().filter(|_| t.len() > body_start);
+    let close = match last {
+        Some(']') => BoundType::Closed,
+        Some(')') => BoundType::Open,
+        Some('[') if notation.iso_brackets => BoundType::Open,
+        Some(c) => {
+            let end = &t[t.len() - c.len_utf8()..];
+            return Err(syntax(Expected::CloseDelimiter, span_of(s, end)));
+        }
+        None => return Err(syntax(Expected::CloseDelimiter, span_of(s, &t[t.len()..]))),
+    };
+    Ok((open, &t[body_start..t.len() - 1], close))
+}
+
+/// Splits the bounds of `body` within `s` at the separator.
+fn split_bounds<'a, E>(
+    s: &str,
+    body: &'a str,
+    notation: &Notation,
+) -> Result<(&'a str, &'a str), ParseIntervalError<E>> {
+    // With `;` accepted, a body holding one splits there, leaving any
+    // commas (decimal commas) to the elements.
+    let find = |sep| {
+        top_level(body, true)
+            .find(|&(_, c)| c == sep)
+            .map(|(i, _)| i)
+    };
+    let idx = if notation.semicolon {
+        find(';').or_else(|| find(','))
+    } else {
+        find(',')
+    };
+    let idx = idx.ok_or_else(|| syntax(Expected::Separator, span_of(s, body)))?;
+    Ok((&body[..idx], &body[idx + 1..]))
+}
+
+/// Parses one `(at, piece)` item of [`set_pieces`], shifting any
+/// syntax error's span to locate it in the whole set literal. An empty
+/// piece is an [`Expected::Piece`] error.
+#[doc(hidden)]
+pub fn parse_piece<P, E>(
+    at: usize,
+    piece: &str,
+    notation: &Notation,
+) -> Result<P, ParseIntervalError<E>>
+where
+    P: FromNotation<Err = ParseIntervalError<E>>,
+{
+    if piece.is_empty() {
+        return Err(syntax(Expected::Piece, at..at));
+    }
+    P::from_notation(piece, notation).map_err(|e| e.offset(at))
+}
+
+fn syntax<E>(expected: Expected, span: Range<usize>) -> ParseIntervalError<E> {
+    ParseIntervalError::Syntax(SyntaxError::new(expected, span))
+}
+
+/// The byte range of `part`, a subslice of `whole`, within `whole`.
+fn span_of(whole: &str, part: &str) -> Range<usize> {
+    let start = part.as_ptr() as usize - whole.as_ptr() as usize;
+    start..start + part.len()
+}
+
+/// Splits a set literal `{a U b U ...}` into its pieces' text.
+///
+/// Returns `None` if `s` is not wrapped in braces, unless `notation`
+/// accepts sets without them. Pieces are separated by a union (`U`, or
+/// `∪` / `\cup` where accepted) surrounded by whitespace outside any
+/// piece's delimiters, and are yielded trimmed with their byte offset
+/// in `s`; empty pieces (a leading, trailing or doubled separator) are
+/// yielded as `""`. `{}` (or `∅` / `\emptyset`) yields no pieces.
+///
+/// This is the set grammar shared by [`MaybeDisjoint`] and
+/// `IntervalSet`.
+#[doc(hidden)]
+pub fn set_pieces<'a>(s: &'a str, notation: &Notation) -> Option<SetPieces<'a>> {
+    let t = s.trim();
+    let body = match t.strip_prefix('{').and_then(|b| b.strip_suffix('}')) {
+        Some(body) => body.trim(),
+        None if notation.bare_sets() => t,
+        None => return None,
+    };
+    let empty = body.is_empty() || notation.is_empty_set(body);
+    Some(SetPieces {
+        input: s,
+        rest: (!empty).then_some(body),
+        notation: *notation,
+    })
+}
+
+impl<'a> SetPieces<'a> {
+    fn located(&self, piece: &'a str) -> (usize, &'a str) {
+        (span_of(self.input, piece).start, piece)
+    }
+}
+
+/// Iterator over the pieces of a set literal; see [`set_pieces`].
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct SetPieces<'a> {
+    input: &'a str,
+    rest: Option<&'a str>,
+    notation: Notation,
+}
+
+impl<'a> Iterator for SetPieces<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let rest = self.rest?;
+        let bytes = rest.as_bytes();
+        let mut inside = false;
+        for (i, c) in top_level(rest, false) {
+            if inside {
+                inside = !self.notation.is_closer(c);
+            } else if self.notation.is_opener(c) {
+                inside = true;
+            } else if let Some(len) = self.notation.union_len(&rest[i..]) {
+                let spaced = i > 0
+                    && bytes[i - 1].is_ascii_whitespace()
+                    && bytes.get(i + len).is_some_and(u8::is_ascii_whitespace);
+                if spaced {
+                    self.rest = Some(&rest[i + len..]);
+                    return Some(self.located(rest[..i].trim()));
+                }
+            }
+        }
+        self.rest = None;
+        Some(self.located(rest.trim()))
+    }
 }
 
 #[cfg(test)]
@@ -195,6 +635,7 @@ mod tests {
 
     use super::*;
     use crate::factory::{FiniteFactory, HalfBoundedFactory, UnboundedFactory};
+    use crate::sets::MaybeDisjoint;
 
     // ---- happy path: every Display form round-trips ----
 
@@ -288,10 +729,10 @@ mod tests {
     #[test]
     fn finite_impl_rejects_unbounded() {
         let r: Result<FiniteInterval<i32>, _> = "[0, ..)".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
 
         let r: Result<FiniteInterval<i32>, _> = "(.., ..)".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
@@ -300,10 +741,10 @@ mod tests {
         assert_eq!(x, HalfInterval::closed_unbound(0));
 
         let r: Result<HalfInterval<i32>, _> = "[0, 10]".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
 
         let r: Result<HalfInterval<i32>, _> = "{}".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     // ---- negative tests: syntax errors ----
@@ -313,41 +754,41 @@ mod tests {
         // `{[0, 5], [10, 15]}` is MaybeDisjoint / IntervalSet syntax,
         // not a single interval.
         let r: Result<EnumInterval<i32>, _> = "{[0, 5], [10, 15]}".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
     fn rejects_closed_bracket_on_unbounded_side() {
         // `[.., x]` — closed bracket on the unbounded side is invalid.
         let r: Result<EnumInterval<i32>, _> = "[.., 5]".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
 
         let r: Result<EnumInterval<i32>, _> = "[0, ..]".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
 
         let r: Result<EnumInterval<i32>, _> = "[.., ..]".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
     fn rejects_missing_delims() {
         let r: Result<EnumInterval<i32>, _> = "0, 10".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
     fn rejects_missing_comma() {
         let r: Result<EnumInterval<i32>, _> = "[0 10]".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
     fn rejects_garbage() {
         let r: Result<EnumInterval<i32>, _> = "".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
 
         let r: Result<EnumInterval<i32>, _> = "[]".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     // ---- negative tests: element and bound errors ----
@@ -369,4 +810,264 @@ mod tests {
         let r: Result<EnumInterval<f64>, _> = "[NaN, 0]".parse();
         assert!(matches!(r, Err(ParseIntervalError::InvalidElement)));
     }
+
+    // ---- notations ----
+
+    fn parse_all<T: Element + FromStr>(s: &str) -> EnumInterval<T>
+    where
+        T::Err: core::fmt::Debug,
+    {
+        EnumInterval::from_notation(s, &Notation::ALL).unwrap()
+    }
+
+    #[test]
+    fn iso_reversed_brackets() {
+        let iso = Notation::DISPLAY.with_iso_brackets(true);
+        let x: EnumInterval<i32> = EnumInterval::from_notation("]0, 10[", &iso).unwrap();
+        assert_eq!(x, EnumInterval::open(0, 10));
+        let x: EnumInterval<f64> = EnumInterval::from_notation("[0, 10[", &iso).unwrap();
+        assert_eq!(x, EnumInterval::closed_open(0.0, 10.0));
+        let r: Result<EnumInterval<i32>, _> = "]0, 10[".parse();
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
+    }
+
+    #[test]
+    fn infinity_markers() {
+        assert_eq!(parse_all::<i32>("(-∞, 5]"), EnumInterval::unbound_closed(5));
+        assert_eq!(
+            parse_all::<i32>("(\u{2212}∞, +∞)"),
+            EnumInterval::unbounded()
+        );
+        assert_eq!(
+            parse_all::<f64>("[0, INF)"),
+            EnumInterval::closed_unbound(0.0)
+        );
+        assert_eq!(
+            parse_all::<f64>("(-Infinity, 0)"),
+            EnumInterval::unbound_open(0.0)
+        );
+        // Infinity is never included, in any spelling.
+        let r = EnumInterval::<i32>::from_notation("[-∞, 5]", &Notation::ALL);
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
+        // Without `inf_text`, `inf` is element text that `f64` rejects.
+        let r: Result<EnumInterval<f64>, _> = "[0, inf)".parse();
+        assert!(matches!(r, Err(ParseIntervalError::InvalidElement)));
+        let unicode_only = Notation::DISPLAY.with_unicode_infinity(true);
+        let r = EnumInterval::<f64>::from_notation("[0, inf)", &unicode_only);
+        assert!(matches!(r, Err(ParseIntervalError::InvalidElement)));
+    }
+
+    #[test]
+    fn rust_ranges() {
+        assert_eq!(parse_all::<i32>("0..10"), EnumInterval::closed_open(0, 10));
+        assert_eq!(parse_all::<i32>("-5..=-1"), EnumInterval::closed(-5, -1));
+        assert_eq!(
+            parse_all::<f64>("1.5..2.5"),
+            EnumInterval::closed_open(1.5, 2.5)
+        );
+        assert_eq!(parse_all::<i32>("3.."), EnumInterval::closed_unbound(3));
+        assert_eq!(parse_all::<i32>("..3"), EnumInterval::unbound_open(3));
+        assert_eq!(parse_all::<i32>("..=3"), EnumInterval::unbound_closed(3));
+        assert_eq!(parse_all::<i32>(" .. "), EnumInterval::unbounded());
+        let r = EnumInterval::<i32>::from_notation("0..=", &Notation::ALL);
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
+        let r = EnumInterval::<i32>::from_notation("10..0", &Notation::ALL);
+        assert!(matches!(r, Err(ParseIntervalError::InvalidBoundPair)));
+    }
+
+    #[test]
+    fn semicolon_separator() {
+        assert_eq!(parse_all::<i32>("[0; 10]"), EnumInterval::closed(0, 10));
+        assert_eq!(parse_all::<i32>("[0, 10]"), EnumInterval::closed(0, 10));
+        // Decimal commas belong to the element.
+        let r = EnumInterval::<f64>::from_notation("[0,5; 1,5]", &Notation::ALL);
+        assert!(matches!(r, Err(ParseIntervalError::Element(_))));
+        let r: Result<EnumInterval<i32>, _> = "[0; 10]".parse();
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
+    }
+
+    #[test]
+    fn variant_impls_take_notation() {
+        let x = FiniteInterval::<i32>::from_notation("0..=10", &Notation::ALL).unwrap();
+        assert_eq!(x, FiniteInterval::closed(0, 10));
+        let x = HalfInterval::<i32>::from_notation("[0, ∞)", &Notation::ALL).unwrap();
+        assert_eq!(x, HalfInterval::closed_unbound(0));
+    }
+
+    // ---- MaybeDisjoint ----
+
+    #[test]
+    fn maybe_disjoint_round_trip() {
+        let cases = [
+            MaybeDisjoint::empty(),
+            MaybeDisjoint::from(EnumInterval::closed(0, 5)),
+            MaybeDisjoint::from_pair(EnumInterval::unbound_open(0), EnumInterval::closed(5, 10)),
+        ];
+        for x in cases {
+            let printed = std::format!("{x}");
+            assert_eq!(
+                printed.parse::<MaybeDisjoint<i32>>().unwrap(),
+                x,
+                "{printed}"
+            );
+        }
+    }
+
+    #[test]
+    fn maybe_disjoint_merges_and_limits_pieces() {
+        let x: MaybeDisjoint<i32> = "{[0, 5] U [3, 8]}".parse().unwrap();
+        assert_eq!(x, MaybeDisjoint::from(EnumInterval::closed(0, 8)));
+        let x: MaybeDisjoint<i32> = "{[0, 5]}".parse().unwrap();
+        assert_eq!(x, MaybeDisjoint::from(EnumInterval::closed(0, 5)));
+        let r: Result<MaybeDisjoint<i32>, _> = "{[0, 1] U [3, 4] U [6, 7]}".parse();
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
+        let r: Result<MaybeDisjoint<i32>, _> = "{[0, 1] U }".parse();
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
+
+        let x = MaybeDisjoint::<i32>::from_notation("{]0, 5[ U 10..}", &Notation::ALL).unwrap();
+        assert_eq!(
+            x,
+            MaybeDisjoint::from_pair(EnumInterval::open(0, 5), EnumInterval::closed_unbound(10))
+        );
+    }
+
+    fn syntax_at<T: Element + FromStr>(s: &str) -> (Expected, Range<usize>) {
+        match EnumInterval::<T>::from_str(s) {
+            Err(ParseIntervalError::Syntax(e)) => (e.expected(), e.span()),
+            _ => panic!("expected a syntax error for {s:?}"),
+        }
+    }
+
+    #[test]
+    fn syntax_errors_locate_the_problem() {
+        assert_eq!(syntax_at::<i32>(""), (Expected::OpenDelimiter, 0..0));
+        assert_eq!(syntax_at::<i32>("  0, 5]"), (Expected::OpenDelimiter, 2..3));
+        assert_eq!(syntax_at::<i32>("[0, 5"), (Expected::CloseDelimiter, 4..5));
+        assert_eq!(syntax_at::<i32>("["), (Expected::CloseDelimiter, 1..1));
+        assert_eq!(syntax_at::<i32>(" [0 5] "), (Expected::Separator, 2..5));
+        assert_eq!(
+            syntax_at::<i32>("[.., 5]"),
+            (Expected::OpenOnUnboundedSide(Side::Left), 0..1)
+        );
+        assert_eq!(
+            syntax_at::<i32>("(0, ..]"),
+            (Expected::OpenOnUnboundedSide(Side::Right), 6..7)
+        );
+        assert_eq!(syntax_at::<i32>(" {[0, 1]}"), (Expected::Interval, 1..9));
+
+        let r = "0..=".parse::<EnumInterval<i32>>();
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
+        let r = EnumInterval::<i32>::from_notation(" 0..=", &Notation::ALL);
+        assert_eq!(r.unwrap_err().span(), Some(5..5));
+
+        let r = "[0, 5]".parse::<HalfInterval<i32>>();
+        assert_eq!(r.unwrap_err().span(), Some(0..6));
+    }
+
+    #[test]
+    fn syntax_errors_in_set_pieces() {
+        let err = "{[0, 5] U [10 15]}"
+            .parse::<MaybeDisjoint<i32>>()
+            .unwrap_err();
+        assert_eq!(err.span(), Some(11..16));
+        assert_eq!(
+            std::format!("{err}"),
+            "malformed interval syntax: expected ',' between bounds at 11..16"
+        );
+
+        let r = "{[0, 1] U  U [3, 4]}".parse::<MaybeDisjoint<i32>>();
+        assert!(matches!(
+            r,
+            Err(ParseIntervalError::Syntax(e)) if e.expected() == Expected::Piece && e.span() == (9..9)
+        ));
+
+        let r = "{[0, 1] U [3, 4] U [6, 7]}".parse::<MaybeDisjoint<i32>>();
+        assert_eq!(r.unwrap_err().span(), Some(19..25));
+
+        // Element failures carry no span.
+        assert_eq!(
+            "[a, 5]".parse::<EnumInterval<i32>>().unwrap_err().span(),
+            None
+        );
+    }
+
+    /// An integer displayed with thousands separators, `1,234`.
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Grouped(i32);
+
+    impl core::fmt::Display for Grouped {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let n = self.0.unsigned_abs();
+            let sign = if self.0 < 0 { "-" } else { "" };
+            match (n / 1_000_000, n / 1000 % 1000, n % 1000) {
+                (0, 0, u) => write!(f, "{sign}{u}"),
+                (0, t, u) => write!(f, "{sign}{t},{u:03}"),
+                (m, t, u) => write!(f, "{sign}{m},{t:03},{u:03}"),
+            }
+        }
+    }
+
+    impl FromStr for Grouped {
+        type Err = core::num::ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.replace(',', "").parse().map(Self)
+        }
+    }
+
+    impl Element for Grouped {
+        type Kind = crate::numeric::DiscreteKind;
+        type Measure = u64;
+
+        fn try_adjacent(&self, side: Side) -> Option<Self> {
+            self.0.try_adjacent(side).map(Self)
+        }
+
+        fn try_measure_finite(left: &Self, right: &Self) -> Option<u64> {
+            i32::try_measure_finite(&left.0, &right.0)
+        }
+    }
+
+    #[test]
+    fn quoted_elements_round_trip() {
+        let x = EnumInterval::closed(Grouped(-1_500), Grouped(2_000_000));
+        let printed = std::format!("{x}");
+        assert_eq!(printed, "[\"-1,500\", \"2,000,000\"]");
+        assert_eq!(printed.parse::<EnumInterval<Grouped>>().unwrap(), x);
+
+        let x = MaybeDisjoint::from_pair(
+            EnumInterval::unbound_closed(Grouped(-1)),
+            EnumInterval::closed_unbound(Grouped(1_000)),
+        );
+        let printed = std::format!("{x}");
+        assert_eq!(printed, "{(.., -1] U [\"1,000\", ..)}");
+        assert_eq!(printed.parse::<MaybeDisjoint<Grouped>>().unwrap(), x);
+
+        // Small values stay bare; quoting is accepted anyway.
+        let x: EnumInterval<i32> = "[\"1\", #\"5\"#)".parse().unwrap();
+        assert_eq!(x, EnumInterval::closed_open(1, 5));
+        // A quoted `..` is an element, not an unbounded side.
+        let r = "[\"..\", 5]".parse::<EnumInterval<i32>>();
+        assert!(matches!(r, Err(ParseIntervalError::Element(_))));
+    }
+
+    #[test]
+    fn nested_and_quoted_separators() {
+        let ranges = Notation::DISPLAY.with_rust_ranges(true);
+        let x = EnumInterval::<Grouped>::from_notation("\"1,000\"..=\"2,000\"", &ranges).unwrap();
+        assert_eq!(x, EnumInterval::closed(Grouped(1_000), Grouped(2_000)));
+
+        let semi = Notation::DISPLAY.with_semicolon(true);
+        let x = EnumInterval::<Grouped>::from_notation("[\"1;000\"; 2]", &semi);
+        assert!(matches!(x, Err(ParseIntervalError::Element(_))));
+        let x = EnumInterval::<Grouped>::from_notation("[1,000; 2,000]", &semi).unwrap();
+        assert_eq!(x, EnumInterval::closed(Grouped(1_000), Grouped(2_000)));
+    }
+
+    #[quickcheck]
+    fn check_grouped_round_trip(a: i32, b: i32) {
+        let x = EnumInterval::closed(Grouped(a.min(b)), Grouped(a.max(b)));
+        let printed = std::format!("{x}");
+        assert_eq!(printed.parse::<EnumInterval<Grouped>>().unwrap(), x);
+    }
 }
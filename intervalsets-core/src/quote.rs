@@ -0,0 +1,293 @@
+//! Quoting of element text, shared by `Display` and the parsers.
+//!
+//! An element whose `Display` text would be misread inside an interval
+//! — it contains a separator, bracket or quote, has surrounding
+//! whitespace, is empty, or reads as an unbounded marker such as `..` —
+//! is written in quotes: `"a, b"`. Text that itself contains `"` is
+//! fenced with `#`s like a Rust raw string: `#"say "hi""#`. Quoted text
+//! is taken verbatim, so parsing never unescapes and needs no
+//! allocation.
+
+use core::fmt::{self, Write};
+
+use crate::bound::Side;
+use crate::parse::Notation;
+
+/// Writes `value` with `f`'s precision, quoted if its text would be
+/// misread under `notation`.
+pub(crate) fn write_element<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    value: &T,
+    notation: &Notation,
+) -> fmt::Result {
+    let precision = f.precision();
+    let mut scan = Scan::default();
+    write_value(&mut scan, value, precision)?;
+    let Some(hashes) = scan.fence(notation) else {
+        return write_value(f, value, precision);
+    };
+    for _ in 0..hashes {
+        f.write_char('#')?;
+    }
+    f.write_char('"')?;
+    write_value(f, value, precision)?;
+    f.write_char('"')?;
+    for _ in 0..hashes {
+        f.write_char('#')?;
+    }
+    Ok(())
+}
+
+fn write_value<W, T>(w: &mut W, value: &T, precision: Option<usize>) -> fmt::Result
+where
+    W: Write + ?Sized,
+    T: fmt::Display,
+{
+    match precision {
+        Some(p) => write!(w, "{value:.p$}"),
+        None => write!(w, "{value}"),
+    }
+}
+
+/// The length of the quoted element at the start of `s`, if `s` starts
+/// with a terminated one.
+pub(crate) fn quoted_len(s: &str) -> Option<usize> {
+    let hashes = s.bytes().take_while(|&b| b == b'#').count();
+    let body = s[hashes..].strip_prefix('"')?;
+    let mut from = 0;
+    while let Some(i) = body[from..].find('"') {
+        let end = from + i + 1;
+        if body[end..]
+            .bytes()
+            .take(hashes)
+            .filter(|&b| b == b'#')
+            .count()
+            == hashes
+        {
+            return Some(hashes + 1 + end + hashes);
+        }
+        from = end;
+    }
+    None
+}
+
+/// The text of an element as written: the contents of a quoted
+/// element, or `s` itself.
+pub(crate) fn unquote(s: &str) -> &str {
+    match quoted_len(s) {
+        Some(len) if len == s.len() => {
+            let hashes = s.bytes().take_while(|&b| b == b'#').count();
+            &s[hashes + 1..s.len() - hashes - 1]
+        }
+        _ => s,
+    }
+}
+
+/// Chars of `s` with their byte offsets, skipping quoted elements and,
+/// when `nest`, anything inside `()`, `[]` or `{}`.
+pub(crate) fn top_level(s: &str, nest: bool) -> TopLevel<'_> {
+    TopLevel {
+        s,
+        pos: 0,
+        depth: 0,
+        nest,
+    }
+}
+
+/// Iterator returned by [`top_level`].
+pub(crate) struct TopLevel<'a> {
+    s: &'a str,
+    pos: usize,
+    depth: usize,
+    nest: bool,
+}
+
+impl Iterator for TopLevel<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<(usize, char)> {
+        loop {
+            let i = self.pos;
+            let c = self.s[i..].chars().next()?;
+            if matches!(c, '"' | '#') {
+                if let Some(len) = quoted_len(&self.s[i..]) {
+                    self.pos += len;
+                    continue;
+                }
+            }
+            self.pos += c.len_utf8();
+            if self.nest {
+                match c {
+                    '(' | '[' | '{' => {
+                        self.depth += 1;
+                        continue;
+                    }
+                    ')' | ']' | '}' if self.depth > 0 => {
+                        self.depth -= 1;
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+            if self.depth == 0 {
+                return Some((i, c));
+            }
+        }
+    }
+}
+
+/// Bytes of text kept to compare against unbounded markers, which are
+/// all shorter.
+const HEAD: usize = 16;
+
+/// Streams an element's text to decide whether, and how, to quote it.
+#[derive(Default)]
+struct Scan {
+    len: usize,
+    head: [u8; HEAD],
+    first: Option<char>,
+    last: Option<char>,
+    special: bool,
+    space: bool,
+    dotdot: bool,
+    quote: bool,
+    /// `#`s since the last `"`, if only `#`s have followed it.
+    run: Option<usize>,
+    max_run: usize,
+}
+
+impl Scan {
+    /// `None` if the text can be written bare, else the number of `#`s
+    /// to fence it with.
+    fn fence(&self, notation: &Notation) -> Option<usize> {
+        let edge_space = self.first.is_none_or(char::is_whitespace)
+            || self.last.is_some_and(char::is_whitespace);
+        // Range pieces have no brackets to shield their elements.
+        let range = notation.rust_ranges
+            && (self.space
+                || self.dotdot
+                || matches!(self.first, Some('.' | '='))
+                || self.last == Some('.'));
+        let quoted = edge_space || self.special || self.quote || range || self.is_marker(notation);
+        match (quoted, self.quote) {
+            (false, _) => None,
+            (true, false) => Some(0),
+            (true, true) => Some(self.max_run + 1),
+        }
+    }
+
+    /// Whether the text reads as an unbounded side under `notation`.
+    fn is_marker(&self, notation: &Notation) -> bool {
+        let Some(text) = self
+            .head
+            .get(..self.len)
+            .and_then(|h| core::str::from_utf8(h).ok())
+        else {
+            return false;
+        };
+        notation.finite_text(text, Side::Left).is_none()
+            || notation.finite_text(text, Side::Right).is_none()
+    }
+}
+
+impl Write for Scan {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if let Some(head) = self.head.get_mut(self.len..self.len + c.len_utf8()) {
+                c.encode_utf8(head);
+            }
+            self.len += c.len_utf8();
+            self.first = self.first.or(Some(c));
+            self.dotdot |= c == '.' && self.last == Some('.');
+            self.last = Some(c);
+            self.space |= c.is_whitespace();
+            self.special |= matches!(c, ',' | ';' | '(' | ')' | '[' | ']' | '{' | '}');
+            self.run = match c {
+                '"' => {
+                    self.quote = true;
+                    Some(0)
+                }
+                '#' => self.run.map(|n| n + 1),
+                _ => None,
+            };
+            if let Some(n) = self.run {
+                self.max_run = self.max_run.max(n);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::format;
+
+    use super::*;
+
+    struct Elem(&'static str);
+
+    impl fmt::Display for Elem {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_element(f, &self.0, &Notation::DISPLAY)
+        }
+    }
+
+    #[test]
+    fn quotes_only_when_needed() {
+        assert_eq!(format!("{}", Elem("1.5")), "1.5");
+        assert_eq!(format!("{}", Elem("Jan 1")), "Jan 1");
+        assert_eq!(format!("{}", Elem("Jan 1, 2024")), "\"Jan 1, 2024\"");
+        assert_eq!(format!("{}", Elem("(1)")), "\"(1)\"");
+        assert_eq!(format!("{}", Elem(" a")), "\" a\"");
+        assert_eq!(format!("{}", Elem("")), "\"\"");
+        assert_eq!(format!("{}", Elem("..")), "\"..\"");
+        assert_eq!(format!("{}", Elem("...")), "...");
+        assert_eq!(format!("{}", Elem("say \"hi\"")), "#\"say \"hi\"\"#");
+        assert_eq!(format!("{}", Elem("\"#")), "##\"\"#\"##");
+    }
+
+    struct Under(&'static str, Notation);
+
+    impl fmt::Display for Under {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_element(f, &self.0, &self.1)
+        }
+    }
+
+    #[test]
+    fn quotes_markers_of_the_notation() {
+        let latex = Notation::DISPLAY.with_latex(true);
+        assert_eq!(format!("{}", Under("\\infty", latex)), "\"\\infty\"");
+        assert_eq!(
+            format!("{}", Under("\\infty", Notation::DISPLAY)),
+            "\\infty"
+        );
+        let ranges = Notation::DISPLAY.with_rust_ranges(true);
+        assert_eq!(format!("{}", Under("1..2", ranges)), "\"1..2\"");
+        assert_eq!(format!("{}", Under("a b", ranges)), "\"a b\"");
+        assert_eq!(format!("{}", Under("1.5", ranges)), "1.5");
+    }
+
+    #[test]
+    fn unquote_inverts_write() {
+        for text in ["a, b", "", "..", "say \"hi\"", "\"#", "\"##x\"#", "#"] {
+            let written = format!("{}", Elem(text));
+            assert_eq!(unquote(&written), text, "{written}");
+            assert_eq!(quoted_len(&written).unwrap_or(written.len()), written.len());
+        }
+        assert_eq!(unquote("\"a\" b"), "\"a\" b");
+        assert_eq!(unquote("#\"a\""), "#\"a\"");
+    }
+
+    #[test]
+    fn top_level_skips_quotes_and_nesting() {
+        let commas = |s| top_level(s, true).filter(|&(_, c)| c == ',').count();
+        assert_eq!(commas("\"a,b\", c"), 1);
+        assert_eq!(commas("(1, 2), (3, 4)"), 1);
+        assert_eq!(commas("#\"x\",\"#, y"), 1);
+        // An unterminated quote is ordinary text.
+        assert_eq!(commas("\"a, b"), 1);
+    }
+}
@@ -0,0 +1,945 @@
+//! Alternative serde encodings, for use with `#[serde(with = ...)]`
+//! (feature `serde`).
+//!
+//! The derived `Serialize`/`Deserialize` impls mirror each type's
+//! structure and reject anything a serializer would not have written.
+//! The adapters here trade that for friendlier wire forms:
+//!
+//! | module      | wire form                                  | non-canonical input |
+//! |-------------|--------------------------------------------|---------------------|
+//! | [`string`]  | `"[0, 10)"`, `"{[0, 1] U [5, 6]}"`         | repaired, as by `FromStr` |
+//! | [`compact`] | `[0, 10, "[)"]`, `[[0, 1], [5, 6]]`        | rejected            |
+//! | [`lenient`] | as `compact`                               | repaired            |
+//!
+//! # Compact form
+//!
+//! An interval is a sequence `[lower, upper]` of its limits, `null`
+//! for an unbounded side. Both finite sides are closed unless a third
+//! element gives the brackets: one of `"[]"`, `"[)"`, `"(]"` or `"()"`,
+//! of which only the sides with a limit matter. The empty interval is
+//! `[]`.
+//!
+//! A set ([`MaybeDisjoint`] here, `IntervalSet` in `intervalsets`) is a
+//! sequence of intervals, `[]` when empty.
+//!
+//! [`compact`] accepts only what it writes, modulo the brackets of
+//! unbounded sides and discrete open bounds (`(0, 10)` of `i32`
+//! normalizes to `[1, 9]` as everywhere else): crossed limits, and
+//! sets whose pieces are empty, unsorted, or connected, are errors.
+//! [`lenient`] reads crossed limits as the empty interval and repairs
+//! sets the way their `new` constructors do. Limits rejected by
+//! [`Element::validate`], such as `NaN`, are errors in both.
+//!
+//! ```
+//! # #[cfg(feature = "serde")] {
+//! use intervalsets_core::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Window {
+//!     #[serde(with = "intervalsets_core::serde::string")]
+//!     open: EnumInterval<f64>,
+//!     #[serde(with = "intervalsets_core::serde::compact")]
+//!     busy: MaybeDisjoint<i32>,
+//! }
+//!
+//! let window = Window {
+//!     open: EnumInterval::closed_open(0.0, 10.0),
+//!     busy: EnumInterval::closed(0, 2).union(EnumInterval::closed(5, 6)),
+//! };
+//! let json = serde_json::to_string(&window).unwrap();
+//! assert_eq!(json, r#"{"open":"[0, 10)","busy":[[0,2],[5,6]]}"#);
+//! assert_eq!(serde_json::from_str::<Window>(&json).unwrap(), window);
+//! # }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, DeserializeSeed, IgnoredAny, SeqAccess, Visitor};
+use ::serde::ser::SerializeSeq;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::bound::{BoundType, FiniteBound, SetBounds, Side};
+use crate::empty::MaybeEmpty;
+use crate::factory::{
+    TryFiniteFactory, TryHalfBoundedFactory, TrySatisfyFiniteInterval, UnboundedFactory,
+};
+use crate::numeric::Element;
+use crate::sets::{EnumInterval, FiniteInterval, HalfInterval, MaybeDisjoint};
+
+/// Types with a [compact](self#compact-form) encoding, as a single
+/// interval or as a set of them.
+///
+/// Implemented for every interval and set type of this crate and of
+/// `intervalsets`.
+pub trait Compact<T>: Sized {
+    /// `true` for sets, encoded as a sequence of intervals; `false` for
+    /// intervals, encoded as one.
+    const SET: bool;
+
+    /// The interval type encoded.
+    type Piece: SetBounds<T> + MaybeEmpty;
+
+    /// The `index`th interval to encode: an interval is its own only
+    /// piece, empty or not; a set has its non-empty pieces in ascending
+    /// order.
+    fn piece(&self, index: usize) -> Option<&Self::Piece>;
+
+    /// Rebuilds a value from decoded intervals: exactly one for an
+    /// interval, any number for a set.
+    ///
+    /// `repair` selects [`lenient`] over [`compact`] semantics for the
+    /// set invariants; the intervals themselves are already decoded
+    /// accordingly. Implementations should drain `pieces`.
+    fn from_pieces<I, E>(pieces: I, repair: bool) -> Result<Self, E>
+    where
+        I: Iterator<Item = Result<EnumInterval<T>, E>>,
+        E: de::Error;
+}
+
+/// Interval notation strings, through `Display` and `FromStr`.
+///
+/// Works for any type implementing both, so for every interval and set
+/// type. Parsing repairs sets as [`FromStr`](core::str::FromStr) does.
+pub mod string {
+    use core::str::FromStr;
+
+    use super::*;
+
+    /// Serializes `value` as its `Display` text.
+    pub fn serialize<X, S>(value: &X, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        X: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes a string with `X::from_str`.
+    pub fn deserialize<'de, X, D>(deserializer: D) -> Result<X, D::Error>
+    where
+        X: FromStr,
+        X::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ParseVisitor(PhantomData))
+    }
+
+    struct ParseVisitor<X>(PhantomData<X>);
+
+    impl<X> Visitor<'_> for ParseVisitor<X>
+    where
+        X: FromStr,
+        X::Err: fmt::Display,
+    {
+        type Value = X;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a string in interval notation")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<X, E> {
+            s.parse().map_err(E::custom)
+        }
+    }
+}
+
+/// The strict [compact form](self#compact-form).
+pub mod compact {
+    use super::*;
+
+    /// Serializes `value` in compact form.
+    pub fn serialize<T, X, S>(value: &X, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        X: Compact<T>,
+        S: Serializer,
+    {
+        super::serialize(value, serializer)
+    }
+
+    /// Deserializes the compact form, rejecting non-canonical input.
+    pub fn deserialize<'de, T, X, D>(deserializer: D) -> Result<X, D::Error>
+    where
+        T: Element + Deserialize<'de>,
+        X: Compact<T>,
+        D: Deserializer<'de>,
+    {
+        super::deserialize(deserializer, false)
+    }
+}
+
+/// The [compact form](self#compact-form), repairing non-canonical
+/// input.
+pub mod lenient {
+    use super::*;
+
+    /// Serializes `value` in compact form.
+    pub fn serialize<T, X, S>(value: &X, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        X: Compact<T>,
+        S: Serializer,
+    {
+        super::serialize(value, serializer)
+    }
+
+    /// Deserializes the compact form, repairing crossed limits and set
+    /// invariants.
+    pub fn deserialize<'de, T, X, D>(deserializer: D) -> Result<X, D::Error>
+    where
+        T: Element + Deserialize<'de>,
+        X: Compact<T>,
+        D: Deserializer<'de>,
+    {
+        super::deserialize(deserializer, true)
+    }
+}
+
+fn serialize<T, X, S>(value: &X, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    X: Compact<T>,
+    S: Serializer,
+{
+    let pieces = || (0..).map_while(|i| value.piece(i));
+    if !X::SET {
+        return match pieces().next() {
+            Some(piece) => Piece(piece, PhantomData).serialize(serializer),
+            None => serializer.serialize_seq(Some(0))?.end(),
+        };
+    }
+    let mut seq = serializer.serialize_seq(Some(pieces().count()))?;
+    for piece in pieces() {
+        seq.serialize_element(&Piece(piece, PhantomData))?;
+    }
+    seq.end()
+}
+
+fn deserialize<'de, T, X, D>(deserializer: D, repair: bool) -> Result<X, D::Error>
+where
+    T: Element + Deserialize<'de>,
+    X: Compact<T>,
+    D: Deserializer<'de>,
+{
+    if X::SET {
+        deserializer.deserialize_seq(SetVisitor(repair, PhantomData))
+    } else {
+        let piece = PieceSeed(repair, PhantomData).deserialize(deserializer);
+        X::from_pieces(core::iter::once(piece), repair)
+    }
+}
+
+/// An interval in compact form.
+struct Piece<'a, P, T>(&'a P, PhantomData<T>);
+
+impl<P, T> Serialize for Piece<'_, P, T>
+where
+    P: SetBounds<T> + MaybeEmpty,
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let interval = self.0;
+        if interval.is_empty() {
+            return serializer.serialize_seq(Some(0))?.end();
+        }
+        let (left, right) = (interval.left(), interval.right());
+        let closed = |b: Option<&FiniteBound<T>>| b.is_none_or(FiniteBound::is_closed);
+        let brackets = match (closed(left), closed(right)) {
+            (true, true) => None,
+            (true, false) => Some("[)"),
+            (false, true) => Some("(]"),
+            (false, false) => Some("()"),
+        };
+        let mut seq = serializer.serialize_seq(Some(if brackets.is_some() { 3 } else { 2 }))?;
+        seq.serialize_element(&left.map(FiniteBound::value))?;
+        seq.serialize_element(&right.map(FiniteBound::value))?;
+        if let Some(brackets) = brackets {
+            seq.serialize_element(brackets)?;
+        }
+        seq.end()
+    }
+}
+
+/// Decodes one interval; `.0` is `repair`.
+struct PieceSeed<T>(bool, PhantomData<T>);
+
+impl<'de, T> DeserializeSeed<'de> for PieceSeed<T>
+where
+    T: Element + Deserialize<'de>,
+{
+    type Value = EnumInterval<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T> Visitor<'de> for PieceSeed<T>
+where
+    T: Element + Deserialize<'de>,
+{
+    type Value = EnumInterval<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an interval as [], [lower, upper] or [lower, upper, brackets]")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let Some(lower) = seq.next_element::<Option<T>>()? else {
+            return Ok(EnumInterval::empty());
+        };
+        let upper = seq
+            .next_element::<Option<T>>()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let Brackets(left, right) = seq.next_element()?.unwrap_or(Brackets::CLOSED);
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(4, &self));
+        }
+        let bound = |kind, limit| FiniteBound::try_new(kind, limit).map_err(de::Error::custom);
+        let interval = match (lower, upper) {
+            (None, None) => Ok(EnumInterval::unbounded()),
+            (Some(l), None) => EnumInterval::try_half_bounded(Side::Left, bound(left, l)?),
+            (None, Some(r)) => EnumInterval::try_half_bounded(Side::Right, bound(right, r)?),
+            (Some(l), Some(r)) => {
+                let (l, r) = (bound(left, l)?, bound(right, r)?);
+                if self.0 {
+                    EnumInterval::try_satisfy_bounds(l, r)
+                } else {
+                    EnumInterval::try_fully_bounded(l, r)
+                }
+            }
+        };
+        interval.map_err(de::Error::custom)
+    }
+}
+
+/// The bound types named by a brackets string.
+struct Brackets(BoundType, BoundType);
+
+impl Brackets {
+    const CLOSED: Self = Self(BoundType::Closed, BoundType::Closed);
+}
+
+impl<'de> Deserialize<'de> for Brackets {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(BracketsVisitor)
+    }
+}
+
+struct BracketsVisitor;
+
+impl Visitor<'_> for BracketsVisitor {
+    type Value = Brackets;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("one of \"[]\", \"[)\", \"(]\" or \"()\"")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Brackets, E> {
+        use BoundType::{Closed, Open};
+        match s {
+            "[]" => Ok(Brackets(Closed, Closed)),
+            "[)" => Ok(Brackets(Closed, Open)),
+            "(]" => Ok(Brackets(Open, Closed)),
+            "()" => Ok(Brackets(Open, Open)),
+            _ => Err(E::invalid_value(de::Unexpected::Str(s), &self)),
+        }
+    }
+}
+
+/// Decodes a set; `.0` is `repair`.
+struct SetVisitor<T, X>(bool, PhantomData<(T, X)>);
+
+impl<'de, T, X> Visitor<'de> for SetVisitor<T, X>
+where
+    T: Element + Deserialize<'de>,
+    X: Compact<T>,
+{
+    type Value = X;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence of intervals")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<X, A::Error> {
+        let repair = self.0;
+        let pieces = core::iter::from_fn(|| {
+            seq.next_element_seed(PieceSeed(repair, PhantomData))
+                .transpose()
+        });
+        X::from_pieces(pieces, repair)
+    }
+}
+
+/// The first of `pieces`, for interval types.
+fn only<T, I, E>(mut pieces: I) -> Result<EnumInterval<T>, E>
+where
+    I: Iterator<Item = Result<EnumInterval<T>, E>>,
+    E: de::Error,
+{
+    pieces
+        .next()
+        .unwrap_or_else(|| Err(E::invalid_length(0, &"one interval")))
+}
+
+impl<T: Element> Compact<T> for EnumInterval<T> {
+    const SET: bool = false;
+    type Piece = Self;
+
+    fn piece(&self, index: usize) -> Option<&Self> {
+        (index == 0).then_some(self)
+    }
+
+    fn from_pieces<I, E>(pieces: I, _: bool) -> Result<Self, E>
+    where
+        I: Iterator<Item = Result<EnumInterval<T>, E>>,
+        E: de::Error,
+    {
+        only(pieces)
+    }
+}
+
+/// `FiniteInterval` and `HalfInterval` decode through `EnumInterval`.
+macro_rules! compact_via_enum {
+    ($($t:ident),+) => {
+        $(
+            impl<T: Element> Compact<T> for $t<T> {
+                const SET: bool = false;
+                type Piece = Self;
+
+                fn piece(&self, index: usize) -> Option<&Self> {
+                    (index == 0).then_some(self)
+                }
+
+                fn from_pieces<I, E>(pieces: I, _: bool) -> Result<Self, E>
+                where
+                    I: Iterator<Item = Result<EnumInterval<T>, E>>,
+                    E: de::Error,
+                {
+                    $t::try_from(only(pieces)?).map_err(E::custom)
+                }
+            }
+        )+
+    };
+}
+
+compact_via_enum!(FiniteInterval, HalfInterval);
+
+impl<T: Element> Compact<T> for MaybeDisjoint<T> {
+    const SET: bool = true;
+    type Piece = EnumInterval<T>;
+
+    fn piece(&self, index: usize) -> Option<&EnumInterval<T>> {
+        let piece = match (self, index) {
+            (Self::Connected(a), 0) | (Self::Disjoint(a, _), 0) => a,
+            (Self::Disjoint(_, b), 1) => b,
+            _ => return None,
+        };
+        (!piece.is_empty()).then_some(piece)
+    }
+
+    fn from_pieces<I, E>(mut pieces: I, repair: bool) -> Result<Self, E>
+    where
+        I: Iterator<Item = Result<EnumInterval<T>, E>>,
+        E: de::Error,
+    {
+        let a = pieces.next().transpose()?;
+        let b = pieces.next().transpose()?;
+        if pieces.next().transpose()?.is_some() {
+            return Err(E::invalid_length(3, &"at most two intervals"));
+        }
+        if repair {
+            return Ok(Self::new(a, b));
+        }
+        match (a, b) {
+            (None, _) => Ok(Self::empty()),
+            (Some(a), None) if !a.is_empty() => Ok(Self::from_interval(a)),
+            (Some(a), Some(b)) if Self::satisfies_invariants(&a, &b) => {
+                Ok(Self::new_disjoint_assume_valid(a, b))
+            }
+            _ => Err(E::custom("set pieces are empty, unsorted, or connected")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::Union;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(bound = "T: Element + Serialize + for<'a> Deserialize<'a>")]
+    struct Strict<T> {
+        #[serde(with = "compact")]
+        enum_interval: EnumInterval<T>,
+        #[serde(with = "compact")]
+        finite: FiniteInterval<T>,
+        #[serde(with = "compact")]
+        half: HalfInterval<T>,
+        #[serde(with = "compact")]
+        set: MaybeDisjoint<T>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Lenient {
+        #[serde(with = "lenient")]
+        interval: EnumInterval<i32>,
+        #[serde(with = "lenient")]
+        set: MaybeDisjoint<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Text {
+        #[serde(with = "string")]
+        interval: FiniteInterval<f64>,
+        #[serde(with = "string")]
+        set: MaybeDisjoint<f64>,
+    }
+
+    fn round_trip<X>(value: X) -> X
+    where
+        X: Serialize + for<'a> Deserialize<'a>,
+    {
+        let json = serde_json::to_string(&value).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn compact_wire_form() {
+        let value = Strict {
+            enum_interval: EnumInterval::open_unbound(0.5),
+            finite: FiniteInterval::closed_open(0.0, 1.0),
+            half: HalfInterval::unbound_closed(2.0),
+            set: MaybeDisjoint::from_interval(EnumInterval::unbounded()),
+        };
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            r#"{"enum_interval":[0.5,null,"(]"],"finite":[0.0,1.0,"[)"],"half":[null,2.0],"set":[[null,null]]}"#
+        );
+        assert_eq!(round_trip(value.clone()), value);
+
+        let empty = Strict::<i32> {
+            enum_interval: EnumInterval::empty(),
+            finite: FiniteInterval::empty(),
+            half: HalfInterval::closed_unbound(0),
+            set: MaybeDisjoint::empty(),
+        };
+        assert_eq!(
+            serde_json::to_string(&empty).unwrap(),
+            r#"{"enum_interval":[],"finite":[],"half":[0,null],"set":[]}"#
+        );
+        assert_eq!(round_trip(empty.clone()), empty);
+    }
+
+    fn strict(json: &str) -> Result<Strict<i32>, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn compact_rejects_non_canonical() {
+        let valid = r#"{"enum_interval":[0,5],"finite":[0,5],"half":[0,null],"set":[[0,1],[5,6]]}"#;
+        let parsed = strict(valid).unwrap();
+        assert_eq!(
+            parsed.set,
+            EnumInterval::closed(0, 1).union(EnumInterval::closed(5, 6))
+        );
+        // Discrete open bounds normalize rather than fail.
+        let open = valid.replace("\"finite\":[0,5]", "\"finite\":[0,5,\"()\"]");
+        assert_eq!(strict(&open).unwrap().finite, FiniteInterval::closed(1, 4));
+
+        for (from, to) in [
+            ("\"enum_interval\":[0,5]", "\"enum_interval\":[5,0]"),
+            ("\"finite\":[0,5]", "\"finite\":[0,null]"),
+            ("\"half\":[0,null]", "\"half\":[0,5]"),
+            ("\"finite\":[0,5]", "\"finite\":[0,5,\"[[\"]"),
+            ("\"finite\":[0,5]", "\"finite\":[0]"),
+            ("\"finite\":[0,5]", "\"finite\":[0,5,\"[]\",1]"),
+            ("[[0,1],[5,6]]", "[[5,6],[0,1]]"),
+            ("[[0,1],[5,6]]", "[[0,1],[2,6]]"),
+            ("[[0,1],[5,6]]", "[[0,1],[]]"),
+            ("[[0,1],[5,6]]", "[[]]"),
+            ("[[0,1],[5,6]]", "[[0,1],[3,3],[5,6]]"),
+        ] {
+            let json = valid.replace(from, to);
+            assert_ne!(json, valid);
+            assert!(strict(&json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn compact_rejects_invalid_limits() {
+        // JSON has no NaN or infinity; MessagePack carries both.
+        let decode = |limits: (f64, f64)| -> Result<EnumInterval<f64>, _> {
+            let bytes = rmp_serde::to_vec(&limits).unwrap();
+            compact::deserialize(&mut rmp_serde::Deserializer::new(&bytes[..]))
+        };
+        assert_eq!(decode((0.0, 1.0)).unwrap(), EnumInterval::closed(0.0, 1.0));
+        assert!(decode((0.0, f64::NAN)).is_err());
+        assert!(decode((f64::NEG_INFINITY, 1.0)).is_err());
+        assert!(decode((f64::NAN, f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn lenient_repairs() {
+        let json = r#"{"interval":[5,0],"set":[[5,6],[0,2],[]]}"#;
+        assert!(
+            serde_json::from_str::<Lenient>(json).is_err(),
+            "three pieces"
+        );
+
+        let json = r#"{"interval":[5,0],"set":[[5,6],[0,4]]}"#;
+        let parsed: Lenient = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            parsed,
+            Lenient {
+                interval: EnumInterval::empty(),
+                set: MaybeDisjoint::from_interval(EnumInterval::closed(0, 6)),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            r#"{"interval":[],"set":[[0,6]]}"#
+        );
+
+        let json = r#"{"interval":[0,10],"set":[[]]}"#;
+        let parsed: Lenient = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed.set, MaybeDisjoint::empty());
+    }
+
+    #[test]
+    fn string_form() {
+        let value = Text {
+            interval: FiniteInterval::open_closed(0.0, 1.5),
+            set: MaybeDisjoint::from_pair(
+                EnumInterval::unbound_open(0.0),
+                EnumInterval::closed(1.0, 2.0),
+            ),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            r#"{"interval":"(0, 1.5]","set":"{(.., 0) U [1, 2]}"}"#
+        );
+        assert_eq!(round_trip(value.clone()), value);
+
+        let bad = r#"{"interval":"(0, 1.5","set":"{}"}"#;
+        assert!(serde_json::from_str::<Text>(bad).is_err());
+    }
+}
+
+/// The derived impls, round-tripped through several formats and fed
+/// payloads they would never write.
+#[cfg(test)]
+mod derived {
+    use ::serde::{Deserialize, Serialize};
+
+    use crate::factory::{FiniteFactory, HalfBoundedFactory, UnboundedFactory};
+    use crate::sets::{EnumInterval, MaybeDisjoint};
+
+    mod brief {
+        use super::*;
+
+        fn round_trip<X>(item: X) -> bool
+        where
+            X: PartialEq + Serialize + core::fmt::Debug,
+            for<'a> X: Deserialize<'a>,
+        {
+            let mut buffer = [0u8; 128];
+            let encoded = serde_brief::to_slice(&item, &mut buffer).unwrap();
+            let decoded = serde_brief::from_slice(encoded).unwrap();
+            item == decoded
+        }
+
+        #[test]
+        fn test_intervals() {
+            assert!(round_trip(EnumInterval::<f32>::empty()));
+            assert!(round_trip(EnumInterval::<i32>::empty()));
+            assert!(round_trip(EnumInterval::closed(0, 100)));
+            assert!(round_trip(EnumInterval::open(0.0, 100.0)));
+            assert!(round_trip(EnumInterval::unbound_open(0.0)));
+            assert!(round_trip(EnumInterval::closed_unbound(0)));
+            assert!(round_trip(EnumInterval::<f32>::unbounded()));
+        }
+
+        #[test]
+        fn test_maybe_disjoint() {
+            assert!(round_trip(MaybeDisjoint::<i32>::empty()));
+            assert!(round_trip(MaybeDisjoint::from_interval(
+                EnumInterval::closed(0, 100)
+            )));
+            assert!(round_trip(MaybeDisjoint::from_pair(
+                EnumInterval::closed(0, 5),
+                EnumInterval::closed(10, 20),
+            )));
+            assert!(round_trip(MaybeDisjoint::from_pair(
+                EnumInterval::unbound_closed(0_i32),
+                EnumInterval::closed_unbound(10),
+            )));
+        }
+    }
+
+    mod json {
+        use super::*;
+
+        fn round_trip<X: PartialEq + Serialize + for<'a> Deserialize<'a> + core::fmt::Debug>(
+            item: X,
+        ) -> bool {
+            let encoded = serde_json::to_string(&item).unwrap();
+            let decoded = serde_json::from_str(&encoded).unwrap();
+            item == decoded
+        }
+
+        #[test]
+        fn test_intervals() {
+            assert!(round_trip(EnumInterval::<f32>::empty()));
+            assert!(round_trip(EnumInterval::<i32>::empty()));
+            assert!(round_trip(EnumInterval::closed(0, 100)));
+            assert!(round_trip(EnumInterval::open(0.0, 100.0)));
+            assert!(round_trip(EnumInterval::unbound_open(0.0)));
+            assert!(round_trip(EnumInterval::closed_unbound(0)));
+            assert!(round_trip(EnumInterval::<f32>::unbounded()));
+        }
+
+        #[test]
+        fn test_maybe_disjoint() {
+            assert!(round_trip(MaybeDisjoint::<i32>::empty()));
+            assert!(round_trip(MaybeDisjoint::from_interval(
+                EnumInterval::closed(0, 100)
+            )));
+            assert!(round_trip(MaybeDisjoint::from_pair(
+                EnumInterval::closed(0, 5),
+                EnumInterval::closed(10, 20),
+            )));
+            assert!(round_trip(MaybeDisjoint::from_pair(
+                EnumInterval::unbound_closed(0_i32),
+                EnumInterval::closed_unbound(10),
+            )));
+        }
+    }
+
+    mod rmp {
+        use super::*;
+
+        fn round_trip<X: PartialEq + Serialize + for<'a> Deserialize<'a> + core::fmt::Debug>(
+            item: X,
+        ) -> bool {
+            let encoded = rmp_serde::encode::to_vec(&item).unwrap();
+            let decoded = rmp_serde::decode::from_slice(&encoded).unwrap();
+            item == decoded
+        }
+
+        #[test]
+        fn test_intervals() {
+            assert!(round_trip(EnumInterval::<f32>::empty()));
+            assert!(round_trip(EnumInterval::<i32>::empty()));
+            assert!(round_trip(EnumInterval::closed(0, 100)));
+            assert!(round_trip(EnumInterval::open(0.0, 100.0)));
+            assert!(round_trip(EnumInterval::unbound_open(0.0)));
+            assert!(round_trip(EnumInterval::closed_unbound(0)));
+            assert!(round_trip(EnumInterval::<f32>::unbounded()));
+        }
+
+        #[test]
+        fn test_maybe_disjoint() {
+            assert!(round_trip(MaybeDisjoint::<i32>::empty()));
+            assert!(round_trip(MaybeDisjoint::from_interval(
+                EnumInterval::closed(0, 100)
+            )));
+            assert!(round_trip(MaybeDisjoint::from_pair(
+                EnumInterval::closed(0, 5),
+                EnumInterval::closed(10, 20),
+            )));
+            assert!(round_trip(MaybeDisjoint::from_pair(
+                EnumInterval::unbound_closed(0_i32),
+                EnumInterval::closed_unbound(10),
+            )));
+        }
+    }
+
+    /// Negative tests — confirm `Deserialize` rejects malformed input. We patch
+    /// the wire output of valid intervals to construct payloads that the
+    /// serializer would never emit (NaN limits, swapped-order Bounded, etc.) and
+    /// assert the strict deserialize path errors. JSON is the easiest format to
+    /// hand-edit; we reuse the same hand-crafted shapes against rmp via a
+    /// "serialize a sentinel, mutate the bytes" helper.
+    mod malformed {
+        use super::*;
+
+        #[test]
+        fn json_rejects_swapped_bounded() {
+            // Build a payload by serializing a valid Bounded(closed(0), closed(10))
+            // then swapping the two limit values so the wire shape is identical
+            // but lhs > rhs. The strict deserialize path must reject it.
+            let canonical = serde_json::to_string(&EnumInterval::<f32>::closed(0.0, 10.0)).unwrap();
+            // Sanity-check the wire format before we mutate it.
+            assert!(
+                canonical.contains("Bounded"),
+                "unexpected serialized form: {canonical}"
+            );
+            let swapped = canonical.replacen("0.0", "TMP", 1);
+            let swapped = swapped.replacen("10.0", "0.0", 1);
+            let swapped = swapped.replace("TMP", "10.0");
+
+            let result: Result<EnumInterval<f32>, _> = serde_json::from_str(&swapped);
+            assert!(
+                result.is_err(),
+                "expected error for swapped-order Bounded, got: {:?}\npayload: {swapped}",
+                result
+            );
+        }
+
+        #[test]
+        fn rmp_rejects_nan_in_half_interval() {
+            // Build a valid HalfInterval, then patch the f32 bytes to a NaN.
+            let valid = EnumInterval::<f32>::unbound_open(0.0);
+            let mut bytes = rmp_serde::encode::to_vec(&valid).unwrap();
+
+            // Find the f32 0.0 = [0,0,0,0] and replace with NaN bits.
+            let nan_bits = f32::NAN.to_be_bytes();
+            let zero_bytes = [0u8; 4];
+            let pos = bytes
+                .windows(4)
+                .position(|w| w == zero_bytes)
+                .expect("valid encoding should contain the zero limit value");
+            bytes[pos..pos + 4].copy_from_slice(&nan_bits);
+
+            let result: Result<EnumInterval<f32>, _> = rmp_serde::decode::from_slice(&bytes);
+            assert!(
+                result.is_err(),
+                "expected error for NaN in HalfInterval bound, got: {:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn rmp_rejects_nan_in_finite_interval() {
+            let valid = EnumInterval::<f32>::closed(0.0, 1.0);
+            let mut bytes = rmp_serde::encode::to_vec(&valid).unwrap();
+
+            // Replace the first f32 (0.0) with NaN.
+            let nan_bits = f32::NAN.to_be_bytes();
+            let zero_bytes = [0u8; 4];
+            let pos = bytes
+                .windows(4)
+                .position(|w| w == zero_bytes)
+                .expect("valid encoding should contain the zero limit value");
+            bytes[pos..pos + 4].copy_from_slice(&nan_bits);
+
+            let result: Result<EnumInterval<f32>, _> = rmp_serde::decode::from_slice(&bytes);
+            assert!(
+                result.is_err(),
+                "expected error for NaN in FiniteInterval bound, got: {:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn json_normalizes_discrete_open_bounds() {
+            // Discrete `Bounded(open(0), open(10))` is silently normalized to
+            // `Bounded(closed(1), closed(9))` by `try_new`. Deserialize matches
+            // that semantic — no error, value-preserving.
+            // Build the payload by serializing the canonical-form interval
+            // and then re-shape it to the open-bound variant the user might
+            // have hand-crafted.
+            let canonical = serde_json::to_string(&EnumInterval::<i32>::closed(1, 9)).unwrap();
+            // Sanity: confirm the serializer emits the shape we expect.
+            assert!(
+                canonical.contains("Closed"),
+                "unexpected serialized form: {canonical}"
+            );
+            let open_payload = canonical
+                .replace("\"Closed\":1", "\"Open\":0")
+                .replace("\"Closed\":9", "\"Open\":10");
+            let parsed: EnumInterval<i32> = serde_json::from_str(&open_payload).unwrap();
+            assert_eq!(parsed, EnumInterval::<i32>::closed(1, 9));
+        }
+
+        #[test]
+        fn json_rejects_maybe_disjoint_with_connecting_pieces() {
+            // Build a payload by serializing a valid Disjoint([0,5], [10,15])
+            // then mutating the gap so the two pieces would touch (closed at
+            // 5 and closed at 6 — adjacent integers connect). Strict
+            // deserialize must reject because the canonical serializer would
+            // have merged them into Connected.
+            let canonical = serde_json::to_string(&MaybeDisjoint::from_pair(
+                EnumInterval::<i32>::closed(0, 5),
+                EnumInterval::<i32>::closed(10, 15),
+            ))
+            .unwrap();
+            assert!(
+                canonical.contains("Disjoint"),
+                "unexpected serialized form: {canonical}"
+            );
+            // Change [10, 15] → [6, 15] so the two pieces connect (5 and 6
+            // are adjacent integers).
+            let connecting = canonical.replacen("10", "6", 1);
+            let result: Result<MaybeDisjoint<i32>, _> = serde_json::from_str(&connecting);
+            assert!(
+                result.is_err(),
+                "expected error for Disjoint with connecting pieces, got: {:?}\npayload: {connecting}",
+                result
+            );
+        }
+
+        #[test]
+        fn json_rejects_maybe_disjoint_with_swapped_pieces() {
+            // Disjoint pieces must be sorted (a < b). Build canonical and
+            // swap the order — strict deserialize rejects.
+            let canonical = serde_json::to_string(&MaybeDisjoint::from_pair(
+                EnumInterval::<i32>::closed(0, 5),
+                EnumInterval::<i32>::closed(10, 15),
+            ))
+            .unwrap();
+            // Swap the two limit values for the first piece with those for
+            // the second piece, leaving the rest of the wire shape intact.
+            // Tag the first piece's bounds then replace.
+            let swapped = canonical
+                .replacen("0", "TMP_A", 1)
+                .replacen("5", "TMP_B", 1)
+                .replacen("10", "0", 1)
+                .replacen("15", "5", 1)
+                .replace("TMP_A", "10")
+                .replace("TMP_B", "15");
+            let result: Result<MaybeDisjoint<i32>, _> = serde_json::from_str(&swapped);
+            assert!(
+                result.is_err(),
+                "expected error for Disjoint with swapped pieces, got: {:?}\npayload: {swapped}",
+                result
+            );
+        }
+
+        #[test]
+        fn json_rejects_maybe_disjoint_with_empty_piece() {
+            // Disjoint pieces must both be non-empty. Build canonical and
+            // patch one piece to an Empty form. Canonical wire format uses
+            // tuple-shaped bound payloads: ["Closed",0] not {"Closed":0}.
+            let canonical = serde_json::to_string(&MaybeDisjoint::from_pair(
+                EnumInterval::<i32>::closed(0, 5),
+                EnumInterval::<i32>::closed(10, 15),
+            ))
+            .unwrap();
+            let with_empty = canonical.replacen(
+                "{\"Bounded\":[[\"Closed\",0],[\"Closed\",5]]}",
+                "\"Empty\"",
+                1,
+            );
+            // Confirm the substitution happened — otherwise we're testing
+            // on an unmodified canonical payload.
+            assert_ne!(
+                with_empty, canonical,
+                "substitution missed; check wire format"
+            );
+            let result: Result<MaybeDisjoint<i32>, _> = serde_json::from_str(&with_empty);
+            assert!(
+                result.is_err(),
+                "expected error for Disjoint with empty piece, got: {:?}\npayload: {with_empty}",
+                result
+            );
+        }
+    }
+}
@@ -15,6 +15,10 @@ use crate::numeric::{Element, Zero};
     feature = "serde",
     serde(bound(deserialize = "T: Element + serde::Deserialize<'de>"))
 )]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[allow(missing_docs)]
 pub enum EnumInterval<T> {
     Finite(FiniteInterval<T>),
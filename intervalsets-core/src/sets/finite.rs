@@ -19,6 +19,7 @@ use crate::try_cmp::TryCmp;
 /// writer path delegates here.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
 pub(super) enum FiniteIntervalInner<T> {
     Empty,
     Bounded(FiniteBound<T>, FiniteBound<T>),
@@ -52,6 +53,7 @@ impl<T> SetBounds<T> for FiniteIntervalInner<T> {
     feature = "serde",
     serde(bound(deserialize = "T: Element + serde::Deserialize<'de>"))
 )]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
 pub struct FiniteInterval<T>(FiniteIntervalInner<T>);
 
 /// Wire-format mirror of [`FiniteInterval`] used to drive validation
@@ -86,6 +88,27 @@ impl<T: Element> TryFrom<RawFiniteInterval<T>> for FiniteInterval<T> {
     }
 }
 
+/// Validates through [`FiniteInterval::try_new`], as serde does.
+#[cfg(feature = "rkyv")]
+impl<T, D> rkyv::Deserialize<FiniteInterval<T>, D> for ArchivedFiniteInterval<T>
+where
+    T: Element + rkyv::Archive,
+    T::Archived: rkyv::Deserialize<T, D>,
+    D: rkyv::rancor::Fallible + ?Sized,
+    D::Error: rkyv::rancor::Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<FiniteInterval<T>, D::Error> {
+        match &self.0 {
+            ArchivedFiniteIntervalInner::Empty => Ok(FiniteInterval::empty()),
+            ArchivedFiniteIntervalInner::Bounded(lhs, rhs) => FiniteInterval::try_new(
+                rkyv::Deserialize::deserialize(lhs, deserializer)?,
+                rkyv::Deserialize::deserialize(rhs, deserializer)?,
+            )
+            .map_err(rkyv::rancor::Source::new),
+        }
+    }
+}
+
 impl<T: Element> FiniteInterval<T> {
     /// Creates a `FiniteInterval`. **Strict** — panics on malformed
     /// input. Discrete bounds are normalized to closed form first;
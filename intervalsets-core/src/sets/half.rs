@@ -14,6 +14,7 @@ use crate::numeric::Element;
     feature = "serde",
     serde(bound(deserialize = "T: Element + serde::Deserialize<'de>"))
 )]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
 pub struct HalfInterval<T> {
     side: Side,
     bound: FiniteBound<T>,
@@ -39,6 +40,24 @@ impl<T: Element> TryFrom<RawHalfInterval<T>> for HalfInterval<T> {
     }
 }
 
+/// Validates through [`HalfInterval::try_new`], as serde does.
+#[cfg(feature = "rkyv")]
+impl<T, D> rkyv::Deserialize<HalfInterval<T>, D> for ArchivedHalfInterval<T>
+where
+    T: Element + rkyv::Archive,
+    T::Archived: rkyv::Deserialize<T, D>,
+    D: rkyv::rancor::Fallible + ?Sized,
+    D::Error: rkyv::rancor::Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<HalfInterval<T>, D::Error> {
+        HalfInterval::try_new(
+            rkyv::Deserialize::deserialize(&self.side, deserializer)?,
+            rkyv::Deserialize::deserialize(&self.bound, deserializer)?,
+        )
+        .map_err(rkyv::rancor::Source::new)
+    }
+}
+
 impl<T: Element> HalfInterval<T> {
     /// Creates a `HalfInterval`. **Strict** — panics if the bound's
     /// value is rejected by
@@ -78,6 +78,11 @@
 //!   Non-literal bounds (identifiers, function calls, casts) fall through to a
 //!   runtime panic, matching the existing panicking factory methods.
 //!
+//! Syntax errors underline the whole literal and name the byte offset
+//! of the offending text in its value, e.g. ``missing `,` between
+//! bounds (at bytes 1..5)``. Stable proc-macros can't point inside a
+//! literal, so the offset is the only locator.
+//!
 //! # Caveats
 //!
 //! - The macro input must be a **string literal**, not a `const` or an
@@ -98,6 +103,8 @@ mod expand;
 mod set;
 mod shape;
 
+use std::ops::Range;
+
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use proc_macro_crate::{crate_name, FoundCrate};
@@ -254,13 +261,27 @@ impl syn::parse::Parse for MacroInput {
     }
 }
 
+/// An error for the bytes `at` of `lit`'s value. Spans the whole
+/// literal and names the byte offset into its value in the message;
+/// stable proc-macros can't point inside a literal.
+fn error_at(lit: &syn::LitStr, at: Range<usize>, message: &str) -> syn::Error {
+    if at.len() > 1 {
+        syn::Error::new(
+            lit.span(),
+            format!("{message} (at bytes {}..{})", at.start, at.end),
+        )
+    } else {
+        syn::Error::new(lit.span(), format!("{message} (at byte {})", at.start))
+    }
+}
+
 fn build(input: TokenStream2, target: Target) -> syn::Result<TokenStream2> {
     let MacroInput { lit, ty } = syn::parse2::<MacroInput>(input)?;
 
     let span = lit.span();
     let s = lit.value();
 
-    let form = parse_shape(&s).map_err(|e| syn::Error::new(span, e.message()))?;
+    let form = parse_shape(&s).map_err(|(e, at)| error_at(&lit, at, e.message()))?;
 
     let paths = paths_for(target, ty);
 
@@ -272,7 +293,7 @@ fn build_set(input: TokenStream2) -> syn::Result<TokenStream2> {
     let span = lit.span();
     let s = lit.value();
 
-    let parts = crate::set::parse_set(&s).map_err(|e| syn::Error::new(span, e.message()))?;
+    let parts = crate::set::parse_set(&s).map_err(|(e, at)| error_at(&lit, at, &e.message()))?;
 
     let root = resolve_crate("intervalsets");
 
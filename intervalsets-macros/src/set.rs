@@ -18,7 +18,9 @@
 //! into the proc-macro's build graph for ~40 lines of pure-string
 //! logic.
 
-use crate::shape::{parse_shape, Form, ShapeError};
+use std::ops::Range;
+
+use crate::shape::{parse_shape, span_of, Form, Located, ShapeError};
 
 /// Result of parsing a `set!` literal: an ordered list of validated
 /// pieces. `pieces.is_empty()` corresponds to the input `{}`.
@@ -51,31 +53,40 @@ impl SetError {
     }
 }
 
-pub(crate) fn parse_set(s: &str) -> Result<SetParts, SetError> {
-    let s = s.trim();
-    if !s.starts_with('{') || !s.ends_with('}') {
-        return Err(SetError::MissingBraces);
+/// Errors carry the byte range of `s` at fault; a bad piece's range is
+/// within that piece.
+pub(crate) fn parse_set(s: &str) -> Result<SetParts, Located<SetError>> {
+    let t = s.trim();
+    if !t.starts_with('{') || !t.ends_with('}') {
+        return Err((SetError::MissingBraces, span_of(s, t)));
     }
     // Strip exactly one byte on each side (ASCII `{` and `}`).
-    let body = s[1..s.len() - 1].trim();
+    let body = t[1..t.len() - 1].trim();
     if body.is_empty() {
         return Ok(SetParts { pieces: Vec::new() });
     }
-    let segments = split_on_top_level_u(body)?;
+    let segments = split_on_top_level_u(body).map_err(|(e, at)| (e, shift(at, s, body)))?;
     let mut pieces = Vec::with_capacity(segments.len());
     for seg in segments {
-        let form = parse_shape(seg.trim()).map_err(SetError::Shape)?;
+        let form = parse_shape(seg).map_err(|(e, at)| (SetError::Shape(e), shift(at, s, seg)))?;
         pieces.push(form);
     }
     Ok(SetParts { pieces })
 }
 
+/// Moves a range within `part` to the same bytes within `whole`.
+fn shift(at: Range<usize>, whole: &str, part: &str) -> Range<usize> {
+    let by = span_of(whole, part).start;
+    at.start + by..at.end + by
+}
+
 /// Walk `body` byte by byte, tracking `[](){}` depth. At depth 0,
 /// split on a `U` whose surroundings (after `body` has been trimmed)
 /// are whitespace or the body boundary, with at least one actual
 /// whitespace on a side so a bare `U` body doesn't count as a
-/// separator. Empty segments produce `EmptySegment`.
-fn split_on_top_level_u(body: &str) -> Result<Vec<&str>, SetError> {
+/// separator. Empty segments produce `EmptySegment` at the separator
+/// that follows (or precedes, for a trailing `U`) them.
+fn split_on_top_level_u(body: &str) -> Result<Vec<&str>, Located<SetError>> {
     let bytes = body.as_bytes();
     let mut segments = Vec::new();
     let mut depth: i32 = 0;
@@ -96,7 +107,7 @@ fn split_on_top_level_u(body: &str) -> Result<Vec<&str>, SetError> {
                 if prev_boundary && next_boundary && (prev_is_ws || next_is_ws) {
                     let seg = body[seg_start..i].trim();
                     if seg.is_empty() {
-                        return Err(SetError::EmptySegment);
+                        return Err((SetError::EmptySegment, i..i + 1));
                     }
                     segments.push(seg);
                     seg_start = i + 1;
@@ -108,7 +119,7 @@ fn split_on_top_level_u(body: &str) -> Result<Vec<&str>, SetError> {
     }
     let tail = body[seg_start..].trim();
     if tail.is_empty() {
-        return Err(SetError::EmptySegment);
+        return Err((SetError::EmptySegment, seg_start - 1..seg_start));
     }
     segments.push(tail);
     Ok(segments)
@@ -121,9 +132,9 @@ mod tests {
     fn ok_count(s: &str) -> Result<usize, &'static str> {
         match parse_set(s) {
             Ok(p) => Ok(p.pieces.len()),
-            Err(SetError::MissingBraces) => Err("missing_braces"),
-            Err(SetError::EmptySegment) => Err("empty_segment"),
-            Err(SetError::Shape(_)) => Err("shape"),
+            Err((SetError::MissingBraces, _)) => Err("missing_braces"),
+            Err((SetError::EmptySegment, _)) => Err("empty_segment"),
+            Err((SetError::Shape(_), _)) => Err("shape"),
         }
     }
 
@@ -199,4 +210,14 @@ mod tests {
         // ends up being a single segment that fails shape parsing.
         assert_eq!(ok_count("{[0, Up]}"), Ok(1));
     }
+
+    #[test]
+    fn error_spans() {
+        let span = |s| parse_set(s).err().expect("set error").1;
+        assert_eq!(span(" [0, 10]"), 1..8);
+        assert_eq!(span("{[0, 5] U  U [10, 15]}"), 11..12);
+        assert_eq!(span("{[0, 10] U }"), 9..10);
+        // Within the second piece: its body `10 15`.
+        assert_eq!(span("{[0, 5] U [10 15]}"), 11..16);
+    }
 }
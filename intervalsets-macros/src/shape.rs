@@ -19,6 +19,8 @@
 //! (`Vec::<i32, A>::new()`) is not balanced and will split at the
 //! inner comma; this is rare enough not to bother with in v1.
 
+use std::ops::Range;
+
 use proc_macro2::{TokenStream, TokenTree};
 
 /// One row of the interval grammar.
@@ -45,6 +47,10 @@ pub(crate) enum Form {
     Unbounded,
 }
 
+/// A failure paired with the byte range of the literal's contents that
+/// caused it.
+pub(crate) type Located<E> = (E, Range<usize>);
+
 /// Structural failure modes for an interval literal string.
 pub(crate) enum ShapeError {
     MissingBrackets,
@@ -74,47 +80,53 @@ impl ShapeError {
 }
 
 /// Classify an interval literal string into a [`Form`].
-pub(crate) fn parse_shape(s: &str) -> Result<Form, ShapeError> {
-    let s = s.trim();
+pub(crate) fn parse_shape(s: &str) -> Result<Form, Located<ShapeError>> {
+    let t = s.trim();
 
-    if s == "{}" {
+    if t == "{}" {
         return Ok(Form::Empty);
     }
 
-    if s.starts_with('{') {
-        return Err(ShapeError::SetNotation);
+    if t.starts_with('{') {
+        return Err((ShapeError::SetNotation, span_of(s, t)));
     }
 
-    let (open, body, close) = peel(s).ok_or(ShapeError::MissingBrackets)?;
-    let (lhs, rhs) = split_body_at_comma(body)?;
+    let (open, body, close) = peel(s, t)?;
+    let (lhs, rhs) = split_body_at_comma(s, body)?;
     let lhs_is_dotdot = is_dotdot(&lhs);
     let rhs_is_dotdot = is_dotdot(&rhs);
 
+    let opener = span_of(s, &t[..1]);
+    let closer = span_of(s, &t[t.len() - 1..]);
+    let unbounded = |at| Err((ShapeError::ClosedOnUnboundedSide, at));
     match (lhs_is_dotdot, rhs_is_dotdot) {
         (true, true) => {
-            if open != '(' || close != ')' {
-                return Err(ShapeError::ClosedOnUnboundedSide);
+            if open != '(' {
+                return unbounded(opener);
+            }
+            if close != ')' {
+                return unbounded(closer);
             }
             Ok(Form::Unbounded)
         }
         (true, false) => {
             if open != '(' {
-                return Err(ShapeError::ClosedOnUnboundedSide);
+                return unbounded(opener);
             }
             match close {
                 ']' => Ok(Form::UnboundClosed(rhs)),
                 ')' => Ok(Form::UnboundOpen(rhs)),
-                _ => Err(ShapeError::MissingBrackets),
+                _ => Err((ShapeError::MissingBrackets, closer)),
             }
         }
         (false, true) => {
             if close != ')' {
-                return Err(ShapeError::ClosedOnUnboundedSide);
+                return unbounded(closer);
             }
             match open {
                 '[' => Ok(Form::ClosedUnbound(lhs)),
                 '(' => Ok(Form::OpenUnbound(lhs)),
-                _ => Err(ShapeError::MissingBrackets),
+                _ => Err((ShapeError::MissingBrackets, opener)),
             }
         }
         (false, false) => match (open, close) {
@@ -122,31 +134,33 @@ pub(crate) fn parse_shape(s: &str) -> Result<Form, ShapeError> {
             ('(', ')') => Ok(Form::Open(lhs, rhs)),
             ('[', ')') => Ok(Form::ClosedOpen(lhs, rhs)),
             ('(', ']') => Ok(Form::OpenClosed(lhs, rhs)),
-            _ => Err(ShapeError::MissingBrackets),
+            _ => Err((ShapeError::MissingBrackets, span_of(s, t))),
         },
     }
 }
 
-fn peel(s: &str) -> Option<(char, &str, char)> {
-    let mut chars = s.chars();
-    let open = chars.next()?;
+/// Split the delimiters off `t`, the trimmed contents of `s`.
+fn peel<'a>(s: &str, t: &'a str) -> Result<(char, &'a str, char), Located<ShapeError>> {
+    let missing = |part: &str| (ShapeError::MissingBrackets, span_of(s, part));
+    let open = t.chars().next().ok_or_else(|| missing(t))?;
     if open != '[' && open != '(' {
-        return None;
-    }
-    let close = s.chars().next_back()?;
-    if close != ']' && close != ')' {
-        return None;
+        return Err(missing(&t[..open.len_utf8()]));
     }
     let body_start = open.len_utf8();
-    let body_end = s.len() - close.len_utf8();
-    if body_start > body_end {
-        return None;
+    let close = t.chars().next_back().filter(|_| t.len() > body_start);
+    let close = close.ok_or_else(|| missing(&t[t.len()..]))?;
+    if close != ']' && close != ')' {
+        return Err(missing(&t[t.len() - close.len_utf8()..]));
     }
-    Some((open, &s[body_start..body_end], close))
+    Ok((open, &t[body_start..t.len() - close.len_utf8()], close))
 }
 
-fn split_body_at_comma(body: &str) -> Result<(TokenStream, TokenStream), ShapeError> {
-    let ts: TokenStream = body.parse().map_err(|_| ShapeError::MissingComma)?;
+fn split_body_at_comma(
+    s: &str,
+    body: &str,
+) -> Result<(TokenStream, TokenStream), Located<ShapeError>> {
+    let missing = || (ShapeError::MissingComma, span_of(s, body));
+    let ts: TokenStream = body.parse().map_err(|_| missing())?;
 
     let mut lhs: Vec<TokenTree> = Vec::new();
     let mut rhs: Vec<TokenTree> = Vec::new();
@@ -163,12 +177,18 @@ fn split_body_at_comma(body: &str) -> Result<(TokenStream, TokenStream), ShapeEr
     }
 
     if !found_comma {
-        return Err(ShapeError::MissingComma);
+        return Err(missing());
     }
 
     Ok((TokenStream::from_iter(lhs), TokenStream::from_iter(rhs)))
 }
 
+/// The byte range of `part`, a subslice of `whole`, within `whole`.
+pub(crate) fn span_of(whole: &str, part: &str) -> Range<usize> {
+    let start = part.as_ptr() as usize - whole.as_ptr() as usize;
+    start..start + part.len()
+}
+
 fn is_dotdot(ts: &TokenStream) -> bool {
     let tokens: Vec<TokenTree> = ts.clone().into_iter().collect();
     if tokens.len() != 2 {
@@ -199,10 +219,10 @@ mod tests {
             Ok(Form::UnboundClosed(_)) => Ok("unbound_closed"),
             Ok(Form::UnboundOpen(_)) => Ok("unbound_open"),
             Ok(Form::Unbounded) => Ok("unbounded"),
-            Err(ShapeError::MissingBrackets) => Err("missing_brackets"),
-            Err(ShapeError::MissingComma) => Err("missing_comma"),
-            Err(ShapeError::ClosedOnUnboundedSide) => Err("closed_on_unbounded"),
-            Err(ShapeError::SetNotation) => Err("set_notation"),
+            Err((ShapeError::MissingBrackets, _)) => Err("missing_brackets"),
+            Err((ShapeError::MissingComma, _)) => Err("missing_comma"),
+            Err((ShapeError::ClosedOnUnboundedSide, _)) => Err("closed_on_unbounded"),
+            Err((ShapeError::SetNotation, _)) => Err("set_notation"),
         }
     }
 
@@ -280,4 +300,18 @@ mod tests {
         assert_eq!(classify("{[0, 5], [10, 15]}"), Err("set_notation"));
         assert_eq!(classify("{0}"), Err("set_notation"));
     }
+
+    fn span(s: &str) -> std::ops::Range<usize> {
+        parse_shape(s).err().expect("shape error").1
+    }
+
+    #[test]
+    fn error_spans() {
+        assert_eq!(span(" 0, 10]"), 1..2);
+        assert_eq!(span("[0, 10"), 5..6);
+        assert_eq!(span("[0 10]"), 1..5);
+        assert_eq!(span("[.., 10]"), 0..1);
+        assert_eq!(span("(0, ..]"), 6..7);
+        assert_eq!(span(" {0} "), 1..4);
+    }
 }
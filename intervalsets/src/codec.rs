@@ -0,0 +1,556 @@
+//! A compact binary encoding for sets of primitive integers.
+//!
+//! Sets such as sparse ID ranges or byte coverage maps are dominated by
+//! their bounds, so the encoding spends bytes only on the bounds and
+//! next to nothing on their types. Discrete sets are normalized to
+//! closed bounds; the only open ones left are `(.., T::MIN)` and
+//! `(T::MAX, ..)`, which are written as the closed bounds one past the
+//! end of `T`. Only the first piece may be unbounded on the left and
+//! only the last on the right, so of the bound types there remains a
+//! header bit for the left end; the right end follows from where the
+//! stream stops.
+//!
+//! The finite bounds, in ascending order, are written as LEB128 varints:
+//!
+//! * the header byte: bit 0 if the first piece is unbounded on the left,
+//!   bit 1 if there are finite bounds at all.
+//! * the first bound as the frame of reference, zigzag encoded.
+//! * every later bound as its distance from the one before, less the
+//!   least distance the invariants allow (0 inside a piece, 2 between
+//!   pieces, which may not touch), plus 1.
+//! * 0 to end the set. Ending after a lower bound leaves the last piece
+//!   unbounded on the right.
+//!
+//! A set of a few wide pieces therefore costs a few bytes per piece
+//! whatever the magnitude of its bounds.
+//!
+//! [`Encoder`] and [`Decoder`] work a piece at a time over
+//! [`Write`] and [`Read`]. Any byte string decodes to a canonical set or
+//! fails: the decoder rejects non-minimal varints, unknown header bits
+//! and bounds outside `T`, and stops reading at the end of the set, so
+//! sets may be concatenated.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::codec::{decode, encode};
+//!
+//! let ids = IntervalSet::new([
+//!     Interval::closed(1_000_000_u64, 1_000_999),
+//!     Interval::closed(5_000_000, 5_000_000),
+//!     Interval::closed_unbound(9_000_000),
+//! ]);
+//!
+//! let bytes = encode(&ids, Vec::new()).unwrap();
+//! assert_eq!(bytes.len(), 16);
+//! assert_eq!(decode::<u64, _>(bytes.as_slice()).unwrap(), ids);
+//! ```
+
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+
+use thiserror::Error as ThisError;
+
+use crate::bound::{BoundType, FiniteBound, SetBounds, Side};
+use crate::factory::traits::*;
+use crate::numeric::{Element, Zero};
+use crate::{Interval, IntervalSet, MaybeEmpty};
+
+const LEFT_UNBOUNDED: u8 = 0b01;
+const BOUNDED: u8 = 0b10;
+const END: u128 = 0;
+
+/// Errors from [`Encoder`] and [`Decoder`].
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum CodecError {
+    /// The underlying reader or writer failed, or the input ended
+    /// inside a set.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    /// The input is not the encoding of a set: an unknown header bit or
+    /// a non-minimal varint on decode, or pieces out of order, touching
+    /// or empty on encode.
+    #[error("not a canonical encoded interval set")]
+    NonCanonical,
+
+    /// A decoded bound does not fit the element type.
+    #[error("decoded bound out of range for the element type")]
+    OutOfRange,
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The primitive integers of up to 64 bits, which the codec supports.
+pub trait PackedElement: Element + Zero + Copy + private::Sealed {
+    #[doc(hidden)]
+    fn widen(self) -> i128;
+
+    #[doc(hidden)]
+    fn narrow(value: i128) -> Option<Self>;
+}
+
+macro_rules! packed_element {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl PackedElement for $t {
+                fn widen(self) -> i128 {
+                    self as i128
+                }
+
+                fn narrow(value: i128) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+packed_element!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+fn write_varint<W: Write>(w: &mut W, mut value: u128) -> io::Result<()> {
+    let mut buf = [0; 19];
+    let mut len = 0;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf[len] = byte;
+            return w.write_all(&buf[..=len]);
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+fn read_byte<R: Read>(r: &mut R) -> io::Result<u8> {
+    let mut byte = [0];
+    r.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+fn read_varint<R: Read>(r: &mut R) -> Result<u128, CodecError> {
+    let mut value = 0_u128;
+    for shift in (0..128).step_by(7) {
+        let byte = read_byte(r)?;
+        let bits = u128::from(byte & 0x7f);
+        if (shift > 0 && byte == 0) || (bits << shift) >> shift != bits {
+            return Err(CodecError::NonCanonical);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(CodecError::NonCanonical)
+}
+
+fn zigzag(value: i128) -> u128 {
+    ((value << 1) ^ (value >> 127)) as u128
+}
+
+fn unzigzag(value: u128) -> i128 {
+    (value >> 1) as i128 ^ -((value & 1) as i128)
+}
+
+/// Where an [`Encoder`] or [`Decoder`] is in a set.
+#[derive(Debug, Clone, Copy)]
+enum State {
+    /// Nothing written or read yet.
+    Start,
+    /// The last bound was the lower bound of an unfinished piece.
+    Lower(i128),
+    /// The last bound was the upper bound of a finished piece.
+    Upper(i128),
+    /// No more pieces may follow.
+    Done,
+}
+
+/// Writes a set a piece at a time.
+///
+/// The pieces must be pushed in ascending order, as an
+/// [`IntervalSet`] stores them.
+///
+/// ```
+/// use intervalsets::prelude::*;
+/// use intervalsets::codec::{Decoder, Encoder};
+///
+/// let mut encoder = Encoder::new(Vec::new());
+/// encoder.push(&Interval::unbound_closed(-10_i32)).unwrap();
+/// encoder.push(&Interval::closed(0, 100)).unwrap();
+/// let bytes = encoder.finish().unwrap();
+/// assert_eq!(bytes, [0b11, 19, 9, 101, 0]);
+///
+/// let pieces: Result<Vec<_>, _> = Decoder::<i32, _>::new(bytes.as_slice()).collect();
+/// assert_eq!(
+///     pieces.unwrap(),
+///     [Interval::unbound_closed(-10), Interval::closed(0, 100)]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Encoder<T, W> {
+    writer: W,
+    state: State,
+    _element: PhantomData<T>,
+}
+
+impl<T: PackedElement, W: Write> Encoder<T, W> {
+    /// Creates an encoder that writes to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            state: State::Start,
+            _element: PhantomData,
+        }
+    }
+
+    /// The closed bound on `side` equivalent to `bound`.
+    fn value(side: Side, bound: Option<&FiniteBound<T>>) -> Option<i128> {
+        let bound = bound?;
+        let value = bound.value().widen();
+        Some(match (bound.bound_type(), side) {
+            (BoundType::Closed, _) => value,
+            (BoundType::Open, Side::Left) => value + 1,
+            (BoundType::Open, Side::Right) => value - 1,
+        })
+    }
+
+    /// Writes the bound `value` after the previous one, at least `gap`
+    /// above it.
+    fn bound(&mut self, prev: i128, value: i128, gap: i128) -> Result<(), CodecError> {
+        let delta = value
+            .checked_sub(prev)
+            .and_then(|d| d.checked_sub(gap))
+            .filter(|d| *d >= 0)
+            .ok_or(CodecError::NonCanonical)?;
+        Ok(write_varint(&mut self.writer, delta as u128 + 1)?)
+    }
+
+    /// Writes the next piece.
+    ///
+    /// # Errors
+    ///
+    /// [`CodecError::NonCanonical`] if `piece` is empty or does not lie
+    /// above the previous piece with a gap between them, and
+    /// [`CodecError::Io`] if the write fails.
+    pub fn push(&mut self, piece: &Interval<T>) -> Result<(), CodecError> {
+        if piece.is_empty() {
+            return Err(CodecError::NonCanonical);
+        }
+        let lhs = Self::value(Side::Left, piece.left());
+        let rhs = Self::value(Side::Right, piece.right());
+
+        let first = match (self.state, lhs) {
+            (State::Start, None) => {
+                let header = match rhs {
+                    Some(_) => LEFT_UNBOUNDED | BOUNDED,
+                    None => LEFT_UNBOUNDED,
+                };
+                self.writer.write_all(&[header])?;
+                rhs.map(|hi| write_varint(&mut self.writer, zigzag(hi)))
+                    .transpose()?;
+                None
+            }
+            (State::Start, Some(lo)) => {
+                self.writer.write_all(&[BOUNDED])?;
+                write_varint(&mut self.writer, zigzag(lo))?;
+                Some(lo)
+            }
+            (State::Upper(prev), Some(lo)) => {
+                self.bound(prev, lo, 2)?;
+                Some(lo)
+            }
+            _ => return Err(CodecError::NonCanonical),
+        };
+
+        self.state = match (first, rhs) {
+            (Some(lo), Some(hi)) => {
+                self.bound(lo, hi, 0)?;
+                State::Upper(hi)
+            }
+            (None, Some(hi)) => State::Upper(hi),
+            (Some(lo), None) => State::Lower(lo),
+            (None, None) => State::Done,
+        };
+        Ok(())
+    }
+
+    /// Ends the set and returns the writer.
+    ///
+    /// # Errors
+    ///
+    /// [`CodecError::Io`] if the write fails.
+    pub fn finish(mut self) -> Result<W, CodecError> {
+        match self.state {
+            State::Start => self.writer.write_all(&[0])?,
+            State::Lower(_) | State::Upper(_) => write_varint(&mut self.writer, END)?,
+            State::Done => {}
+        }
+        Ok(self.writer)
+    }
+}
+
+/// Reads a set a piece at a time, as an iterator of its pieces.
+///
+/// The iterator ends after the last piece, leaving the reader just past
+/// the set, or after the first error.
+#[derive(Debug)]
+pub struct Decoder<T, R> {
+    reader: R,
+    state: State,
+    _element: PhantomData<T>,
+}
+
+impl<T: PackedElement, R: Read> Decoder<T, R> {
+    /// Creates a decoder that reads from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            state: State::Start,
+            _element: PhantomData,
+        }
+    }
+
+    /// Returns the reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next bound at least `gap` above `prev`, or `None` at
+    /// the end of the set.
+    fn bound(&mut self, prev: i128, gap: i128) -> Result<Option<i128>, CodecError> {
+        match read_varint(&mut self.reader)? {
+            END => Ok(None),
+            delta => i128::try_from(delta - 1)
+                .ok()
+                .and_then(|d| prev.checked_add(gap)?.checked_add(d))
+                .map(Some)
+                .ok_or(CodecError::OutOfRange),
+        }
+    }
+
+    fn element(value: i128) -> Result<T, CodecError> {
+        T::narrow(value).ok_or(CodecError::OutOfRange)
+    }
+
+    /// The piece bounded by `value` on `side` only. One past the end of
+    /// `T`, `value` stands for the open bound at the end.
+    fn half(side: Side, value: i128) -> Result<Interval<T>, CodecError> {
+        let (bound_type, limit) = match T::narrow(value) {
+            Some(limit) => (BoundType::Closed, limit),
+            None => {
+                let limit = match side {
+                    Side::Left => value - 1,
+                    Side::Right => value + 1,
+                };
+                (BoundType::Open, Self::element(limit)?)
+            }
+        };
+        Ok(Interval::half_bounded(
+            side,
+            FiniteBound::new(bound_type, limit),
+        ))
+    }
+
+    fn step(&mut self) -> Result<Option<Interval<T>>, CodecError> {
+        let lo = match self.state {
+            State::Done => return Ok(None),
+            State::Start => {
+                let header = read_byte(&mut self.reader)?;
+                if header & !(LEFT_UNBOUNDED | BOUNDED) != 0 {
+                    return Err(CodecError::NonCanonical);
+                }
+                self.state = State::Done;
+                let base = match header & BOUNDED {
+                    0 => None,
+                    _ => Some(unzigzag(read_varint(&mut self.reader)?)),
+                };
+                match (header & LEFT_UNBOUNDED != 0, base) {
+                    (false, None) => return Ok(None),
+                    (true, None) => return Ok(Some(Interval::unbounded())),
+                    (true, Some(hi)) => {
+                        let piece = Self::half(Side::Right, hi)?;
+                        self.state = State::Upper(hi);
+                        return Ok(Some(piece));
+                    }
+                    (false, Some(lo)) => lo,
+                }
+            }
+            State::Upper(prev) => match self.bound(prev, 2)? {
+                Some(lo) => lo,
+                None => {
+                    self.state = State::Done;
+                    return Ok(None);
+                }
+            },
+            State::Lower(lo) => lo,
+        };
+
+        self.state = State::Lower(lo);
+        let piece = match self.bound(lo, 0)? {
+            Some(hi) => {
+                self.state = State::Upper(hi);
+                Interval::closed(Self::element(lo)?, Self::element(hi)?)
+            }
+            None => {
+                self.state = State::Done;
+                Self::half(Side::Left, lo)?
+            }
+        };
+        Ok(Some(piece))
+    }
+}
+
+impl<T: PackedElement, R: Read> Iterator for Decoder<T, R> {
+    type Item = Result<Interval<T>, CodecError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.step().transpose();
+        if matches!(item, Some(Err(_))) {
+            self.state = State::Done;
+        }
+        item
+    }
+}
+
+/// Encodes `set` to `writer` and returns the writer.
+///
+/// # Errors
+///
+/// [`CodecError::Io`] if a write fails.
+pub fn encode<T: PackedElement, W: Write>(
+    set: &IntervalSet<T>,
+    writer: W,
+) -> Result<W, CodecError> {
+    let mut encoder = Encoder::new(writer);
+    for piece in set.iter() {
+        encoder.push(piece)?;
+    }
+    encoder.finish()
+}
+
+/// Decodes one set from `reader`.
+///
+/// # Errors
+///
+/// See [`CodecError`].
+pub fn decode<T: PackedElement, R: Read>(reader: R) -> Result<IntervalSet<T>, CodecError> {
+    let pieces = Decoder::new(reader).collect::<Result<Vec<_>, _>>()?;
+    Ok(IntervalSet::new_assume_valid(pieces))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::Union;
+
+    fn bytes<T: PackedElement>(set: &IntervalSet<T>) -> Vec<u8> {
+        encode(set, Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn test_edge_sets() {
+        assert_eq!(bytes(&IntervalSet::<i32>::empty()), [0]);
+        assert_eq!(bytes(&IntervalSet::from(Interval::<i32>::unbounded())), [1]);
+        assert_eq!(
+            bytes(&IntervalSet::from(Interval::closed_unbound(3_u8))),
+            [2, 6, 0]
+        );
+        assert_eq!(
+            bytes(&IntervalSet::from(Interval::closed(u64::MIN, u64::MAX))),
+            [2, 0, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02, 0]
+        );
+
+        for set in [
+            IntervalSet::empty(),
+            IntervalSet::from(Interval::unbounded()),
+            IntervalSet::from(Interval::closed(i64::MIN, i64::MAX)),
+            Interval::unbound_closed(i64::MIN).union(Interval::closed_unbound(i64::MAX)),
+            Interval::unbound_open(i64::MIN)
+                .union(Interval::closed(i64::MIN + 1, 0))
+                .union(Interval::open_unbound(i64::MAX)),
+        ] {
+            assert_eq!(decode::<i64, _>(bytes(&set).as_slice()).unwrap(), set);
+        }
+    }
+
+    #[test]
+    fn test_concatenated_sets() {
+        let a = IntervalSet::from(Interval::closed(0_u16, 10));
+        let b = Interval::closed(5_u16, 6).union(Interval::closed_unbound(100));
+        let mut stream = encode(&a, Vec::new()).unwrap();
+        stream = encode(&b, stream).unwrap();
+
+        let mut reader = stream.as_slice();
+        assert_eq!(decode::<u16, _>(&mut reader).unwrap(), a);
+        assert_eq!(decode::<u16, _>(&mut reader).unwrap(), b);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_decoder_rejects_malformed() {
+        let fails = |input: &[u8]| decode::<u8, _>(input).unwrap_err();
+
+        assert!(matches!(fails(&[]), CodecError::Io(_)));
+        assert!(matches!(fails(&[2, 0, 5]), CodecError::Io(_)));
+        assert!(matches!(fails(&[4]), CodecError::NonCanonical));
+        assert!(matches!(
+            fails(&[2, 0x80, 0x00, 0]),
+            CodecError::NonCanonical
+        ));
+        assert!(matches!(
+            fails(&[2, 0, 0x82, 0x02, 0]),
+            CodecError::OutOfRange
+        ));
+        assert!(matches!(fails(&[2, 1, 0]), CodecError::OutOfRange));
+
+        let mut decoder = Decoder::<u8, _>::new([2_u8, 4, 3, 0x80].as_slice());
+        assert_eq!(decoder.next().unwrap().unwrap(), Interval::closed(2, 4));
+        assert!(decoder.next().unwrap().is_err());
+        assert!(decoder.next().is_none());
+    }
+
+    #[test]
+    fn test_encoder_rejects_non_canonical() {
+        let mut encoder = Encoder::new(Vec::new());
+        encoder.push(&Interval::closed(0, 4)).unwrap();
+        for piece in [
+            Interval::empty(),
+            Interval::closed(5, 6),
+            Interval::closed(-3, -1),
+            Interval::unbound_closed(10),
+        ] {
+            assert!(matches!(
+                encoder.push(&piece),
+                Err(CodecError::NonCanonical)
+            ));
+        }
+        encoder.push(&Interval::closed_unbound(6)).unwrap();
+        assert!(encoder.push(&Interval::closed(100, 101)).is_err());
+        assert_eq!(encoder.finish().unwrap(), [2, 0, 5, 1, 0]);
+    }
+
+    #[quickcheck]
+    fn check_round_trip_i32(set: IntervalSet<i32>) {
+        assert_eq!(decode::<i32, _>(bytes(&set).as_slice()).unwrap(), set);
+    }
+
+    #[quickcheck]
+    fn check_round_trip_u64(set: IntervalSet<u64>) {
+        assert_eq!(decode::<u64, _>(bytes(&set).as_slice()).unwrap(), set);
+    }
+
+    #[quickcheck]
+    fn check_any_input_decodes_canonical(input: Vec<u8>) {
+        let mut reader = input.as_slice();
+        if let Ok(set) = decode::<i8, _>(&mut reader) {
+            let used = input.len() - reader.len();
+            assert!(IntervalSet::satisfies_invariants(set.slice()));
+            assert_eq!(bytes(&set), input[..used]);
+        }
+    }
+}
@@ -1,22 +1,48 @@
+//! `Display` for [`Interval`] and [`IntervalSet`], and [`Style`]s for
+//! other notations.
+//!
+//! The plain `Display` impls write [`Style::DISPLAY`];
+//! [`DisplayWith::display_with`] writes any other [`Style`], which the
+//! parser reads back under [`Style::notation`]. Precision, as in
+//! `{:.2}`, applies to every element.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::display::{DisplayWith, Style};
+//!
+//! let x = Interval::closed_open(0.0, 10.0).union(Interval::open_unbound(20.0));
+//! assert_eq!(x.display_with(Style::LATEX).to_string(), r"[0, 10) \cup (20, \infty)");
+//! assert_eq!(format!("{:.2}", x.display_with(Style::UNICODE)), "[0.00, 10.00) ∪ (20.00, ∞)");
+//! ```
+
 use core::fmt;
 
-use itertools::Itertools;
+use intervalsets_core::display::write_set;
+pub use intervalsets_core::display::{DisplayWith, Style, Styled, Symbols};
 
-use crate::{Interval, IntervalSet, MaybeEmpty};
+use crate::{Interval, IntervalSet};
+
+impl<T: fmt::Display> DisplayWith for Interval<T> {
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, style: &Style) -> fmt::Result {
+        self.0.fmt_with(f, style)
+    }
+}
+
+impl<T: fmt::Display> DisplayWith for IntervalSet<T> {
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, style: &Style) -> fmt::Result {
+        write_set(f, style, self.iter())
+    }
+}
 
 impl<T: fmt::Display> fmt::Display for Interval<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+        self.fmt_with(f, &Style::DISPLAY)
     }
 }
 
 impl<T: fmt::Display> fmt::Display for IntervalSet<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.is_empty() {
-            write!(f, "{{}}")
-        } else {
-            write!(f, "{{{}}}", self.iter().join(" U "))
-        }
+        self.fmt_with(f, &Style::DISPLAY)
     }
 }
 
@@ -83,4 +109,48 @@ mod tests {
             "{[0, 10]}"
         );
     }
+
+    #[test]
+    fn test_display_set_styles() {
+        use crate::parse::FromNotation;
+
+        let x = Interval::unbound_open(0.0)
+            .union(Interval::closed(5.0, 10.0))
+            .union(Interval::closed_unbound(20.0));
+        let cases = [
+            (Style::DISPLAY, "{(.., 0) U [5, 10] U [20, ..)}"),
+            (Style::UNICODE, "(-∞, 0) ∪ [5, 10] ∪ [20, ∞)"),
+            (Style::LATEX, r"(-\infty, 0) \cup [5, 10] \cup [20, \infty)"),
+            (Style::ISO, "{].., 0[ U [5, 10] U [20, ..[}"),
+            (Style::RUST, "{..0 U 5..=10 U 20..}"),
+        ];
+        for (style, text) in cases {
+            assert_eq!(x.display_with(style).to_string(), text);
+            let parsed = IntervalSet::<f64>::from_notation(text, &style.notation());
+            assert_eq!(parsed, Ok(x.clone()), "{text}");
+        }
+        let empty = IntervalSet::<i32>::empty();
+        assert_eq!(empty.display_with(Style::UNICODE).to_string(), "∅");
+        assert_eq!(
+            format!("{:.1}", IntervalSet::from(Interval::closed(0.25, 1.0))),
+            "{[0.2, 1.0]}"
+        );
+    }
+
+    #[quickcheck]
+    fn check_display_styles_round_trip(x: IntervalSet<i32>) {
+        use crate::parse::FromNotation;
+
+        for style in [
+            Style::DISPLAY,
+            Style::UNICODE,
+            Style::LATEX,
+            Style::ISO,
+            Style::RUST,
+        ] {
+            let text = x.display_with(style).to_string();
+            let parsed = IntervalSet::<i32>::from_notation(&text, &style.notation());
+            assert_eq!(parsed, Ok(x.clone()), "{text}");
+        }
+    }
 }
@@ -19,7 +19,9 @@
 //! collapses to [`InvalidElement`](Error::InvalidElement).
 
 use intervalsets_core::error::Error as CoreError;
-pub use intervalsets_core::error::{MathError, ParseIntervalError, TotalOrderError};
+pub use intervalsets_core::error::{
+    Expected, MathError, ParseIntervalError, SyntaxError, TotalOrderError,
+};
 use thiserror::Error as ThisError;
 
 /// Errors returned by fallible `intervalsets` APIs.
@@ -67,6 +69,41 @@ pub enum Error {
     /// (`MathError::Domain`).
     #[error(transparent)]
     Math(#[from] MathError),
+
+    /// Operand shapes disagree in an interval linear-algebra op — a
+    /// matrix-vector product with mismatched inner dimension, a
+    /// non-square system, or row data of ragged length. Raised by
+    /// [`linalg`](crate::linalg) constructors and solvers.
+    #[error("operand dimensions do not agree")]
+    DimensionMismatch,
+
+    /// A [`linalg`](crate::linalg) solver could not prove the interval
+    /// matrix nonsingular: either it contains a singular member, or
+    /// the solver's sufficient condition for regularity failed. Use
+    /// [`IntervalMatrix::regularity`](crate::linalg::IntervalMatrix::regularity)
+    /// to tell the cases apart where possible.
+    #[error("interval matrix is singular or could not be verified regular")]
+    SingularMatrix,
+
+    /// A [`Partition`](crate::partition::Partition) would have an
+    /// empty piece — breakpoints not strictly increasing inside an
+    /// inhabited domain — or two partitions with different domains or
+    /// breakpoint sides were combined.
+    #[error("partition breakpoints are invalid or partitions are incompatible")]
+    InvalidPartition,
+
+    /// A [`Piecewise`](crate::piecewise::Piecewise) constructor was
+    /// given more or fewer values than there are pieces to assign them
+    /// to.
+    #[error("number of values does not match the number of pieces")]
+    ValueCountMismatch,
+
+    /// An archive could not be decoded for a reason other than the
+    /// values it holds: a malformed buffer, or an error raised by a
+    /// deserializer outside this crate. Raised by the `rkyv` feature's
+    /// decoding paths.
+    #[error("archive is malformed or could not be decoded")]
+    Archive,
 }
 
 impl From<TotalOrderError> for Error {
@@ -172,6 +172,20 @@
 //! * chrono
 //! * uom
 //!
+//! Further features add capabilities:
+//!
+//! * rand: uniform random sampling from [`Interval`] and [`IntervalSet`]
+//!   (see `sample`)
+//! * statrs: probabilities of sets under statistical distributions and
+//!   sampling conditioned on a set (see `probability`; implies rand)
+//! * serde: `Serialize` and `Deserialize`, plus string and compact
+//!   encodings for `#[serde(with = ...)]` (see `serde`)
+//! * rkyv: zero-copy archives of sets, with a checked view that
+//!   answers queries on the archived bytes (see `rkyv`)
+//! * roaring: conversions to and from roaring bitmaps, and the measure
+//!   of a set's overlap with one (see `roaring`)
+//! * svg: standalone SVG diagrams of sets on a number line (see `svg`)
+//!
 #![deny(bad_style)]
 #![deny(future_incompatible)]
 #![deny(nonstandard_style)]
@@ -262,22 +276,41 @@ pub use intervalsets_macros::interval;
 /// ```
 pub use intervalsets_macros::set;
 
+pub mod codec;
 pub mod error;
 pub mod factory;
+pub mod linalg;
 
 pub use intervalsets_core::MaybeEmpty;
 
 pub mod measure;
+pub mod metrics;
 pub mod ops;
+pub mod parse;
+pub mod partition;
+pub mod piecewise;
+pub mod poly;
+#[cfg(feature = "statrs")]
+pub mod probability;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "roaring")]
+pub mod roaring;
+#[cfg(feature = "rand")]
+pub mod sample;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "svg")]
+pub mod svg;
+pub mod timeline;
 
 mod sets;
 pub use sets::{Interval, IntervalSet};
 
 mod cast;
-mod display;
+pub mod display;
 mod feat;
 mod from;
-mod parse;
 //mod util;
 
 /// Common operations & traits
@@ -0,0 +1,381 @@
+use core::ops::Index;
+
+use super::{point, IntervalVector, LinalgElement};
+use crate::error::Error;
+use crate::ops::{Midpoint, TryAdd, TryMul, TrySub};
+use crate::Interval;
+
+/// A dense, row-major matrix of [`Interval`] entries.
+///
+/// ```
+/// use intervalsets::prelude::*;
+/// use intervalsets::linalg::{IntervalMatrix, IntervalVector};
+///
+/// let a = IntervalMatrix::from_rows(vec![
+///     vec![Interval::closed(1.0, 2.0), Interval::closed(0.0, 0.0)],
+///     vec![Interval::closed(0.0, 0.0), Interval::closed(-1.0, 1.0)],
+/// ])
+/// .unwrap();
+/// let x = IntervalVector::try_from_points([2.0, 3.0]).unwrap();
+///
+/// let y = a.try_mul(&x).unwrap();
+/// assert_eq!(y[0], Interval::closed(2.0, 4.0));
+/// assert_eq!(y[1], Interval::closed(-3.0, 3.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<Interval<T>>,
+}
+
+impl<T> IntervalMatrix<T> {
+    /// Creates a `rows x cols` matrix from row-major `data`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] if `data.len() != rows * cols`.
+    pub fn try_new(rows: usize, cols: usize, data: Vec<Interval<T>>) -> Result<Self, Error> {
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(Error::DimensionMismatch);
+        }
+        Ok(Self { rows, cols, data })
+    }
+
+    /// Creates a matrix from a list of rows.
+    ///
+    /// An empty list produces a `0 x 0` matrix.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] if the rows are ragged.
+    pub fn from_rows(rows: Vec<Vec<Interval<T>>>) -> Result<Self, Error> {
+        let n = rows.len();
+        let m = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|r| r.len() != m) {
+            return Err(Error::DimensionMismatch);
+        }
+        Self::try_new(n, m, rows.into_iter().flatten().collect())
+    }
+
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Is the matrix square.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Entry at `(i, j)`, or `None` when out of range.
+    pub fn get(&self, i: usize, j: usize) -> Option<&Interval<T>> {
+        if i < self.rows && j < self.cols {
+            self.data.get(i * self.cols + j)
+        } else {
+            None
+        }
+    }
+
+    /// Row `i` as a slice.
+    ///
+    /// # Panics
+    ///
+    /// If `i >= self.rows()`.
+    pub fn row(&self, i: usize) -> &[Interval<T>] {
+        assert!(i < self.rows, "row index out of range");
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Entries in row-major order.
+    pub fn slice(&self) -> &[Interval<T>] {
+        &self.data
+    }
+
+    /// Returns the underlying row-major entry storage.
+    pub fn into_raw(self) -> Vec<Interval<T>> {
+        self.data
+    }
+}
+
+impl<T: Clone> IntervalMatrix<T> {
+    /// Returns the transpose.
+    pub fn transpose(&self) -> Self {
+        let data = (0..self.cols)
+            .flat_map(|j| (0..self.rows).map(move |i| (i, j)))
+            .map(|(i, j)| self.data[i * self.cols + j].clone())
+            .collect();
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+}
+
+impl<T: LinalgElement> IntervalMatrix<T> {
+    /// Creates a `rows x cols` matrix of degenerate entries from
+    /// row-major `points`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] on a length mismatch;
+    /// [`Error::InvalidElement`] if any point is rejected by
+    /// [`Element::validate`](crate::Element::validate).
+    pub fn try_from_points(rows: usize, cols: usize, points: Vec<T>) -> Result<Self, Error> {
+        let data = points
+            .into_iter()
+            .map(point)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::try_new(rows, cols, data)
+    }
+
+    /// The `n x n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let data = (0..n * n)
+            .map(|k| {
+                let v = if k % (n + 1) == 0 {
+                    T::one()
+                } else {
+                    T::zero()
+                };
+                point(v).expect("zero and one are valid elements")
+            })
+            .collect();
+        Self {
+            rows: n,
+            cols: n,
+            data,
+        }
+    }
+
+    /// Entrywise midpoint, row-major.
+    ///
+    /// # Errors
+    ///
+    /// `Error::Math(MathError::Domain)` if any entry is empty or
+    /// unbounded; otherwise any error from `T`'s midpoint.
+    pub fn midpoint(&self) -> Result<Vec<T>, Error> {
+        self.data.iter().map(|x| x.midpoint()).collect()
+    }
+
+    /// Product with a point matrix `c` (row-major, `c_rows x self.rows`)
+    /// on the left: `C · A`.
+    pub(super) fn premul_points(&self, c: &[T], c_rows: usize) -> Result<Self, Error> {
+        let mut data = Vec::with_capacity(c_rows * self.cols);
+        for i in 0..c_rows {
+            for j in 0..self.cols {
+                let mut acc = point(T::zero())?;
+                for k in 0..self.rows {
+                    let cik = point(c[i * self.rows + k].clone())?;
+                    acc = acc.try_add(cik.try_mul(self.data[k * self.cols + j].clone())?)?;
+                }
+                data.push(acc);
+            }
+        }
+        Self::try_new(c_rows, self.cols, data)
+    }
+}
+
+impl<T> Index<(usize, usize)> for IntervalMatrix<T> {
+    type Output = Interval<T>;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(i < self.rows && j < self.cols, "index out of range");
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T: LinalgElement> TryMul<&IntervalVector<T>> for &IntervalMatrix<T> {
+    type Output = IntervalVector<T>;
+    type Error = Error;
+
+    /// Matrix-vector product `A · x`. Each output component is the
+    /// interval sum of `A_ij · x_j`, an enclosure of
+    /// `{ Ãx̃ : Ã ∈ A, x̃ ∈ x }`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] if `x.dim() != self.cols()`;
+    /// otherwise any entry-level arithmetic error.
+    fn try_mul(self, rhs: &IntervalVector<T>) -> Result<Self::Output, Self::Error> {
+        if rhs.dim() != self.cols {
+            return Err(Error::DimensionMismatch);
+        }
+        (0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(rhs.iter())
+                    .try_fold(point(T::zero())?, |acc, (a, x)| {
+                        acc.try_add(a.clone().try_mul(x.clone())?)
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(IntervalVector::new)
+    }
+}
+
+impl<T: LinalgElement> TryMul for &IntervalMatrix<T> {
+    type Output = IntervalMatrix<T>;
+    type Error = Error;
+
+    /// Matrix product `A · B`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] if `self.cols() != rhs.rows()`;
+    /// otherwise any entry-level arithmetic error.
+    fn try_mul(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+        if self.cols != rhs.rows {
+            return Err(Error::DimensionMismatch);
+        }
+        let mut data = Vec::with_capacity(self.rows * rhs.cols);
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                let mut acc = point(T::zero())?;
+                for k in 0..self.cols {
+                    let p = self[(i, k)].clone().try_mul(rhs[(k, j)].clone())?;
+                    acc = acc.try_add(p)?;
+                }
+                data.push(acc);
+            }
+        }
+        IntervalMatrix::try_new(self.rows, rhs.cols, data)
+    }
+}
+
+impl<T: LinalgElement> TryAdd for &IntervalMatrix<T> {
+    type Output = IntervalMatrix<T>;
+    type Error = Error;
+
+    /// Entrywise sum.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] on differing shapes; otherwise any
+    /// entry-level arithmetic error.
+    fn try_add(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+        if (self.rows, self.cols) != (rhs.rows, rhs.cols) {
+            return Err(Error::DimensionMismatch);
+        }
+        let data = self
+            .data
+            .iter()
+            .zip(rhs.data.iter())
+            .map(|(a, b)| a.clone().try_add(b.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        IntervalMatrix::try_new(self.rows, self.cols, data)
+    }
+}
+
+impl<T: LinalgElement> TrySub for &IntervalMatrix<T> {
+    type Output = IntervalMatrix<T>;
+    type Error = Error;
+
+    /// Entrywise difference.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] on differing shapes; otherwise any
+    /// entry-level arithmetic error.
+    fn try_sub(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+        if (self.rows, self.cols) != (rhs.rows, rhs.cols) {
+            return Err(Error::DimensionMismatch);
+        }
+        let data = self
+            .data
+            .iter()
+            .zip(rhs.data.iter())
+            .map(|(a, b)| a.clone().try_sub(b.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        IntervalMatrix::try_new(self.rows, self.cols, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+
+    fn m(rows: &[&[(f64, f64)]]) -> IntervalMatrix<f64> {
+        IntervalMatrix::from_rows(
+            rows.iter()
+                .map(|r| r.iter().map(|&(l, h)| Interval::closed(l, h)).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn shape_checks() {
+        let data = vec![Interval::closed(0.0, 1.0); 3];
+        assert_eq!(
+            IntervalMatrix::try_new(2, 2, data.clone()),
+            Err(Error::DimensionMismatch)
+        );
+        assert_eq!(
+            IntervalMatrix::from_rows(vec![data.clone(), data[..2].to_vec()]),
+            Err(Error::DimensionMismatch)
+        );
+        let a = IntervalMatrix::try_new(1, 3, data).unwrap();
+        assert!(!a.is_square());
+        assert_eq!(a.get(0, 3), None);
+        assert_eq!(a.transpose().rows(), 3);
+    }
+
+    #[test]
+    fn identity() {
+        let i = IntervalMatrix::<f64>::identity(2);
+        assert_eq!(
+            i,
+            m(&[&[(1.0, 1.0), (0.0, 0.0)], &[(0.0, 0.0), (1.0, 1.0)]])
+        );
+    }
+
+    #[test]
+    fn mat_vec() {
+        let a = m(&[&[(1.0, 2.0), (-1.0, 1.0)], &[(0.0, 0.0), (3.0, 3.0)]]);
+        let x =
+            IntervalVector::from_iter([Interval::closed(1.0, 1.0), Interval::closed(-2.0, 2.0)]);
+        let y = (&a).try_mul(&x).unwrap();
+        assert_eq!(y[0], Interval::closed(-1.0, 4.0));
+        assert_eq!(y[1], Interval::closed(-6.0, 6.0));
+
+        let short = IntervalVector::from_iter([Interval::closed(1.0, 1.0)]);
+        assert_eq!((&a).try_mul(&short), Err(Error::DimensionMismatch));
+    }
+
+    #[test]
+    fn mat_mat() {
+        let a = m(&[&[(1.0, 2.0), (0.0, 0.0)], &[(0.0, 0.0), (1.0, 1.0)]]);
+        let i = IntervalMatrix::identity(2);
+        assert_eq!((&a).try_mul(&i), Ok(a.clone()));
+        assert_eq!(
+            (&a).try_mul(&a).unwrap()[(0, 0)],
+            Interval::closed(1.0, 4.0)
+        );
+        assert_eq!(
+            (&a).try_sub(&a).unwrap()[(0, 0)],
+            Interval::closed(-1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn entry_overflow_surfaces() {
+        let a = IntervalMatrix::try_from_points(1, 1, vec![f64::MAX]).unwrap();
+        assert!(matches!((&a).try_add(&a), Err(Error::Math(_))));
+    }
+
+    #[test]
+    fn unbounded_entries_multiply() {
+        let a = IntervalMatrix::try_new(1, 1, vec![Interval::closed_unbound(1.0)]).unwrap();
+        let x = IntervalVector::try_from_points([2.0]).unwrap();
+        assert_eq!((&a).try_mul(&x).unwrap()[0], Interval::closed_unbound(2.0));
+    }
+}
@@ -0,0 +1,213 @@
+//! Interval linear algebra: vectors, matrices, and verified enclosures
+//! of the solution set of `Ax = b`.
+//!
+//! [`IntervalVector`] and [`IntervalMatrix`] store [`Interval`]
+//! entries and are built on the crate's
+//! [`TryAdd`] / [`TrySub`] / [`TryMul`] / [`TryDiv`] arithmetic, so
+//! every entry-level failure (overflow, non-finite float result,
+//! NaN) surfaces as an [`Error`] rather than a panic.
+//!
+//! # Solution sets
+//!
+//! For an interval matrix `A` and interval vector `b` the *united
+//! solution set* is
+//!
+//! ```text
+//! Σ(A, b) = { x : Ãx = b̃ for some Ã ∈ A, b̃ ∈ b }
+//! ```
+//!
+//! Σ is generally not a box. The solvers return an
+//! [`IntervalVector`] (a box) guaranteed to **enclose** Σ:
+//!
+//! - [`IntervalMatrix::solve_gauss`] — interval Gaussian elimination
+//!   with mignitude pivoting.
+//! - [`IntervalMatrix::solve_krawczyk`] — Krawczyk iteration on the
+//!   midpoint-preconditioned system.
+//! - [`IntervalMatrix::solve_hansen_bliek`] — the Hansen–Bliek–Rohn
+//!   bound (in the Ning–Kearfott form) on the midpoint-preconditioned
+//!   system; the optimal box for that system whenever it is an
+//!   H-matrix.
+//!
+//! [`IntervalMatrix::regularity`] reports whether every member of `A`
+//! is nonsingular.
+//!
+//! # Arithmetic model
+//!
+//! Enclosures are exact with respect to `T`'s own
+//! [`TryAdd`] / [`TrySub`] / [`TryMul`] / [`TryDiv`]. Library float
+//! impls round to nearest rather than outward, so `f32` / `f64`
+//! results can miss the true enclosure by a few ULPs at the bounds.
+//! Element types with exact arithmetic over the inputs (e.g.
+//! `rust_decimal::Decimal` within its precision) inherit exact
+//! guarantees.
+//!
+//! # Example
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::linalg::{IntervalMatrix, IntervalVector, Regularity};
+//!
+//! let a = IntervalMatrix::from_rows(vec![
+//!     vec![Interval::closed(3.5, 4.5), Interval::closed(-0.5, 0.5)],
+//!     vec![Interval::closed(-0.5, 0.5), Interval::closed(3.5, 4.5)],
+//! ])
+//! .unwrap();
+//! let b = IntervalVector::from_iter([Interval::closed(3.0, 5.0), Interval::closed(3.0, 5.0)]);
+//!
+//! assert_eq!(a.regularity(), Ok(Regularity::Regular));
+//!
+//! let x = a.solve_hansen_bliek(&b).unwrap();
+//! // x = [1, 1] solves the midpoint system.
+//! assert!(x.contains_point(&[1.0, 1.0]));
+//! ```
+
+use intervalsets_core::numeric::{ContinuousElement, Midpointable};
+use num_traits::{One, Zero};
+
+use crate::bound::SetBounds;
+use crate::error::{Error, MathError};
+use crate::factory::TryFiniteFactory;
+use crate::ops::{TryAdd, TryDiv, TryMul, TrySub};
+use crate::Interval;
+
+mod matrix;
+mod solve;
+mod vector;
+
+pub use matrix::IntervalMatrix;
+pub use solve::Regularity;
+pub use vector::IntervalVector;
+
+/// Element types supported by [`IntervalVector`] and
+/// [`IntervalMatrix`].
+///
+/// Blanket-implemented for every continuous [`Element`](crate::Element)
+/// with closed, [`MathError`]-reporting
+/// [`TryAdd`] / [`TrySub`] / [`TryMul`] / [`TryDiv`] and a
+/// [`Midpointable`] impl — `f32`, `f64`, `rust_decimal::Decimal`,
+/// and `OrderedFloat<_>` among the library types.
+///
+/// # Contract
+///
+/// - Implementations come only from the blanket impl; the trait is a
+///   named bound, not an extension point.
+/// - Discrete elements are excluded: elimination divides entries, and
+///   integer division does not enclose the real quotient.
+pub trait LinalgElement:
+    ContinuousElement
+    + Clone
+    + Zero
+    + One
+    + Midpointable<Error: Into<MathError>>
+    + TryAdd<Output = Self, Error = MathError>
+    + TrySub<Output = Self, Error = MathError>
+    + TryMul<Output = Self, Error = MathError>
+    + TryDiv<Output = Self, Error = MathError>
+{
+}
+
+impl<T> LinalgElement for T where
+    T: ContinuousElement
+        + Clone
+        + Zero
+        + One
+        + Midpointable<Error: Into<MathError>>
+        + TryAdd<Output = T, Error = MathError>
+        + TrySub<Output = T, Error = MathError>
+        + TryMul<Output = T, Error = MathError>
+        + TryDiv<Output = T, Error = MathError>
+{
+}
+
+// ===== Scalar helpers =====
+//
+// Point-valued arithmetic on `T` used by preconditioning and the
+// norm checks. Everything goes through the Try* traits so overflow
+// and non-finite results surface as `Err` rather than corrupting a
+// verification test.
+
+/// `|x|`.
+fn abs<T: LinalgElement>(x: T) -> Result<T, Error> {
+    if x < T::zero() {
+        Ok(T::zero().try_sub(x)?)
+    } else {
+        Ok(x)
+    }
+}
+
+fn max<T: LinalgElement>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+fn min<T: LinalgElement>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Finite endpoints of `x`. Empty and unbounded entries have no
+/// magnitude and are reported as `MathError::Domain`.
+fn endpoints<T: LinalgElement>(x: &Interval<T>) -> Result<(T, T), Error> {
+    match (x.lval(), x.rval()) {
+        (Some(l), Some(r)) => Ok((l.clone(), r.clone())),
+        _ => Err(Error::Math(MathError::Domain)),
+    }
+}
+
+/// Magnitude `max { |t| : t ∈ x }`.
+fn mag<T: LinalgElement>(x: &Interval<T>) -> Result<T, Error> {
+    let (l, r) = endpoints(x)?;
+    Ok(max(abs(l)?, abs(r)?))
+}
+
+/// Mignitude `min { |t| : t ∈ x }`; zero when `x` straddles zero.
+fn mig<T: LinalgElement>(x: &Interval<T>) -> Result<T, Error> {
+    let (l, r) = endpoints(x)?;
+    let zero = T::zero();
+    if l <= zero && zero <= r {
+        Ok(zero)
+    } else {
+        Ok(min(abs(l)?, abs(r)?))
+    }
+}
+
+/// `[-r, r]`.
+fn symmetric<T: LinalgElement>(r: T) -> Result<Interval<T>, Error> {
+    Interval::try_closed(T::zero().try_sub(r.clone())?, r)
+}
+
+/// Degenerate interval `[x, x]`.
+fn point<T: LinalgElement>(x: T) -> Result<Interval<T>, Error> {
+    Interval::try_singleton(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+
+    #[test]
+    fn mag_and_mig() {
+        let x = Interval::closed(-3.0, 2.0);
+        assert_eq!(mag(&x), Ok(3.0));
+        assert_eq!(mig(&x), Ok(0.0));
+
+        let y = Interval::closed(-5.0, -2.0);
+        assert_eq!(mag(&y), Ok(5.0));
+        assert_eq!(mig(&y), Ok(2.0));
+    }
+
+    #[test]
+    fn unbounded_entry_has_no_magnitude() {
+        let x = Interval::<f64>::closed_unbound(0.0);
+        assert_eq!(mag(&x), Err(Error::Math(MathError::Domain)));
+        let e = Interval::<f64>::empty();
+        assert_eq!(mig(&e), Err(Error::Math(MathError::Domain)));
+    }
+}
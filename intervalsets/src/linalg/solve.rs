@@ -0,0 +1,555 @@
+use super::{abs, mag, max, mig, point, symmetric, IntervalMatrix, IntervalVector, LinalgElement};
+use crate::error::Error;
+use crate::ops::{Intersection, TryAdd, TryDiv, TryMul, TrySub};
+use crate::{Interval, MaybeEmpty};
+
+/// Outcome of [`IntervalMatrix::regularity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Regularity {
+    /// Every member of the interval matrix is nonsingular.
+    Regular,
+    /// The interval matrix contains at least one singular member.
+    Singular,
+    /// Neither property could be proven by the checks performed.
+    Unknown,
+}
+
+impl Regularity {
+    /// Is regularity proven.
+    pub fn is_regular(&self) -> bool {
+        matches!(self, Self::Regular)
+    }
+}
+
+/// Midpoint preconditioning shared by the Krawczyk and Hansen–Bliek
+/// solvers: `C = mid(A)⁻¹`, `C·A`, and `C·b`.
+struct Preconditioned<T> {
+    ca: IntervalMatrix<T>,
+    cb: Option<IntervalVector<T>>,
+}
+
+impl<T: LinalgElement> IntervalMatrix<T> {
+    fn check_system(&self, b: Option<&IntervalVector<T>>) -> Result<(), Error> {
+        if !self.is_square() || b.is_some_and(|b| b.dim() != self.rows()) {
+            return Err(Error::DimensionMismatch);
+        }
+        Ok(())
+    }
+
+    /// `None` when `mid(A)` is singular.
+    fn precondition(
+        &self,
+        b: Option<&IntervalVector<T>>,
+    ) -> Result<Option<Preconditioned<T>>, Error> {
+        let n = self.rows();
+        let Some(c) = point_inverse(n, self.midpoint()?)? else {
+            return Ok(None);
+        };
+        let ca = self.premul_points(&c, n)?;
+        let cb = match b {
+            Some(b) => {
+                let bm = IntervalMatrix::try_new(n, 1, b.slice().to_vec())?;
+                Some(IntervalVector::new(bm.premul_points(&c, n)?.into_raw()))
+            }
+            None => None,
+        };
+        Ok(Some(Preconditioned { ca, cb }))
+    }
+
+    /// `‖I − CA‖∞` as an upper bound on the spectral radius of
+    /// `|I − CA|`, together with `I − CA`.
+    fn contraction(ca: &IntervalMatrix<T>) -> Result<(IntervalMatrix<T>, T), Error> {
+        let n = ca.rows();
+        let e = IntervalMatrix::identity(n).try_sub(ca)?;
+        let mut beta = T::zero();
+        for i in 0..n {
+            let row = e
+                .row(i)
+                .iter()
+                .try_fold(T::zero(), |acc, x| Ok::<_, Error>(acc.try_add(mag(x)?)?))?;
+            beta = max(beta, row);
+        }
+        Ok((e, beta))
+    }
+
+    /// Reports whether every member of `A` is nonsingular.
+    ///
+    /// - [`Regularity::Singular`] when the midpoint matrix is itself
+    ///   singular (it is a member of `A`).
+    /// - [`Regularity::Regular`] when `‖I − CA‖∞ < 1` with
+    ///   `C = mid(A)⁻¹` — a sufficient condition for regularity.
+    /// - [`Regularity::Unknown`] otherwise. Deciding regularity exactly
+    ///   is NP-hard in general; this check runs in `O(n³)`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] if `A` is not square;
+    /// `Error::Math(MathError::Domain)` if any entry is empty or
+    /// unbounded; otherwise any arithmetic error from `T`.
+    pub fn regularity(&self) -> Result<Regularity, Error> {
+        self.check_system(None)?;
+        let Some(pre) = self.precondition(None)? else {
+            return Ok(Regularity::Singular);
+        };
+        let (_, beta) = Self::contraction(&pre.ca)?;
+        if beta < T::one() {
+            Ok(Regularity::Regular)
+        } else {
+            Ok(Regularity::Unknown)
+        }
+    }
+
+    /// Encloses the solution set of `Ax = b` by interval Gaussian
+    /// elimination.
+    ///
+    /// Rows are pivoted on the largest mignitude in the current
+    /// column. Elimination fails when every remaining candidate pivot
+    /// contains zero — which happens for singular `A`, but can also
+    /// happen for regular `A` whose entries are wide; the
+    /// preconditioned solvers are more robust there.
+    ///
+    /// ```
+    /// use intervalsets::prelude::*;
+    /// use intervalsets::linalg::{IntervalMatrix, IntervalVector};
+    ///
+    /// let a = IntervalMatrix::try_from_points(2, 2, vec![2.0, 1.0, 1.0, 1.0]).unwrap();
+    /// let b = IntervalVector::try_from_points([3.0, 2.0]).unwrap();
+    /// let x = a.solve_gauss(&b).unwrap();
+    /// assert_eq!(x[0], Interval::singleton(1.0));
+    /// assert_eq!(x[1], Interval::singleton(1.0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::DimensionMismatch`] if `A` is not square or `b` has
+    ///   the wrong length.
+    /// - [`Error::SingularMatrix`] if no zero-free pivot exists.
+    /// - Any arithmetic error from `T`.
+    pub fn solve_gauss(&self, b: &IntervalVector<T>) -> Result<IntervalVector<T>, Error> {
+        self.check_system(Some(b))?;
+        let n = self.rows();
+        let mut a: Vec<Vec<Interval<T>>> = (0..n).map(|i| self.row(i).to_vec()).collect();
+        let mut b = b.slice().to_vec();
+
+        for k in 0..n {
+            let mut pivot = k;
+            let mut best = mig(&a[k][k])?;
+            for (i, row) in a.iter().enumerate().skip(k + 1) {
+                let m = mig(&row[k])?;
+                if m > best {
+                    best = m;
+                    pivot = i;
+                }
+            }
+            if best <= T::zero() {
+                return Err(Error::SingularMatrix);
+            }
+            a.swap(k, pivot);
+            b.swap(k, pivot);
+
+            for i in k + 1..n {
+                let l = quotient(a[i][k].clone(), a[k][k].clone())?;
+                let (upper, lower) = a.split_at_mut(i);
+                for (aij, akj) in lower[0].iter_mut().zip(upper[k].iter()).skip(k + 1) {
+                    *aij = aij.clone().try_sub(l.clone().try_mul(akj.clone())?)?;
+                }
+                let t = l.try_mul(b[k].clone())?;
+                b[i] = b[i].clone().try_sub(t)?;
+            }
+        }
+
+        let mut x = vec![point(T::zero())?; n];
+        for i in (0..n).rev() {
+            let mut s = b[i].clone();
+            for j in i + 1..n {
+                s = s.try_sub(a[i][j].clone().try_mul(x[j].clone())?)?;
+            }
+            x[i] = quotient(s, a[i][i].clone())?;
+        }
+        Ok(IntervalVector::new(x))
+    }
+
+    /// Encloses the solution set of `Ax = b` by Krawczyk iteration.
+    ///
+    /// With `C = mid(A)⁻¹` and `E = I − CA`, regularity is verified by
+    /// `β = ‖E‖∞ < 1`. The iteration starts from the box
+    /// `[−r, r]ⁿ`, `r = ‖Cb‖∞ / (1 − β)`, which provably contains the
+    /// solution set, and refines it by
+    ///
+    /// ```text
+    /// x ← (Cb + E·x) ∩ x
+    /// ```
+    ///
+    /// for at most `max_iter` steps or until a fixed point.
+    ///
+    /// ```
+    /// use intervalsets::prelude::*;
+    /// use intervalsets::linalg::{IntervalMatrix, IntervalVector};
+    ///
+    /// let a = IntervalMatrix::from_rows(vec![
+    ///     vec![Interval::closed(3.5, 4.5), Interval::closed(-0.5, 0.5)],
+    ///     vec![Interval::closed(-0.5, 0.5), Interval::closed(3.5, 4.5)],
+    /// ])
+    /// .unwrap();
+    /// let b = IntervalVector::try_from_points([4.0, 4.0]).unwrap();
+    /// let x = a.solve_krawczyk(&b, 20).unwrap();
+    /// assert!(x.contains_point(&[1.0, 1.0]));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::DimensionMismatch`] if `A` is not square or `b` has
+    ///   the wrong length.
+    /// - [`Error::SingularMatrix`] if `mid(A)` is singular or
+    ///   `β ≥ 1`.
+    /// - `Error::Math(MathError::Domain)` if any entry is empty or
+    ///   unbounded; otherwise any arithmetic error from `T`.
+    pub fn solve_krawczyk(
+        &self,
+        b: &IntervalVector<T>,
+        max_iter: usize,
+    ) -> Result<IntervalVector<T>, Error> {
+        self.check_system(Some(b))?;
+        let Some(Preconditioned { ca, cb: Some(cb) }) = self.precondition(Some(b))? else {
+            return Err(Error::SingularMatrix);
+        };
+        let (e, beta) = Self::contraction(&ca)?;
+        if beta >= T::one() {
+            return Err(Error::SingularMatrix);
+        }
+
+        let r = cb.norm_inf()?.try_div(T::one().try_sub(beta)?)?;
+        let mut x = IntervalVector::new(vec![symmetric(r)?; self.rows()]);
+        for _ in 0..max_iter {
+            let next = cb.try_add(&e.try_mul(&x)?)?;
+            let next = next.intersection(&x);
+            // The solution set is nonempty and contained in both
+            // operands; an empty meet means T's rounding broke the
+            // enclosure, so keep the last sound box.
+            if next.is_empty() || next == x {
+                break;
+            }
+            x = next;
+        }
+        Ok(x)
+    }
+
+    /// Encloses the solution set of `Ax = b` with the Hansen–Bliek–Rohn
+    /// bound, applied in the Ning–Kearfott form to the
+    /// midpoint-preconditioned system `(CA)x = Cb`.
+    ///
+    /// Let `M = ⟨CA⟩⁻¹` where `⟨·⟩` is the comparison matrix
+    /// (`⟨A⟩_ii = mig(A_ii)`, `⟨A⟩_ij = −mag(A_ij)`). When `CA` is an
+    /// H-matrix (`M ≥ 0`), with `u = M·|Cb|` and `d_i = M_ii`,
+    ///
+    /// ```text
+    /// x_i ∈ ((Cb)_i + [−β_i, β_i]) / ((CA)_ii + [−α_i, α_i])
+    /// α_i = ⟨CA⟩_ii − 1/d_i,   β_i = u_i/d_i − |(Cb)_i|
+    /// ```
+    ///
+    /// This is the interval hull of the preconditioned solution set, so
+    /// it is never wider than [`solve_krawczyk`](Self::solve_krawczyk)'s
+    /// fixed point on the same system.
+    ///
+    /// ```
+    /// use intervalsets::prelude::*;
+    /// use intervalsets::linalg::{IntervalMatrix, IntervalVector};
+    ///
+    /// let a = IntervalMatrix::from_rows(vec![
+    ///     vec![Interval::closed(3.5, 4.5), Interval::closed(-0.5, 0.5)],
+    ///     vec![Interval::closed(-0.5, 0.5), Interval::closed(3.5, 4.5)],
+    /// ])
+    /// .unwrap();
+    /// let b = IntervalVector::try_from_points([4.0, 4.0]).unwrap();
+    /// let x = a.solve_hansen_bliek(&b).unwrap();
+    /// assert!(x.contains_point(&[1.0, 1.0]));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::DimensionMismatch`] if `A` is not square or `b` has
+    ///   the wrong length.
+    /// - [`Error::SingularMatrix`] if `mid(A)` is singular or `CA` is
+    ///   not an H-matrix.
+    /// - `Error::Math(MathError::Domain)` if any entry is empty or
+    ///   unbounded; otherwise any arithmetic error from `T`.
+    pub fn solve_hansen_bliek(&self, b: &IntervalVector<T>) -> Result<IntervalVector<T>, Error> {
+        self.check_system(Some(b))?;
+        let Some(Preconditioned { ca, cb: Some(cb) }) = self.precondition(Some(b))? else {
+            return Err(Error::SingularMatrix);
+        };
+        let n = self.rows();
+
+        let mut cmp = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                let v = if i == j {
+                    mig(&ca[(i, j)])?
+                } else {
+                    T::zero().try_sub(mag(&ca[(i, j)])?)?
+                };
+                cmp.push(v);
+            }
+        }
+        let Some(m) = point_inverse(n, cmp.clone())? else {
+            return Err(Error::SingularMatrix);
+        };
+        if m.iter().any(|v| *v < T::zero()) {
+            return Err(Error::SingularMatrix);
+        }
+
+        let bmag = cb.iter().map(mag).collect::<Result<Vec<_>, _>>()?;
+        let mut x = Vec::with_capacity(n);
+        for i in 0..n {
+            let u = (0..n).try_fold(T::zero(), |acc, j| {
+                acc.try_add(m[i * n + j].clone().try_mul(bmag[j].clone())?)
+            })?;
+            let d = m[i * n + i].clone();
+            // Both are nonnegative in exact arithmetic; clamp away
+            // rounding residue so the radii stay valid.
+            let alpha = cmp[i * n + i]
+                .clone()
+                .try_sub(T::one().try_div(d.clone())?)?;
+            let beta = u.try_div(d)?.try_sub(bmag[i].clone())?;
+            let num = cb[i].clone().try_add(symmetric(max(beta, T::zero()))?)?;
+            let den = ca[(i, i)]
+                .clone()
+                .try_add(symmetric(max(alpha, T::zero()))?)?;
+            x.push(quotient(num, den)?);
+        }
+        Ok(IntervalVector::new(x))
+    }
+}
+
+/// `num / den` for a zero-free `den`, which always yields a single
+/// interval.
+fn quotient<T: LinalgElement>(num: Interval<T>, den: Interval<T>) -> Result<Interval<T>, Error> {
+    if mig(&den)? <= T::zero() {
+        return Err(Error::SingularMatrix);
+    }
+    Ok(num.try_div(den)?.expect_interval())
+}
+
+/// Inverse of the row-major `n x n` point matrix `a` by Gauss–Jordan
+/// elimination with partial pivoting. `None` when a zero pivot shows
+/// `a` singular.
+fn point_inverse<T: LinalgElement>(n: usize, mut a: Vec<T>) -> Result<Option<Vec<T>>, Error> {
+    let mut inv: Vec<T> = (0..n * n)
+        .map(|k| {
+            if k % (n + 1) == 0 {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+        .collect();
+
+    for k in 0..n {
+        let mut pivot = k;
+        let mut best = abs(a[k * n + k].clone())?;
+        for i in k + 1..n {
+            let v = abs(a[i * n + k].clone())?;
+            if v > best {
+                best = v;
+                pivot = i;
+            }
+        }
+        if best <= T::zero() {
+            return Ok(None);
+        }
+        if pivot != k {
+            for j in 0..n {
+                a.swap(k * n + j, pivot * n + j);
+                inv.swap(k * n + j, pivot * n + j);
+            }
+        }
+
+        let p = a[k * n + k].clone();
+        for j in 0..n {
+            a[k * n + j] = a[k * n + j].clone().try_div(p.clone())?;
+            inv[k * n + j] = inv[k * n + j].clone().try_div(p.clone())?;
+        }
+        for i in (0..n).filter(|&i| i != k) {
+            let f = a[i * n + k].clone();
+            if f == T::zero() {
+                continue;
+            }
+            for j in 0..n {
+                let t = f.clone().try_mul(a[k * n + j].clone())?;
+                a[i * n + j] = a[i * n + j].clone().try_sub(t)?;
+                let t = f.clone().try_mul(inv[k * n + j].clone())?;
+                inv[i * n + j] = inv[i * n + j].clone().try_sub(t)?;
+            }
+        }
+    }
+    Ok(Some(inv))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::MathError;
+    use crate::factory::traits::*;
+    use crate::ops::Contains;
+    use crate::SetBounds;
+
+    fn m(rows: &[&[(f64, f64)]]) -> IntervalMatrix<f64> {
+        IntervalMatrix::from_rows(
+            rows.iter()
+                .map(|r| r.iter().map(|&(l, h)| Interval::closed(l, h)).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn v(pairs: &[(f64, f64)]) -> IntervalVector<f64> {
+        pairs.iter().map(|&(l, r)| Interval::closed(l, r)).collect()
+    }
+
+    /// Diagonally dominant 2x2 system with a box right-hand side.
+    fn dominant() -> (IntervalMatrix<f64>, IntervalVector<f64>) {
+        let a = m(&[&[(3.5, 4.5), (-0.5, 0.5)], &[(-0.5, 0.5), (3.5, 4.5)]]);
+        let b = v(&[(3.0, 5.0), (3.0, 5.0)]);
+        (a, b)
+    }
+
+    /// Solve the 2x2 point system exactly by Cramer's rule.
+    fn cramer(a: [f64; 4], b: [f64; 2]) -> [f64; 2] {
+        let det = a[0] * a[3] - a[1] * a[2];
+        [
+            (b[0] * a[3] - a[1] * b[1]) / det,
+            (a[0] * b[1] - b[0] * a[2]) / det,
+        ]
+    }
+
+    /// Every vertex system of `dominant()` has its solution inside the
+    /// enclosure `x`.
+    fn encloses_vertices(x: &IntervalVector<f64>) -> bool {
+        let (a, b) = dominant();
+        let ends = |iv: &Interval<f64>| [*iv.lval().unwrap(), *iv.rval().unwrap()];
+        let mut ok = true;
+        for a0 in ends(&a[(0, 0)]) {
+            for a1 in ends(&a[(0, 1)]) {
+                for a2 in ends(&a[(1, 0)]) {
+                    for a3 in ends(&a[(1, 1)]) {
+                        for b0 in ends(&b[0]) {
+                            for b1 in ends(&b[1]) {
+                                let s = cramer([a0, a1, a2, a3], [b0, b1]);
+                                // rounding slack at the bounds
+                                ok &= x.iter().zip(s).all(|(iv, s)| {
+                                    iv.contains(&s)
+                                        || (iv.lval().unwrap() - s).abs() < 1e-12
+                                        || (iv.rval().unwrap() - s).abs() < 1e-12
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        ok
+    }
+
+    fn width(x: &Interval<f64>) -> f64 {
+        x.rval().unwrap() - x.lval().unwrap()
+    }
+
+    // ===== Point systems =====
+
+    #[test]
+    fn point_system_is_exact() {
+        let a = IntervalMatrix::try_from_points(2, 2, vec![2.0, 1.0, 1.0, 1.0]).unwrap();
+        let b = IntervalVector::try_from_points([3.0, 2.0]).unwrap();
+        let exact = IntervalVector::try_from_points([1.0, 1.0]).unwrap();
+        assert_eq!(a.solve_gauss(&b), Ok(exact.clone()));
+        assert_eq!(a.solve_krawczyk(&b, 10), Ok(exact.clone()));
+        assert_eq!(a.solve_hansen_bliek(&b), Ok(exact));
+    }
+
+    #[test]
+    fn gauss_pivots_past_zero() {
+        let a = IntervalMatrix::try_from_points(2, 2, vec![0.0, 1.0, 1.0, 0.0]).unwrap();
+        let b = IntervalVector::try_from_points([2.0, 3.0]).unwrap();
+        let x = a.solve_gauss(&b).unwrap();
+        assert_eq!(x, IntervalVector::try_from_points([3.0, 2.0]).unwrap());
+    }
+
+    #[test]
+    fn point_inverse_3x3() {
+        let a = vec![2.0, 0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 0.5];
+        let inv = point_inverse(3, a).unwrap().unwrap();
+        assert_eq!(inv, vec![0.5, 0.0, 0.0, 0.0, 0.25, 0.0, 0.0, 0.0, 2.0]);
+        assert_eq!(point_inverse(2, vec![1.0, 2.0, 2.0, 4.0]), Ok(None));
+    }
+
+    // ===== Interval systems =====
+
+    #[test]
+    fn all_solvers_enclose_solution_set() {
+        let (a, b) = dominant();
+        let g = a.solve_gauss(&b).unwrap();
+        let k = a.solve_krawczyk(&b, 50).unwrap();
+        let h = a.solve_hansen_bliek(&b).unwrap();
+        assert!(encloses_vertices(&g));
+        assert!(encloses_vertices(&k));
+        assert!(encloses_vertices(&h));
+    }
+
+    #[test]
+    fn hansen_bliek_is_no_wider_than_krawczyk() {
+        let (a, b) = dominant();
+        let k = a.solve_krawczyk(&b, 100).unwrap();
+        let h = a.solve_hansen_bliek(&b).unwrap();
+        for (k, h) in k.iter().zip(h.iter()) {
+            assert!(width(h) <= width(k) + 1e-12);
+        }
+    }
+
+    #[test]
+    fn regularity() {
+        let (a, _) = dominant();
+        assert_eq!(a.regularity(), Ok(Regularity::Regular));
+
+        // mid(A) = [[1, 1], [1, 1]] is singular.
+        let s = m(&[&[(0.0, 2.0), (1.0, 1.0)], &[(1.0, 1.0), (1.0, 1.0)]]);
+        assert_eq!(s.regularity(), Ok(Regularity::Singular));
+        assert_eq!(
+            s.solve_krawczyk(&v(&[(0.0, 0.0), (0.0, 0.0)]), 5),
+            Err(Error::SingularMatrix)
+        );
+
+        // Regular midpoint but radii too wide for the sufficient test.
+        let w = m(&[&[(0.0, 4.0), (-1.0, 1.0)], &[(-1.0, 1.0), (0.0, 4.0)]]);
+        assert_eq!(w.regularity(), Ok(Regularity::Unknown));
+        assert!(!Regularity::Unknown.is_regular());
+    }
+
+    #[test]
+    fn gauss_rejects_zero_pivot_column() {
+        let a = m(&[&[(-1.0, 1.0), (1.0, 1.0)], &[(-1.0, 1.0), (2.0, 2.0)]]);
+        let b = v(&[(1.0, 1.0), (1.0, 1.0)]);
+        assert_eq!(a.solve_gauss(&b), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    fn shape_and_domain_errors() {
+        let (a, b) = dominant();
+        let short = v(&[(0.0, 1.0)]);
+        assert_eq!(a.solve_gauss(&short), Err(Error::DimensionMismatch));
+        assert_eq!(a.solve_hansen_bliek(&short), Err(Error::DimensionMismatch));
+
+        let rect = IntervalMatrix::try_from_points(1, 2, vec![1.0, 2.0]).unwrap();
+        assert_eq!(rect.regularity(), Err(Error::DimensionMismatch));
+
+        let unb = IntervalMatrix::try_new(1, 1, vec![Interval::closed_unbound(1.0)]).unwrap();
+        assert_eq!(
+            unb.solve_krawczyk(&v(&[(1.0, 1.0)]), 5),
+            Err(Error::Math(MathError::Domain))
+        );
+
+        let empty = IntervalMatrix::<f64>::try_new(0, 0, vec![]).unwrap();
+        let none = IntervalVector::new(vec![]);
+        assert_eq!(empty.solve_gauss(&none), Ok(none.clone()));
+        assert_eq!(a.solve_krawczyk(&b, 0).map(|x| x.dim()), Ok(2));
+    }
+}
@@ -0,0 +1,238 @@
+use core::ops::Index;
+
+use super::{mag, max, LinalgElement};
+use crate::error::Error;
+use crate::numeric::Element;
+use crate::ops::{Contains, Intersection, Midpoint, TryAdd, TrySub};
+use crate::{Interval, MaybeEmpty};
+
+/// A box in `T^n`: a vector of [`Interval`] components.
+///
+/// ```
+/// use intervalsets::prelude::*;
+/// use intervalsets::linalg::IntervalVector;
+///
+/// let x = IntervalVector::from_iter([Interval::closed(0.0, 1.0), Interval::closed(2.0, 4.0)]);
+/// assert_eq!(x.dim(), 2);
+/// assert!(x.contains_point(&[0.5, 3.0]));
+/// assert_eq!(x.midpoint(), Ok(vec![0.5, 3.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalVector<T> {
+    elems: Vec<Interval<T>>,
+}
+
+impl<T> IntervalVector<T> {
+    /// Creates a vector from its components.
+    pub fn new(elems: Vec<Interval<T>>) -> Self {
+        Self { elems }
+    }
+
+    /// Number of components.
+    pub fn dim(&self) -> usize {
+        self.elems.len()
+    }
+
+    /// Components as a slice.
+    pub fn slice(&self) -> &[Interval<T>] {
+        &self.elems
+    }
+
+    /// Iterate over the components.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.elems.iter()
+    }
+
+    /// Returns the underlying component storage.
+    pub fn into_raw(self) -> Vec<Interval<T>> {
+        self.elems
+    }
+}
+
+impl<T: LinalgElement> IntervalVector<T> {
+    /// Creates a vector of degenerate components `[p_i, p_i]`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidElement`] if any point is rejected by
+    /// [`Element::validate`].
+    pub fn try_from_points<I: IntoIterator<Item = T>>(points: I) -> Result<Self, Error> {
+        points
+            .into_iter()
+            .map(super::point)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+
+    /// Componentwise midpoint.
+    ///
+    /// # Errors
+    ///
+    /// `Error::Math(MathError::Domain)` if any component is empty or
+    /// unbounded; otherwise any error from `T`'s midpoint.
+    pub fn midpoint(&self) -> Result<Vec<T>, Error> {
+        self.elems.iter().map(|x| x.midpoint()).collect()
+    }
+
+    /// Maximum-norm `max_i mag(x_i)` — the largest absolute value over
+    /// every point of the box. Zero for a zero-dimensional vector.
+    ///
+    /// # Errors
+    ///
+    /// `Error::Math(MathError::Domain)` if any component is empty or
+    /// unbounded.
+    pub fn norm_inf(&self) -> Result<T, Error> {
+        self.elems
+            .iter()
+            .try_fold(T::zero(), |acc, x| Ok(max(acc, mag(x)?)))
+    }
+
+    /// Tests whether the point `p` lies in the box. A point of the
+    /// wrong dimension is never contained.
+    pub fn contains_point(&self, p: &[T]) -> bool {
+        p.len() == self.dim() && self.elems.iter().zip(p).all(|(x, v)| x.contains(v))
+    }
+}
+
+impl<T> MaybeEmpty for IntervalVector<T> {
+    /// A box is empty when any of its components is empty.
+    fn is_empty(&self) -> bool {
+        self.elems.iter().any(|x| x.is_empty())
+    }
+}
+
+impl<T> Index<usize> for IntervalVector<T> {
+    type Output = Interval<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.elems[index]
+    }
+}
+
+impl<T> From<Vec<Interval<T>>> for IntervalVector<T> {
+    fn from(elems: Vec<Interval<T>>) -> Self {
+        Self::new(elems)
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for IntervalVector<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for IntervalVector<T> {
+    type Item = Interval<T>;
+    type IntoIter = std::vec::IntoIter<Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elems.into_iter()
+    }
+}
+
+/// Componentwise intersection. Mismatched dimensions intersect over
+/// the common prefix; callers that care should compare
+/// [`dim`](IntervalVector::dim) first.
+impl<T: Element + Clone> Intersection<Self> for &IntervalVector<T> {
+    type Output = IntervalVector<T>;
+
+    fn intersection(self, rhs: Self) -> Self::Output {
+        self.elems
+            .iter()
+            .zip(rhs.elems.iter())
+            .map(|(a, b)| a.intersection(b))
+            .collect()
+    }
+}
+
+impl<T: LinalgElement> TryAdd for &IntervalVector<T> {
+    type Output = IntervalVector<T>;
+    type Error = Error;
+
+    /// Componentwise sum.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] on differing dimensions; otherwise
+    /// any entry-level arithmetic error.
+    fn try_add(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+        if self.dim() != rhs.dim() {
+            return Err(Error::DimensionMismatch);
+        }
+        self.elems
+            .iter()
+            .zip(rhs.elems.iter())
+            .map(|(a, b)| a.clone().try_add(b.clone()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(IntervalVector::new)
+    }
+}
+
+impl<T: LinalgElement> TrySub for &IntervalVector<T> {
+    type Output = IntervalVector<T>;
+    type Error = Error;
+
+    /// Componentwise difference.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DimensionMismatch`] on differing dimensions; otherwise
+    /// any entry-level arithmetic error.
+    fn try_sub(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+        if self.dim() != rhs.dim() {
+            return Err(Error::DimensionMismatch);
+        }
+        self.elems
+            .iter()
+            .zip(rhs.elems.iter())
+            .map(|(a, b)| a.clone().try_sub(b.clone()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(IntervalVector::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+
+    fn v(pairs: &[(f64, f64)]) -> IntervalVector<f64> {
+        pairs.iter().map(|&(l, r)| Interval::closed(l, r)).collect()
+    }
+
+    #[test]
+    fn add_sub() {
+        let a = v(&[(0.0, 1.0), (2.0, 3.0)]);
+        let b = v(&[(1.0, 1.0), (-1.0, 1.0)]);
+        assert_eq!(a.try_add(&b), Ok(v(&[(1.0, 2.0), (1.0, 4.0)])));
+        assert_eq!(a.try_sub(&b), Ok(v(&[(-1.0, 0.0), (1.0, 4.0)])));
+    }
+
+    #[test]
+    fn dimension_mismatch() {
+        let a = v(&[(0.0, 1.0)]);
+        let b = v(&[(0.0, 1.0), (0.0, 1.0)]);
+        assert_eq!(a.try_add(&b), Err(Error::DimensionMismatch));
+        assert!(!b.contains_point(&[0.5]));
+    }
+
+    #[test]
+    fn norm_and_emptiness() {
+        let a = v(&[(-4.0, 1.0), (2.0, 3.0)]);
+        assert_eq!(a.norm_inf(), Ok(4.0));
+        assert!(a.is_inhabited());
+
+        let b = v(&[(0.0, 1.0)]).intersection(&v(&[(2.0, 3.0)]));
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn from_points() {
+        let p = IntervalVector::try_from_points([1.0, 2.0]).unwrap();
+        assert_eq!(p, v(&[(1.0, 1.0), (2.0, 2.0)]));
+        assert_eq!(
+            IntervalVector::try_from_points([f64::NAN]),
+            Err(Error::InvalidElement)
+        );
+    }
+}
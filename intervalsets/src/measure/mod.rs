@@ -15,9 +15,12 @@
 //!
 //! The unified [`Measure`] trait returns the natural additive measure
 //! of a set: cardinality on discrete element types, Lebesgue width on
-//! continuous element types.
+//! continuous element types. [`MeasureWith`] applies any other
+//! additive measure, such as a [`Cdf`], [`Density`] or [`PointMass`].
 
-pub use intervalsets_core::measure::{Extent, Measure};
+pub use intervalsets_core::measure::{
+    Cdf, CustomMeasure, Density, Extent, Measure, MeasureWith, PointMass,
+};
 
 #[allow(clippy::module_inception)]
 mod measure;
@@ -0,0 +1,456 @@
+//! Distances and similarities between sets.
+//!
+//! [`SetMetrics`] is implemented by [`Interval`] and [`IntervalSet`],
+//! in any combination:
+//!
+//! | method | definition | empty operand |
+//! |--------|------------|---------------|
+//! | [`point_distance`](SetMetrics::point_distance) | `inf { \|x - s\| : s ∈ S }` | `Infinite` |
+//! | [`gap_distance`](SetMetrics::gap_distance) | `inf { \|a - b\| : a ∈ A, b ∈ B }` | `Infinite` |
+//! | [`hausdorff_distance`](SetMetrics::hausdorff_distance) | `max(sup_A d(a, B), sup_B d(b, A))` | `0` if both, else `Infinite` |
+//! | [`jaccard`](SetMetrics::jaccard) | `m(A ∩ B) / m(A ∪ B)` | `Err` |
+//! | [`overlap_coefficient`](SetMetrics::overlap_coefficient) | `m(A ∩ B) / min(m(A), m(B))` | `Err` |
+//!
+//! # Distances
+//!
+//! Distances are [`Extent`]s in units of `T`, and are differences
+//! computed with [`TrySub`]. The larger value is always the minuend,
+//! so unsigned `T` works. A distance is `Infinite` when no finite
+//! bound exists, e.g. from a point to an empty set or between a
+//! half-bounded set and a bounded one under Hausdorff.
+//!
+//! **Open endpoints** do not change a distance: the infimum and
+//! supremum are attained on the closure of each set. The distance from
+//! `1.0` to `(0.0, 1.0)` is `0`, and `[0.0, 1.0)` and `(1.0, 2.0]` have
+//! gap distance `0` even though they share no member. Discrete sets
+//! are stored with closed bounds, so for them the closure is the set
+//! itself and every distance is attained by actual members.
+//!
+//! # Similarities
+//!
+//! Similarities are ratios of [`Measure`]s, converted through `f64`
+//! like [`Quantile::cdf`](crate::ops::Quantile::cdf). A ratio with an
+//! infinite numerator or a zero denominator — e.g. two unbounded sets
+//! with an unbounded overlap, or two empty sets — is undefined and
+//! returns `Err(MathError::Domain)`. A finite overlap of an infinite
+//! union gives `0.0`.
+//!
+//! # Errors
+//!
+//! `Err(MathError::Range)` (or `Domain` for non-finite floats) when a
+//! difference overflows `T`, e.g. `i32::MIN` to `i32::MAX`, and
+//! whatever [`Measure`] reports for the similarities.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::measure::Extent;
+//! use intervalsets::metrics::SetMetrics;
+//!
+//! let predicted = IntervalSet::new([Interval::closed(0, 9), Interval::closed(20, 29)]);
+//! let actual = Interval::closed(5, 24);
+//!
+//! assert_eq!(predicted.point_distance(&15), Ok(Extent::Finite(5)));
+//! assert_eq!(predicted.gap_distance(&actual), Ok(Extent::Finite(0)));
+//! assert_eq!(predicted.hausdorff_distance(&actual), Ok(Extent::Finite(5)));
+//! assert_eq!(predicted.jaccard(&actual), Ok(10.0 / 30.0));
+//! assert_eq!(predicted.overlap_coefficient(&actual), Ok(0.5));
+//! ```
+
+use core::convert::Infallible;
+
+use intervalsets_core::measure::{Extent, Measure};
+use intervalsets_core::ops::math::{TryAdd, TrySub};
+use num_traits::ToPrimitive;
+
+use crate::error::MathError;
+use crate::numeric::{Element, Midpointable, Zero};
+use crate::ops::{Intersection, Union};
+use crate::{Interval, IntervalSet, MaybeEmpty, SetBounds};
+
+mod private {
+    use crate::Interval;
+
+    pub trait Components<T> {
+        /// The connected components of `self`, in ascending order.
+        /// Empty components are ignored.
+        fn components(&self) -> &[Interval<T>];
+    }
+}
+
+/// Distances and measure similarities between sets.
+///
+/// See the [module docs](self) for definitions and edge cases.
+pub trait SetMetrics<T>: private::Components<T> {
+    /// Distance from `x` to the nearest point of the set's closure.
+    ///
+    /// # Errors
+    ///
+    /// The error of the [`TrySub`] that measures the distance.
+    fn point_distance(&self, x: &T) -> Result<Extent<T>, MathError>
+    where
+        T: Element + Clone + Zero + TrySub<Output = T>,
+        <T as TrySub>::Error: Into<MathError>,
+    {
+        distance_to(self.components(), Some(x), Some(x))
+    }
+
+    /// Distance between the closures of `self` and `other`; `0` if
+    /// they intersect or touch.
+    ///
+    /// # Errors
+    ///
+    /// As for [`point_distance`](Self::point_distance).
+    fn gap_distance<S: SetMetrics<T>>(&self, other: &S) -> Result<Extent<T>, MathError>
+    where
+        T: Element + Clone + Zero + TrySub<Output = T>,
+        <T as TrySub>::Error: Into<MathError>,
+    {
+        let mut best = Extent::Infinite;
+        for piece in inhabited(self.components()) {
+            let d = distance_to(other.components(), piece.lval(), piece.rval())?;
+            best = min(best, d);
+            if best == Extent::Finite(T::zero()) {
+                break;
+            }
+        }
+        Ok(best)
+    }
+
+    /// Hausdorff distance between the closures of `self` and `other`:
+    /// the farthest any point of either set is from the other set.
+    ///
+    /// # Errors
+    ///
+    /// As for [`point_distance`](Self::point_distance).
+    fn hausdorff_distance<S: SetMetrics<T>>(&self, other: &S) -> Result<Extent<T>, MathError>
+    where
+        T: Element + Clone + Zero + TrySub<Output = T> + Midpointable<Error = Infallible>,
+        <T as TrySub>::Error: Into<MathError>,
+    {
+        let there = directed_hausdorff(self.components(), other.components())?;
+        let back = directed_hausdorff(other.components(), self.components())?;
+        Ok(max(there, back))
+    }
+
+    /// Jaccard index `m(A ∩ B) / m(A ∪ B)`, in `[0, 1]`.
+    ///
+    /// # Errors
+    ///
+    /// `Err(MathError::Domain)` if the ratio is undefined (see the
+    /// [module docs](self)); otherwise the error of [`Measure`].
+    fn jaccard<S: SetMetrics<T>>(&self, other: &S) -> Result<f64, MathError>
+    where
+        T: Element + Clone,
+        T::Measure: ToPrimitive,
+        <T::Measure as TryAdd>::Error: Into<MathError>,
+    {
+        let (a, b) = (to_set(self.components()), to_set(other.components()));
+        let overlap = a.clone().intersection(b.clone()).try_measure()?;
+        let union = a.union(b).try_measure()?;
+        ratio(overlap, union)
+    }
+
+    /// Overlap (Szymkiewicz–Simpson) coefficient
+    /// `m(A ∩ B) / min(m(A), m(B))`, in `[0, 1]`; `1` whenever one set
+    /// contains the other.
+    ///
+    /// # Errors
+    ///
+    /// As for [`jaccard`](Self::jaccard).
+    fn overlap_coefficient<S: SetMetrics<T>>(&self, other: &S) -> Result<f64, MathError>
+    where
+        T: Element + Clone,
+        T::Measure: ToPrimitive,
+        <T::Measure as TryAdd>::Error: Into<MathError>,
+    {
+        let (a, b) = (to_set(self.components()), to_set(other.components()));
+        let overlap = a.clone().intersection(b.clone()).try_measure()?;
+        let (ma, mb) = (a.try_measure()?, b.try_measure()?);
+        let smaller = match (ma, mb) {
+            (Extent::Finite(x), Extent::Finite(y)) => {
+                let (fx, fy) = (to_f64(&x)?, to_f64(&y)?);
+                Extent::Finite(if fx <= fy { x } else { y })
+            }
+            (Extent::Finite(x), Extent::Infinite) | (Extent::Infinite, Extent::Finite(x)) => {
+                Extent::Finite(x)
+            }
+            (Extent::Infinite, Extent::Infinite) => Extent::Infinite,
+        };
+        ratio(overlap, smaller)
+    }
+}
+
+impl<T> private::Components<T> for Interval<T> {
+    fn components(&self) -> &[Interval<T>] {
+        core::slice::from_ref(self)
+    }
+}
+
+impl<T> private::Components<T> for IntervalSet<T> {
+    fn components(&self) -> &[Interval<T>] {
+        self.slice()
+    }
+}
+
+impl<T> SetMetrics<T> for Interval<T> {}
+
+impl<T> SetMetrics<T> for IntervalSet<T> {}
+
+fn inhabited<T>(pieces: &[Interval<T>]) -> impl Iterator<Item = &Interval<T>> {
+    pieces.iter().filter(|p| p.is_inhabited())
+}
+
+/// `lo <= hi` where `None` is `-∞` for `lo` and `+∞` for `hi`.
+fn below<T: PartialOrd>(lo: Option<&T>, hi: Option<&T>) -> bool {
+    match (lo, hi) {
+        (Some(l), Some(h)) => l <= h,
+        _ => true,
+    }
+}
+
+fn sub<T>(hi: &T, lo: &T) -> Result<Extent<T>, MathError>
+where
+    T: Clone + TrySub<Output = T>,
+    <T as TrySub>::Error: Into<MathError>,
+{
+    hi.clone()
+        .try_sub(lo.clone())
+        .map(Extent::Finite)
+        .map_err(Into::into)
+}
+
+fn min<T: PartialOrd>(a: Extent<T>, b: Extent<T>) -> Extent<T> {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<T: PartialOrd>(a: Extent<T>, b: Extent<T>) -> Extent<T> {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Distance from the closed span `[lo, hi]` (`None` unbounded) to the
+/// closure of `pieces`. The pieces are sorted, so only the first piece
+/// not entirely left of `lo` and its predecessor can be nearest.
+fn distance_to<T>(
+    pieces: &[Interval<T>],
+    lo: Option<&T>,
+    hi: Option<&T>,
+) -> Result<Extent<T>, MathError>
+where
+    T: Element + Clone + Zero + TrySub<Output = T>,
+    <T as TrySub>::Error: Into<MathError>,
+{
+    let pieces: &[Interval<T>] = match pieces {
+        [p] if p.is_empty() => &[],
+        _ => pieces,
+    };
+    let idx = pieces.partition_point(|p| !below(lo, p.rval()));
+    let mut best = Extent::Infinite;
+    if let Some(next) = pieces.get(idx) {
+        if below(next.lval(), hi) {
+            return Ok(Extent::Finite(T::zero()));
+        }
+        // `next` starts right of `hi`, so both are finite.
+        if let (Some(start), Some(hi)) = (next.lval(), hi) {
+            best = sub(start, hi)?;
+        }
+    }
+    if let (Some(prev), Some(lo)) = (idx.checked_sub(1).map(|i| &pieces[i]), lo) {
+        if let Some(end) = prev.rval() {
+            best = min(best, sub(lo, end)?);
+        }
+    }
+    Ok(best)
+}
+
+/// `sup { d(a, B) : a ∈ cl(A) }`. The distance to `B` is piecewise
+/// linear in `a`, so the supremum is reached at an endpoint of `A` or
+/// at the middle of a gap of `B` lying inside `A`.
+fn directed_hausdorff<T>(a: &[Interval<T>], b: &[Interval<T>]) -> Result<Extent<T>, MathError>
+where
+    T: Element + Clone + Zero + TrySub<Output = T> + Midpointable<Error = Infallible>,
+    <T as TrySub>::Error: Into<MathError>,
+{
+    let (Some(a_first), Some(a_last)) = (inhabited(a).next(), inhabited(a).last()) else {
+        return Ok(Extent::Finite(T::zero()));
+    };
+    let (Some(b_first), Some(b_last)) = (inhabited(b).next(), inhabited(b).last()) else {
+        return Ok(Extent::Infinite);
+    };
+    let escapes_left = a_first.lval().is_none() && b_first.lval().is_some();
+    let escapes_right = a_last.rval().is_none() && b_last.rval().is_some();
+    if escapes_left || escapes_right {
+        return Ok(Extent::Infinite);
+    }
+
+    let mut worst = Extent::Finite(T::zero());
+    for piece in inhabited(a) {
+        for x in [piece.lval(), piece.rval()].into_iter().flatten() {
+            worst = max(worst, distance_to(b, Some(x), Some(x))?);
+        }
+    }
+    for pair in b.windows(2) {
+        let (Some(end), Some(start)) = (pair[0].rval(), pair[1].lval()) else {
+            continue;
+        };
+        let Ok(mid) = T::midpoint(end.clone(), start.clone());
+        if distance_to(a, Some(&mid), Some(&mid))? == Extent::Finite(T::zero()) {
+            worst = max(worst, distance_to(b, Some(&mid), Some(&mid))?);
+        }
+    }
+    Ok(worst)
+}
+
+fn to_set<T: Element + Clone>(pieces: &[Interval<T>]) -> IntervalSet<T> {
+    IntervalSet::new(pieces.iter().cloned())
+}
+
+fn to_f64<M: ToPrimitive>(m: &M) -> Result<f64, MathError> {
+    m.to_f64().ok_or(MathError::Range)
+}
+
+/// `num / den` for measures; undefined shapes are `Domain`.
+fn ratio<M: ToPrimitive>(num: Extent<M>, den: Extent<M>) -> Result<f64, MathError> {
+    match (num, den) {
+        (Extent::Finite(_), Extent::Infinite) => Ok(0.0),
+        (Extent::Finite(n), Extent::Finite(d)) => {
+            let d = to_f64(&d)?;
+            if d > 0.0 {
+                Ok(to_f64(&n)? / d)
+            } else {
+                Err(MathError::Domain)
+            }
+        }
+        (Extent::Infinite, _) => Err(MathError::Domain),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::TestResult;
+
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::Contains;
+
+    fn fin<T>(x: T) -> Result<Extent<T>, MathError> {
+        Ok(Extent::Finite(x))
+    }
+
+    #[test]
+    fn point_distance() {
+        let s = IntervalSet::new([Interval::closed(0, 9), Interval::closed(20, 29)]);
+        assert_eq!(s.point_distance(&-3), fin(3));
+        assert_eq!(s.point_distance(&5), fin(0));
+        assert_eq!(s.point_distance(&12), fin(3));
+        assert_eq!(s.point_distance(&17), fin(3));
+        assert_eq!(s.point_distance(&40), fin(11));
+        assert_eq!(
+            IntervalSet::<i32>::empty().point_distance(&0),
+            Ok(Extent::Infinite)
+        );
+        assert_eq!(
+            Interval::<i32>::empty().point_distance(&0),
+            Ok(Extent::Infinite)
+        );
+
+        // Open endpoints: the infimum is attained on the closure.
+        let x = Interval::open(0.0, 1.0);
+        assert_eq!(x.point_distance(&1.0), fin(0.0));
+        assert_eq!(x.point_distance(&3.0), fin(2.0));
+        assert_eq!(Interval::closed_unbound(5_u8).point_distance(&2), fin(3));
+    }
+
+    #[test]
+    fn gap_distance() {
+        let a = Interval::closed_open(0.0, 1.0);
+        let b = Interval::open_closed(1.0, 2.0);
+        assert_eq!(a.gap_distance(&b), fin(0.0));
+
+        let a = IntervalSet::new([Interval::closed(0, 3), Interval::closed(50, 60)]);
+        let b = IntervalSet::new([Interval::closed(10, 20), Interval::closed(45, 47)]);
+        assert_eq!(a.gap_distance(&b), fin(3));
+        assert_eq!(b.gap_distance(&a), fin(3));
+        assert_eq!(
+            a.gap_distance(&Interval::<i32>::empty()),
+            Ok(Extent::Infinite)
+        );
+        assert_eq!(
+            Interval::unbound_closed(0_u32).gap_distance(&Interval::closed(7, 9)),
+            fin(7)
+        );
+    }
+
+    #[test]
+    fn hausdorff_distance() {
+        let a = Interval::closed(0, 10);
+        let b = IntervalSet::new([Interval::closed(0, 2), Interval::closed(8, 10)]);
+        // 5 is 3 away from b; b is entirely inside a.
+        assert_eq!(a.hausdorff_distance(&b), fin(3));
+        assert_eq!(b.hausdorff_distance(&a), fin(3));
+
+        let a = Interval::closed(0.0, 1.0);
+        let b = Interval::closed(3.0, 10.0);
+        assert_eq!(a.hausdorff_distance(&b), fin(9.0));
+
+        let a = Interval::closed_unbound(0.0);
+        assert_eq!(a.hausdorff_distance(&b), Ok(Extent::Infinite));
+        assert_eq!(
+            a.hausdorff_distance(&Interval::closed_unbound(1.0)),
+            fin(1.0)
+        );
+
+        let e = Interval::<f64>::empty();
+        assert_eq!(e.hausdorff_distance(&e), fin(0.0));
+        assert_eq!(e.hausdorff_distance(&b), Ok(Extent::Infinite));
+    }
+
+    #[test]
+    fn similarities() {
+        let a = Interval::closed(0.0, 10.0);
+        let b = Interval::closed(5.0, 20.0);
+        assert_eq!(a.jaccard(&b), Ok(0.25));
+        assert_eq!(a.overlap_coefficient(&b), Ok(0.5));
+        assert_eq!(a.jaccard(&a), Ok(1.0));
+
+        // Discrete: by cardinality.
+        let a = Interval::closed(1, 4);
+        let b = Interval::closed(4, 7);
+        assert_eq!(a.jaccard(&b), Ok(1.0 / 7.0));
+
+        let u = Interval::closed_unbound(0.0);
+        assert_eq!(u.jaccard(&Interval::closed(0.0, 1.0)), Ok(0.0));
+        assert_eq!(u.overlap_coefficient(&Interval::closed(0.0, 1.0)), Ok(1.0));
+        assert_eq!(u.jaccard(&u), Err(MathError::Domain));
+        let e = IntervalSet::<i32>::empty();
+        assert_eq!(e.jaccard(&e), Err(MathError::Domain));
+    }
+
+    #[test]
+    fn overflow_is_reported() {
+        let a = Interval::singleton(i32::MIN);
+        assert_eq!(a.point_distance(&i32::MAX), Err(MathError::Range));
+    }
+
+    #[quickcheck]
+    fn check_gap_is_min_point_distance(a: IntervalSet<i8>, b: IntervalSet<i8>) -> TestResult {
+        let mut brute = Extent::Infinite;
+        for x in (i8::MIN..=i8::MAX).filter(|x| a.contains(x)) {
+            match b.point_distance(&x) {
+                Ok(d) => brute = min(brute, d),
+                Err(_) => return TestResult::discard(),
+            }
+        }
+        match a.gap_distance(&b) {
+            Ok(d) => TestResult::from_bool(d == brute),
+            Err(_) => TestResult::discard(),
+        }
+    }
+}
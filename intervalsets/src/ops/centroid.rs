@@ -0,0 +1,124 @@
+//! Set-level [`Centroid`] trait impls for [`Interval`] and
+//! [`IntervalSet`] — measure-weighted center of mass.
+//!
+//! Each piece of an [`IntervalSet`] pulls on the centroid in
+//! proportion to its measure, so the result tracks where the set's
+//! mass actually is rather than the middle of its hull. The centroid
+//! may still land in a gap; `representative` always returns a member.
+//! The search itself lives in `intervalsets_core::ops::centroid_core`.
+//!
+//! Empty / unbounded / half-bounded inputs return
+//! `Err(Error::Math(MathError::Domain))`, as for
+//! [`Midpoint`](super::Midpoint).
+
+use core::convert::Infallible;
+
+use intervalsets_core::error::MathError;
+use intervalsets_core::ops::math::{TryAdd, TryDiv, TryMul};
+pub use intervalsets_core::ops::Centroid;
+use intervalsets_core::ops::{centroid_core, representative_core};
+
+use crate::error::Error;
+use crate::numeric::{Element, Midpointable, NumCast};
+use crate::{Interval, IntervalSet};
+
+impl<T> Centroid<T> for Interval<T>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd + NumCast + TryMul<Output = T::Measure> + TryDiv<Output = T::Measure>,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+    <T::Measure as TryMul>::Error: Into<MathError>,
+    <T::Measure as TryDiv>::Error: Into<MathError>,
+{
+    type Error = Error;
+
+    /// The midpoint, rounded down on discrete `T`; see [`Centroid`].
+    fn centroid(&self) -> Result<T, Error> {
+        Centroid::centroid(&self.0).map_err(Error::from)
+    }
+
+    fn representative(&self) -> Result<T, Error> {
+        Centroid::representative(&self.0).map_err(Error::from)
+    }
+}
+
+impl<T> Centroid<T> for IntervalSet<T>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd + NumCast + TryMul<Output = T::Measure> + TryDiv<Output = T::Measure>,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+    <T::Measure as TryMul>::Error: Into<MathError>,
+    <T::Measure as TryDiv>::Error: Into<MathError>,
+{
+    type Error = Error;
+
+    /// Measure-weighted mean of the pieces' centers. O(n) in the
+    /// number of pieces plus one midpoint search over the hull.
+    fn centroid(&self) -> Result<T, Error> {
+        Ok(centroid_core(self.slice().iter().map(|p| &p.0))?)
+    }
+
+    /// The centroid if it is a member, otherwise the nearest closed
+    /// piece endpoint; see [`Centroid`].
+    fn representative(&self) -> Result<T, Error> {
+        let c = centroid_core(self.slice().iter().map(|p| &p.0))?;
+        Ok(representative_core(self.slice().iter().map(|p| &p.0), c)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::TestResult;
+
+    use super::*;
+    use crate::bound::SetBounds;
+    use crate::factory::traits::*;
+    use crate::ops::{Contains, Midpoint};
+
+    #[test]
+    fn interval_matches_midpoint() {
+        let x = Interval::closed(0, 10);
+        assert_eq!(x.centroid(), x.midpoint());
+        assert_eq!(x.representative(), Ok(5));
+
+        let x = Interval::open(-2.0, 6.0);
+        assert_eq!(x.centroid(), Ok(2.0));
+    }
+
+    #[test]
+    fn heavy_piece_pulls_centroid() {
+        // Hull midpoint is 50, in the gap; the mass sits near 0.
+        let x = IntervalSet::new([Interval::closed(0, 89), Interval::closed(99, 100)]);
+        assert_eq!(x.midpoint(), Ok(50));
+        assert_eq!(x.centroid(), Ok(45));
+        assert_eq!(x.representative(), Ok(45));
+
+        let x = IntervalSet::new([Interval::closed(0.0, 1.0), Interval::closed(7.0, 10.0)]);
+        assert_eq!(x.centroid(), Ok(6.5));
+        assert_eq!(x.representative(), Ok(7.0));
+    }
+
+    #[test]
+    fn errors() {
+        let domain = Error::Math(MathError::Domain);
+        assert_eq!(IntervalSet::<i32>::empty().centroid(), Err(domain.clone()));
+        assert_eq!(
+            Interval::closed_unbound(0.0).representative(),
+            Err(domain.clone())
+        );
+        let x = IntervalSet::new([Interval::closed(0, 1), Interval::unbound_closed(-5)]);
+        assert_eq!(x.centroid(), Err(domain));
+    }
+
+    #[quickcheck]
+    fn check_representative_is_member(set: IntervalSet<i16>) -> TestResult {
+        let Ok(c) = set.centroid() else {
+            return TestResult::discard();
+        };
+        let r = set.representative().unwrap();
+        let (lo, hi) = (set.hull().lval().copied(), set.hull().rval().copied());
+        TestResult::from_bool(
+            set.contains(&r) && (set.contains(&c) == (r == c)) && lo <= Some(c) && Some(c) <= hi,
+        )
+    }
+}
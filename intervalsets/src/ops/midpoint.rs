@@ -6,7 +6,8 @@
 //! the interval's midpoint; for an [`IntervalSet`] with multiple
 //! pieces this is the hull midpoint and **may lie in a gap** between
 //! components. Callers wanting a point guaranteed to lie inside some
-//! component should reach for `Centroid` (planned).
+//! component should reach for
+//! [`Centroid::representative`](super::Centroid::representative).
 //!
 //! Empty / unbounded / half-bounded inputs return
 //! `Err(Error::Math(MathError::Domain))`.
@@ -37,6 +37,27 @@ pub use bisect::{Bisect, Bisection};
 mod midpoint;
 pub use midpoint::Midpoint;
 
+mod centroid;
+pub use centroid::Centroid;
+
+mod nearest;
+pub use nearest::Nearest;
+
+mod morphology;
+pub use morphology::Morphology;
+
+mod simplify;
+pub use simplify::{Simplified, Simplify};
+
+mod topology;
+pub use topology::Topology;
+
+mod partition;
+pub use partition::{EqualParts, PartitionEqual};
+
+mod quantile;
+pub use quantile::Quantile;
+
 mod intersection;
 pub use intersection::Intersection;
 
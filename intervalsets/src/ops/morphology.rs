@@ -0,0 +1,381 @@
+//! Morphological clean-up of [`IntervalSet`]s: [`Morphology`].
+//!
+//! Dilation and erosion are the Minkowski sum and difference with the
+//! closed ball `[-ε, ε]`:
+//!
+//! - `dilate(ε)` moves every left bound down by `ε` and every right
+//!   bound up by `ε`. Pieces that now touch are merged.
+//! - `erode(ε)` moves them inward by `ε`. Pieces narrower than `2ε`
+//!   vanish.
+//! - `opening(ε) = dilate(erode(ε))` drops pieces narrower than `2ε`
+//!   and leaves the rest unchanged.
+//! - `closing(ε) = erode(dilate(ε))` fills gaps narrower than `2ε` and
+//!   leaves the rest unchanged.
+//!
+//! Bound types are kept, so `[a, b)` dilates to `[a - ε, b + ε)`.
+//! Unbounded sides stay unbounded. On discrete `T`, `ε` is a number of
+//! steps: dilating `[3, 5]` by `2` gives `[1, 7]`.
+//!
+//! [`try_fill_gaps`](Morphology::try_fill_gaps) and
+//! [`try_drop_short`](Morphology::try_drop_short) express the same
+//! clean-up through [`Measure`] instead. Gaps and pieces are compared
+//! by their own measure, so thresholds are element counts on discrete
+//! `T` and widths on continuous `T`.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::ops::Morphology;
+//!
+//! let noisy = IntervalSet::new([
+//!     Interval::closed(0i32, 10),
+//!     Interval::closed(12, 20),
+//!     Interval::singleton(40),
+//! ]);
+//! let clean = noisy.fill_gaps(&1).drop_short(&2);
+//! assert_eq!(clean, IntervalSet::from(Interval::closed(0, 20)));
+//! assert_eq!(noisy.closing(&1), clean.union(Interval::singleton(40)));
+//! ```
+
+use intervalsets_core::measure::{Extent, Measure};
+use intervalsets_core::ops::math::{TryAdd, TrySub};
+use intervalsets_core::ops::MergeSortedByValue;
+
+use crate::bound::FiniteBound;
+use crate::error::{Error, MathError};
+use crate::factory::traits::*;
+use crate::numeric::{Element, Zero};
+use crate::ops::Rebound;
+use crate::{Interval, IntervalSet, MaybeEmpty, SetBounds};
+
+/// Dilation, erosion, and measure-based gap and piece filtering.
+///
+/// See the [module docs](self) for definitions.
+///
+/// # Contract
+///
+/// Tier 3 (`try_*` + panicking sugar). The `try_*` forms never panic.
+/// The plain forms unwrap them.
+///
+/// # Errors
+///
+/// - `Error::Math(MathError::Domain)` if `ε` is negative or
+///   incomparable (NaN).
+/// - The [`TryAdd`] / [`TrySub`] error when dilation moves a bound
+///   past the range of `T`, e.g. dilating `[0, i32::MAX]`. Erosion
+///   instead drops a piece whose bound it would push past the range,
+///   as no element of `T` is left in it.
+/// - For the measure-based forms, the error of [`Measure`].
+pub trait Morphology<T: Element>: Sized {
+    /// Expands every piece by `eps` on each finite side.
+    fn try_dilate(&self, eps: &T) -> Result<Self, Error>;
+
+    /// Shrinks every piece by `eps` on each finite side.
+    fn try_erode(&self, eps: &T) -> Result<Self, Error>;
+
+    /// Erosion followed by dilation: removes pieces narrower than
+    /// `2·eps`.
+    fn try_opening(&self, eps: &T) -> Result<Self, Error> {
+        self.try_erode(eps)?.try_dilate(eps)
+    }
+
+    /// Dilation followed by erosion: fills gaps narrower than `2·eps`.
+    fn try_closing(&self, eps: &T) -> Result<Self, Error> {
+        self.try_dilate(eps)?.try_erode(eps)
+    }
+
+    /// Joins neighbouring pieces whose gap has measure at most
+    /// `max_gap`.
+    fn try_fill_gaps(&self, max_gap: &T::Measure) -> Result<Self, Error>;
+
+    /// Removes pieces whose measure is less than `min_measure`.
+    /// Unbounded pieces are always kept.
+    fn try_drop_short(&self, min_measure: &T::Measure) -> Result<Self, Error>;
+
+    /// Panicking form of [`try_dilate`](Self::try_dilate).
+    fn dilate(&self, eps: &T) -> Self {
+        self.try_dilate(eps).unwrap()
+    }
+
+    /// Panicking form of [`try_erode`](Self::try_erode).
+    fn erode(&self, eps: &T) -> Self {
+        self.try_erode(eps).unwrap()
+    }
+
+    /// Panicking form of [`try_opening`](Self::try_opening).
+    fn opening(&self, eps: &T) -> Self {
+        self.try_opening(eps).unwrap()
+    }
+
+    /// Panicking form of [`try_closing`](Self::try_closing).
+    fn closing(&self, eps: &T) -> Self {
+        self.try_closing(eps).unwrap()
+    }
+
+    /// Panicking form of [`try_fill_gaps`](Self::try_fill_gaps).
+    fn fill_gaps(&self, max_gap: &T::Measure) -> Self {
+        self.try_fill_gaps(max_gap).unwrap()
+    }
+
+    /// Panicking form of [`try_drop_short`](Self::try_drop_short).
+    fn drop_short(&self, min_measure: &T::Measure) -> Self {
+        self.try_drop_short(min_measure).unwrap()
+    }
+}
+
+fn non_negative<T: PartialOrd + Zero>(eps: &T) -> Result<(), Error> {
+    match eps.partial_cmp(&T::zero()) {
+        Some(core::cmp::Ordering::Less) | None => Err(Error::Math(MathError::Domain)),
+        _ => Ok(()),
+    }
+}
+
+/// Moves `bound` by `eps`: towards `-∞` if `down`, else towards `+∞`.
+fn shift<T>(
+    bound: Option<&FiniteBound<T>>,
+    eps: &T,
+    down: bool,
+) -> Result<Option<FiniteBound<T>>, Error>
+where
+    T: Element + Clone + TryAdd<Output = T> + TrySub<Output = T>,
+    <T as TryAdd>::Error: Into<MathError>,
+    <T as TrySub>::Error: Into<MathError>,
+{
+    let Some(bound) = bound else {
+        return Ok(None);
+    };
+    let value = bound.value().clone();
+    let moved = if down {
+        value.try_sub(eps.clone()).map_err(Into::into)
+    } else {
+        value.try_add(eps.clone()).map_err(Into::into)
+    };
+    Ok(Some(FiniteBound::try_new(bound.bound_type(), moved?)?))
+}
+
+fn measure_of<T: Element>(x: &Interval<T>) -> Result<Extent<T::Measure>, Error> {
+    Ok(x.try_measure()?)
+}
+
+impl<T> Morphology<T> for IntervalSet<T>
+where
+    T: Element + Clone + Zero + TryAdd<Output = T> + TrySub<Output = T>,
+    T::Measure: PartialOrd,
+    <T as TryAdd>::Error: Into<MathError>,
+    <T as TrySub>::Error: Into<MathError>,
+{
+    fn try_dilate(&self, eps: &T) -> Result<Self, Error> {
+        non_negative(eps)?;
+        let grown = self
+            .iter()
+            .map(|piece| {
+                let left = shift(piece.left(), eps, true)?;
+                let right = shift(piece.right(), eps, false)?;
+                piece.clone().try_with_left(left)?.try_with_right(right)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        // Every left bound moved by the same amount, so the pieces are
+        // still sorted; only neighbours can have started to touch.
+        Ok(Self::new_assume_valid(MergeSortedByValue::new(grown)))
+    }
+
+    fn try_erode(&self, eps: &T) -> Result<Self, Error> {
+        non_negative(eps)?;
+        let mut shrunk = Vec::with_capacity(self.slice().len());
+        for piece in self.iter() {
+            let moved = shift(piece.left(), eps, false)
+                .and_then(|left| Ok((left, shift(piece.right(), eps, true)?)));
+            let (left, right) = match moved {
+                Ok(ends) => ends,
+                // Pushed past the range of `T`: nothing is left.
+                Err(Error::Math(_)) => continue,
+                Err(e) => return Err(e),
+            };
+            let piece = piece.clone().try_with_left(left)?.try_with_right(right)?;
+            if piece.is_inhabited() {
+                shrunk.push(piece);
+            }
+        }
+        Ok(Self::new_assume_valid(shrunk))
+    }
+
+    fn try_fill_gaps(&self, max_gap: &T::Measure) -> Result<Self, Error> {
+        let mut filled: Vec<Interval<T>> = Vec::with_capacity(self.slice().len());
+        for piece in self.iter() {
+            if let Some(prev) = filled.last_mut() {
+                // Neighbours never touch, so both facing bounds are
+                // finite and the gap between them is inhabited.
+                let gap = Interval::try_satisfy_bounds(
+                    prev.right().expect("followed by a piece").clone().flip(),
+                    piece.left().expect("preceded by a piece").clone().flip(),
+                )?;
+                if let Extent::Finite(g) = measure_of(&gap)? {
+                    if g <= *max_gap {
+                        *prev = prev.clone().try_with_right(piece.right().cloned())?;
+                        continue;
+                    }
+                }
+            }
+            filled.push(piece.clone());
+        }
+        Ok(Self::new_assume_valid(filled))
+    }
+
+    fn try_drop_short(&self, min_measure: &T::Measure) -> Result<Self, Error> {
+        let mut kept = Vec::with_capacity(self.slice().len());
+        for piece in self.iter() {
+            let keep = match measure_of(piece)? {
+                Extent::Infinite => true,
+                Extent::Finite(m) => m >= *min_measure,
+            };
+            if keep {
+                kept.push(piece.clone());
+            }
+        }
+        Ok(Self::new_assume_valid(kept))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::TestResult;
+
+    use super::*;
+    use crate::bound::BoundType;
+    use crate::ops::{Contains, Union};
+
+    fn set<T: Element + Clone>(pieces: impl IntoIterator<Item = Interval<T>>) -> IntervalSet<T> {
+        IntervalSet::new(pieces)
+    }
+
+    #[test]
+    fn dilate_merges_and_keeps_bound_types() {
+        let x = set([Interval::closed_open(0.0, 1.0), Interval::open(3.0, 4.0)]);
+        assert_eq!(
+            x.dilate(&0.5),
+            set([Interval::closed_open(-0.5, 1.5), Interval::open(2.5, 4.5)])
+        );
+        // Facing open bounds at 2.0 leave that point uncovered.
+        assert_eq!(
+            x.dilate(&1.0),
+            set([Interval::closed_open(-1.0, 2.0), Interval::open(2.0, 5.0)])
+        );
+        assert_eq!(x.dilate(&1.5), set([Interval::closed_open(-1.5, 5.5)]));
+        assert_eq!(x.dilate(&0.0), x);
+    }
+
+    #[test]
+    fn discrete_eps_counts_steps() {
+        let x = set([Interval::closed(3, 5), Interval::closed(9, 9)]);
+        assert_eq!(x.dilate(&2), set([Interval::closed(1, 11)]));
+        assert_eq!(
+            x.dilate(&1),
+            set([Interval::closed(2, 6), Interval::closed(8, 10)])
+        );
+        assert_eq!(x.erode(&1), set([Interval::singleton(4)]));
+        assert_eq!(x.opening(&1), set([Interval::closed(3, 5)]));
+        assert_eq!(x.closing(&2), set([Interval::closed(3, 9)]));
+    }
+
+    #[test]
+    fn unbounded_pieces() {
+        let x = set([
+            Interval::unbound_closed(0.0),
+            Interval::closed_unbound(10.0),
+        ]);
+        assert_eq!(
+            x.erode(&1.0),
+            set([
+                Interval::unbound_closed(-1.0),
+                Interval::closed_unbound(11.0)
+            ])
+        );
+        assert_eq!(x.dilate(&5.0), set([Interval::unbounded()]));
+        assert_eq!(x.drop_short(&1e300), x);
+    }
+
+    #[test]
+    fn fill_gaps_and_drop_short() {
+        let x: IntervalSet<i32> = set([
+            Interval::closed(0, 10),
+            Interval::closed(13, 20),
+            Interval::closed(23, 23),
+            Interval::closed(30, 40),
+        ]);
+        // Gaps hold 2, 2 and 6 elements.
+        assert_eq!(
+            x.fill_gaps(&2),
+            set([Interval::closed(0, 23), Interval::closed(30, 40)])
+        );
+        assert_eq!(x.fill_gaps(&1), x);
+        assert_eq!(
+            x.drop_short(&2),
+            set([
+                Interval::closed(0, 10),
+                Interval::closed(13, 20),
+                Interval::closed(30, 40)
+            ])
+        );
+
+        // Continuous gaps are widths; touching limits are kept apart.
+        let y = set([Interval::closed_open(0.0, 1.0), Interval::open(1.0, 2.0)]);
+        assert_eq!(y.fill_gaps(&0.0), set([Interval::closed_open(0.0, 2.0)]));
+    }
+
+    #[test]
+    fn erode_near_type_limits() {
+        let x = set([
+            Interval::closed(i32::MIN, i32::MIN + 5),
+            Interval::closed(i32::MAX - 1, i32::MAX),
+        ]);
+        assert_eq!(
+            x.erode(&2),
+            set([Interval::closed(i32::MIN + 2, i32::MIN + 3)])
+        );
+        assert_eq!(x.erode(&i32::MAX), IntervalSet::empty());
+        assert_eq!(
+            x.opening(&1),
+            set([Interval::closed(i32::MIN, i32::MIN + 5)])
+        );
+
+        let y = set([Interval::closed(0_u8, 1), Interval::closed_unbound(250)]);
+        assert_eq!(y.erode(&3), set([Interval::closed_unbound(253)]));
+        assert_eq!(y.erode(&u8::MAX), IntervalSet::empty());
+
+        let z = set([Interval::closed(0.0, 1.0)]);
+        assert_eq!(z.erode(&f64::MAX), IntervalSet::empty());
+    }
+
+    #[test]
+    fn errors() {
+        let x = set([Interval::closed(0, i32::MAX)]);
+        assert_eq!(x.try_dilate(&1), Err(Error::Math(MathError::Range)));
+        assert_eq!(x.try_erode(&-1), Err(Error::Math(MathError::Domain)));
+        let y = set([Interval::closed(0.0, 1.0)]);
+        assert_eq!(y.try_dilate(&f64::NAN), Err(Error::Math(MathError::Domain)));
+    }
+
+    #[quickcheck]
+    fn check_opening_closing_bracket(x: IntervalSet<i16>, eps: u8) -> TestResult {
+        let eps = i16::from(eps % 8);
+        // An open bound at the end of `i16` holds no element, and
+        // erosion rightly drops the piece.
+        let at_limit = |b: &FiniteBound<i16>| {
+            b.bound_type() == BoundType::Open && matches!(*b.value(), i16::MIN | i16::MAX)
+        };
+        if x.iter()
+            .flat_map(|p| [p.left(), p.right()])
+            .flatten()
+            .any(at_limit)
+        {
+            return TestResult::discard();
+        }
+        let (Ok(open), Ok(close)) = (x.try_opening(&eps), x.try_closing(&eps)) else {
+            return TestResult::discard();
+        };
+        let inside = open.iter().all(|p| x.contains(p));
+        let outside = x.iter().all(|p| close.contains(p));
+        let idempotent = open.try_opening(&eps) == Ok(open.clone())
+            && close.try_closing(&eps) == Ok(close.clone());
+        TestResult::from_bool(inside && outside && idempotent && open.clone().union(x.clone()) == x)
+    }
+}
@@ -0,0 +1,145 @@
+//! Set-level [`Nearest`] trait impls for [`Interval`] and
+//! [`IntervalSet`].
+//!
+//! Answers are [`FiniteBound`]s: closed for a member, open for a limit
+//! that is not attained (continuous `T` only). Queries on an
+//! [`IntervalSet`] binary-search its pieces, so they are O(log n).
+
+use intervalsets_core::bound::FiniteBound;
+pub use intervalsets_core::ops::Nearest;
+use intervalsets_core::ops::{nearest_sorted, next_sorted, prev_sorted};
+
+use crate::numeric::Element;
+use crate::{Interval, IntervalSet};
+
+impl<T> Nearest<T> for Interval<T>
+where
+    T: Element + Clone,
+    T::Measure: PartialOrd,
+{
+    fn nearest(&self, x: &T) -> Option<FiniteBound<T>> {
+        self.0.nearest(x)
+    }
+
+    fn next_member_at_or_after(&self, x: &T) -> Option<FiniteBound<T>> {
+        self.0.next_member_at_or_after(x)
+    }
+
+    fn prev_member_at_or_before(&self, x: &T) -> Option<FiniteBound<T>> {
+        self.0.prev_member_at_or_before(x)
+    }
+}
+
+impl<T> Nearest<T> for IntervalSet<T>
+where
+    T: Element + Clone,
+    T::Measure: PartialOrd,
+{
+    fn nearest(&self, x: &T) -> Option<FiniteBound<T>> {
+        nearest_sorted(self.slice(), x)
+    }
+
+    fn next_member_at_or_after(&self, x: &T) -> Option<FiniteBound<T>> {
+        next_sorted(self.slice(), x)
+    }
+
+    fn prev_member_at_or_before(&self, x: &T) -> Option<FiniteBound<T>> {
+        prev_sorted(self.slice(), x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::TestResult;
+
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::Contains;
+    use crate::SetBounds;
+
+    #[test]
+    fn allowed_ranges_with_holes() {
+        let allowed = IntervalSet::new([
+            Interval::closed(0, 10),
+            Interval::closed(20, 30),
+            Interval::closed(50, 60),
+        ]);
+        assert_eq!(allowed.clamp_into(5), Some(5));
+        assert_eq!(allowed.clamp_into(-7), Some(0));
+        assert_eq!(allowed.clamp_into(14), Some(10));
+        assert_eq!(allowed.clamp_into(16), Some(20));
+        assert_eq!(allowed.clamp_into(45), Some(50));
+        assert_eq!(allowed.clamp_into(99), Some(60));
+
+        assert_eq!(
+            allowed.next_member_at_or_after(&31),
+            Some(FiniteBound::closed(50))
+        );
+        assert_eq!(
+            allowed.prev_member_at_or_before(&31),
+            Some(FiniteBound::closed(30))
+        );
+        assert_eq!(allowed.next_member_at_or_after(&61), None);
+        assert_eq!(IntervalSet::<i32>::empty().nearest(&0), None);
+    }
+
+    #[test]
+    fn continuous_limits() {
+        let allowed =
+            IntervalSet::new([Interval::closed_open(0.0, 1.0), Interval::open_unbound(2.0)]);
+        assert_eq!(allowed.nearest(&1.4), Some(FiniteBound::open(1.0)));
+        assert_eq!(allowed.nearest(&1.6), Some(FiniteBound::open(2.0)));
+        assert_eq!(allowed.nearest(&1e9), Some(FiniteBound::closed(1e9)));
+        assert_eq!(allowed.prev_member_at_or_before(&-1.0), None);
+        assert_eq!(
+            Interval::open(0.0, 1.0).next_member_at_or_after(&-1.0),
+            Some(FiniteBound::open(0.0))
+        );
+    }
+
+    #[test]
+    fn memberless_limit_pieces() {
+        let above = IntervalSet::from(Interval::open_unbound(i8::MAX));
+        assert_eq!(above.nearest(&0), Some(FiniteBound::open(i8::MAX)));
+        assert_eq!(
+            above.next_member_at_or_after(&0),
+            Some(FiniteBound::open(i8::MAX))
+        );
+
+        let below = IntervalSet::from(Interval::unbound_open(i8::MIN));
+        assert_eq!(below.nearest(&0), Some(FiniteBound::open(i8::MIN)));
+        assert_eq!(
+            below.prev_member_at_or_before(&0),
+            Some(FiniteBound::open(i8::MIN))
+        );
+    }
+
+    #[quickcheck]
+    fn check_discrete_nearest(set: IntervalSet<i8>, x: i8) -> TestResult {
+        // `(i8::MAX, ..)` and `(.., i8::MIN)` have no members and answer
+        // with their open bound; see `memberless_limit_pieces`.
+        if set
+            .iter()
+            .flat_map(|p| [p.left(), p.right()])
+            .flatten()
+            .any(|b| b.is_open() && matches!(*b.value(), i8::MIN | i8::MAX))
+        {
+            return TestResult::discard();
+        }
+        let Some(n) = set.nearest(&x) else {
+            return TestResult::from_bool(set.slice().is_empty());
+        };
+        let d = |y: i8| (i16::from(y) - i16::from(x)).abs();
+        let best = (i8::MIN..=i8::MAX)
+            .filter(|y| set.contains(y))
+            .min_by_key(|&y| (d(y), y))
+            .unwrap();
+        let next = (x..=i8::MAX).find(|y| set.contains(y));
+        let prev = (i8::MIN..=x).rev().find(|y| set.contains(y));
+        TestResult::from_bool(
+            n == FiniteBound::closed(best)
+                && set.next_member_at_or_after(&x) == next.map(FiniteBound::closed)
+                && set.prev_member_at_or_before(&x) == prev.map(FiniteBound::closed),
+        )
+    }
+}
@@ -0,0 +1,150 @@
+//! [`PartitionEqual`] impls for the outer crate's [`Interval`] and
+//! [`IntervalSet`]. The cut search lives in
+//! [`intervalsets_core::ops::cut_core`].
+
+use core::convert::Infallible;
+
+use intervalsets_core::error::MathError;
+use intervalsets_core::ops::math::{TryAdd, TryDiv, TryMul, TrySub};
+pub use intervalsets_core::ops::{EqualParts, PartitionEqual};
+
+use crate::bound::SetBounds;
+use crate::numeric::{Element, Midpointable, NumCast};
+use crate::{Interval, IntervalSet};
+
+fn finite_bounds<T: Clone, S: SetBounds<T>>(s: &S) -> Option<(T, T)> {
+    Some((s.lval()?.clone(), s.rval()?.clone()))
+}
+
+impl<T> PartitionEqual<T> for Interval<T>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd
+        + NumCast
+        + TryMul<Output = T::Measure>
+        + TryDiv<Output = T::Measure>
+        + TrySub<Output = T::Measure>,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+    <T::Measure as TryMul>::Error: Into<MathError>,
+    <T::Measure as TryDiv>::Error: Into<MathError>,
+    <T::Measure as TrySub>::Error: Into<MathError>,
+{
+    fn partition_equal(&self, n: usize) -> Result<EqualParts<'static, T, Self>, MathError> {
+        EqualParts::try_equal(self.clone(), finite_bounds(self), n)
+    }
+
+    fn partition_weighted<'w>(
+        &self,
+        weights: &'w [T::Measure],
+    ) -> Result<EqualParts<'w, T, Self>, MathError> {
+        EqualParts::try_weighted(self.clone(), finite_bounds(self), weights)
+    }
+}
+
+impl<T> PartitionEqual<T> for IntervalSet<T>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd
+        + NumCast
+        + TryMul<Output = T::Measure>
+        + TryDiv<Output = T::Measure>
+        + TrySub<Output = T::Measure>,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+    <T::Measure as TryMul>::Error: Into<MathError>,
+    <T::Measure as TryDiv>::Error: Into<MathError>,
+    <T::Measure as TrySub>::Error: Into<MathError>,
+{
+    fn partition_equal(&self, n: usize) -> Result<EqualParts<'static, T, Self>, MathError> {
+        EqualParts::try_equal(self.clone(), finite_bounds(&self.hull()), n)
+    }
+
+    fn partition_weighted<'w>(
+        &self,
+        weights: &'w [T::Measure],
+    ) -> Result<EqualParts<'w, T, Self>, MathError> {
+        EqualParts::try_weighted(self.clone(), finite_bounds(&self.hull()), weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::TestResult;
+
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::measure::{Extent, Measure};
+    use crate::ops::Union;
+
+    #[test]
+    fn interval_shards() {
+        let x = Interval::closed(0_u32, 999);
+        let parts: Vec<_> = x.partition_equal(3).unwrap().collect();
+        assert_eq!(
+            parts,
+            vec![
+                Interval::closed(0, 332),
+                Interval::closed(333, 665),
+                Interval::closed(666, 999),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_parts_span_gaps() {
+        let x = Interval::closed(0_i32, 9).union(Interval::closed(20, 29));
+        let parts: Vec<_> = x.partition_equal(4).unwrap().collect();
+        assert_eq!(
+            parts,
+            vec![
+                Interval::closed(0, 4).into(),
+                Interval::closed(5, 9).into(),
+                Interval::closed(20, 24).into(),
+                Interval::closed(25, 29).into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn time_window_weights() {
+        let x = Interval::closed_open(0.0_f64, 60.0);
+        let parts: Vec<_> = x.partition_weighted(&[1.0, 2.0, 3.0]).unwrap().collect();
+        assert_eq!(parts.len(), 3);
+        for (p, want) in parts.iter().zip([10.0, 20.0, 30.0]) {
+            assert!((p.measure().finite() - want).abs() < 1e-12, "{p:?}");
+        }
+        assert_eq!(parts[2].rval(), Some(&60.0));
+        assert!(parts[2].right().unwrap().is_open());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            IntervalSet::<i32>::empty().partition_equal(2).err(),
+            Some(MathError::Domain)
+        );
+        let x = Interval::closed(0, 9).union(Interval::closed_unbound(20));
+        assert_eq!(x.partition_equal(2).err(), Some(MathError::Domain));
+    }
+
+    #[quickcheck]
+    fn check_discrete_balance(set: IntervalSet<i16>, n: u8) -> TestResult {
+        let n = 1 + (n as usize) % 16;
+        let Ok(Extent::Finite(total)) = set.try_measure() else {
+            return TestResult::discard();
+        };
+        let Ok(parts) = set.partition_equal(n) else {
+            return TestResult::discard();
+        };
+        let parts: Vec<_> = parts.collect();
+        let sizes: Vec<u32> = parts.iter().map(|p| p.measure().finite()).collect();
+        let (lo, hi) = (sizes.iter().min().unwrap(), sizes.iter().max().unwrap());
+
+        let mut union = IntervalSet::empty();
+        for p in parts.iter().cloned() {
+            union = union.union(p);
+        }
+        TestResult::from_bool(
+            parts.len() == n && sizes.iter().sum::<u32>() == total && hi - lo <= 1 && union == set,
+        )
+    }
+}
@@ -0,0 +1,393 @@
+//! Measure quantiles and ranks of a set — the general form of
+//! [`Bisect`](super::Bisect).
+//!
+//! For a set `S` with natural measure `m` (cardinality on discrete
+//! `T`, width on continuous `T`):
+//!
+//! ```text
+//! rank(x)       = m(S ∩ (-∞, x])
+//! cdf(x)        = rank(x) / m(S)
+//! quantile_at(t) = min { x : rank(x) >= t }
+//! quantile(q)   = quantile_at(q · m(S))
+//! ```
+//!
+//! `quantile(0.5)` agrees with the bisection midpoint up to the
+//! element type's resolution. The search follows `bisect_core`: the
+//! bracket is narrowed with [`Midpointable`] and each probe is measured
+//! through [`Split`] and [`Measure`], so discrete types get the exact
+//! answer and floats stop within one ulp.
+
+use core::convert::Infallible;
+
+use intervalsets_core::measure::{Extent, Measure};
+use intervalsets_core::ops::math::TryAdd;
+use num_traits::ToPrimitive;
+
+use super::Split;
+use crate::bound::{SetBounds, Side};
+use crate::error::{Error, MathError};
+use crate::numeric::{Element, Midpointable, Zero};
+use crate::{Interval, IntervalSet, MaybeEmpty};
+
+/// Ranks and quantiles of a set by its natural measure.
+///
+/// See the [module docs](self) for definitions.
+///
+/// # Contract
+///
+/// - `rank` is monotone non-decreasing in `x`, `0` left of the set
+///   and `m(S)` right of it.
+/// - `quantile_at(t)` is the smallest `x` with `rank(x) >= t`. For
+///   discrete `T` it is always a member of `S` and exact. For
+///   continuous `T` it is within one ulp of the true value and may
+///   be an open endpoint of a piece (the infimum is not attained).
+/// - `quantile_at(0)` and `quantile(0.0)` return `inf(S)`.
+/// - `quantile` and `cdf` convert measures through `f64`, so they are
+///   exact only while `m(S)` is exactly representable there
+///   (counts below `2^53` for discrete `T`).
+///
+/// # Tier
+///
+/// Tier 3a only, as with [`Midpoint`](super::Midpoint): empty and
+/// unbounded inputs are routine outcomes, not exceptional ones.
+pub trait Quantile<T> {
+    /// Measure type of the set, `T::Measure` for the library impls.
+    type Measure;
+
+    /// `m(S ∩ (-∞, x])`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Math(MathError::Domain)` if that measure is infinite
+    ///   (`S` is unbounded below and `x` is not left of it).
+    /// - `Error::InvalidElement` if `x` is incomparable (e.g. NaN).
+    /// - Measure overflow as its `MathError`.
+    fn rank(&self, x: &T) -> Result<Self::Measure, Error>;
+
+    /// `rank(x) / m(S)` in `[0, 1]`.
+    ///
+    /// # Errors
+    ///
+    /// As [`rank`](Self::rank), plus `Error::Math(MathError::Domain)`
+    /// if `S` is empty, has infinite measure, or has measure zero.
+    fn cdf(&self, x: &T) -> Result<f64, Error>;
+
+    /// Smallest `x` with `rank(x) >= target`.
+    ///
+    /// # Errors
+    ///
+    /// `Error::Math(MathError::Domain)` if `S` is empty or not fully
+    /// bounded, or if `target` is negative or exceeds `m(S)`.
+    fn quantile_at(&self, target: Self::Measure) -> Result<T, Error>;
+
+    /// Smallest `x` with `cdf(x) >= q`.
+    ///
+    /// ```
+    /// use intervalsets::prelude::*;
+    /// use intervalsets::ops::Quantile;
+    ///
+    /// let x = IntervalSet::new([Interval::closed(0, 9), Interval::closed(20, 29)]);
+    /// assert_eq!(x.quantile(0.25), Ok(4));
+    /// assert_eq!(x.quantile(0.75), Ok(24));
+    /// assert_eq!(x.rank(&24), Ok(15_u64));
+    /// assert_eq!(x.cdf(&15), Ok(0.5));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// `Error::Math(MathError::Domain)` if `q` is outside `[0, 1]`
+    /// (or NaN), or `S` is empty or not fully bounded.
+    fn quantile(&self, q: f64) -> Result<T, Error>;
+}
+
+impl<T> Quantile<T> for Interval<T>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd + ToPrimitive,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+{
+    type Measure = T::Measure;
+
+    fn rank(&self, x: &T) -> Result<T::Measure, Error> {
+        finite(
+            self.clone()
+                .try_split(x.clone(), Side::Left)?
+                .0
+                .try_measure()?,
+        )
+    }
+
+    fn cdf(&self, x: &T) -> Result<f64, Error> {
+        cdf(self.rank(x)?, finite(self.try_measure()?)?)
+    }
+
+    fn quantile_at(&self, target: T::Measure) -> Result<T, Error> {
+        non_negative(&target)?;
+        search(core::slice::from_ref(self), |r| *r >= target)
+    }
+
+    fn quantile(&self, q: f64) -> Result<T, Error> {
+        let total = fraction(q, finite(self.try_measure()?)?)?;
+        search(core::slice::from_ref(self), |r| to_f64(r) >= total)
+    }
+}
+
+impl<T> Quantile<T> for IntervalSet<T>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd + ToPrimitive,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+{
+    type Measure = T::Measure;
+
+    fn rank(&self, x: &T) -> Result<T::Measure, Error> {
+        finite(
+            self.clone()
+                .try_split(x.clone(), Side::Left)?
+                .0
+                .try_measure()?,
+        )
+    }
+
+    fn cdf(&self, x: &T) -> Result<f64, Error> {
+        cdf(self.rank(x)?, finite(self.try_measure()?)?)
+    }
+
+    fn quantile_at(&self, target: T::Measure) -> Result<T, Error> {
+        non_negative(&target)?;
+        search(self.slice(), |r| *r >= target)
+    }
+
+    fn quantile(&self, q: f64) -> Result<T, Error> {
+        let total = fraction(q, finite(self.try_measure()?)?)?;
+        search(self.slice(), |r| to_f64(r) >= total)
+    }
+}
+
+fn finite<M>(m: Extent<M>) -> Result<M, Error> {
+    match m {
+        Extent::Finite(m) => Ok(m),
+        Extent::Infinite => Err(Error::Math(MathError::Domain)),
+    }
+}
+
+fn non_negative<M: PartialOrd + Zero>(target: &M) -> Result<(), Error> {
+    if *target < M::zero() {
+        return Err(Error::Math(MathError::Domain));
+    }
+    Ok(())
+}
+
+fn to_f64<M: ToPrimitive>(m: &M) -> f64 {
+    // Library measures (unsigned ints, floats, Decimal) always convert.
+    m.to_f64().unwrap_or(f64::NAN)
+}
+
+fn cdf<M: ToPrimitive>(rank: M, total: M) -> Result<f64, Error> {
+    let total = to_f64(&total);
+    if total.is_nan() || total <= 0.0 {
+        return Err(Error::Math(MathError::Domain));
+    }
+    // `rank <= total`, but clamp in case the two conversions round apart.
+    Ok((to_f64(&rank) / total).min(1.0))
+}
+
+/// `q · total` as `f64`, checking `q ∈ [0, 1]`.
+fn fraction<M: ToPrimitive>(q: f64, total: M) -> Result<f64, Error> {
+    if !(0.0..=1.0).contains(&q) {
+        return Err(Error::Math(MathError::Domain));
+    }
+    Ok(q * to_f64(&total))
+}
+
+/// Smallest `x` in the hull of `pieces` whose rank satisfies the
+/// monotone predicate `reached`.
+///
+/// Walks the pieces accumulating measure to find the piece where
+/// `reached` first holds, then narrows inside it by midpoint search.
+fn search<T, F>(pieces: &[Interval<T>], reached: F) -> Result<T, Error>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    T::Measure: PartialOrd,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+    F: Fn(&T::Measure) -> bool,
+{
+    let (Some(first), Some(last)) = (pieces.first(), pieces.last()) else {
+        return Err(Error::Math(MathError::Domain));
+    };
+    if first.is_empty() || !first.is_fully_bounded() || !last.is_fully_bounded() {
+        return Err(Error::Math(MathError::Domain));
+    }
+    let zero = <T::Measure as Zero>::zero();
+    if reached(&zero) {
+        return Ok(first.lval().expect("bounded").clone());
+    }
+
+    let mut before = zero;
+    for piece in pieces {
+        let m = finite(piece.try_measure()?)?;
+        let after = add(before.clone(), m)?;
+        if reached(&after) {
+            let rank = |x: &T| -> Result<T::Measure, Error> {
+                let (left, _) = piece.clone().try_split(x.clone(), Side::Left)?;
+                add(before.clone(), finite(left.try_measure()?)?)
+            };
+            return narrow(piece, rank, &reached);
+        }
+        before = after;
+    }
+    Err(Error::Math(MathError::Domain))
+}
+
+/// Midpoint search within one piece, whose right end is known to
+/// satisfy `reached`. Invariant: `reached(rank(hi))` and, once the
+/// left end is ruled out, `!reached(rank(lo))`.
+fn narrow<T, R, F>(piece: &Interval<T>, rank: R, reached: &F) -> Result<T, Error>
+where
+    T: Element + Clone + Midpointable<Error = Infallible>,
+    R: Fn(&T) -> Result<T::Measure, Error>,
+    F: Fn(&T::Measure) -> bool,
+{
+    let mut lo = piece.lval().expect("bounded").clone();
+    let mut hi = piece.rval().expect("bounded").clone();
+    if reached(&rank(&lo)?) {
+        return Ok(lo);
+    }
+    loop {
+        let m = T::midpoint(lo.clone(), hi.clone()).unwrap();
+        // Adjacent values (one unit or one ulp apart): `hi` is the answer.
+        if m == lo || m == hi {
+            return Ok(hi);
+        }
+        if reached(&rank(&m)?) {
+            hi = m;
+        } else {
+            lo = m;
+        }
+    }
+}
+
+fn add<M>(a: M, b: M) -> Result<M, Error>
+where
+    M: TryAdd<Output = M>,
+    M::Error: Into<MathError>,
+{
+    a.try_add(b).map_err(|e| Error::Math(e.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::{Bisect, Contains, Union};
+
+    #[test]
+    fn integer_quantiles_are_exact() {
+        let x = Interval::closed(1_i32, 100);
+        assert_eq!(x.quantile_at(1u64), Ok(1));
+        assert_eq!(x.quantile_at(37u64), Ok(37));
+        assert_eq!(x.quantile(0.5), Ok(50));
+        assert_eq!(x.quantile(0.505), Ok(51));
+        assert_eq!(x.quantile(1.0), Ok(100));
+        assert_eq!(x.quantile(0.0), Ok(1));
+
+        let neg = Interval::closed(-10_i32, -1);
+        assert_eq!(neg.quantile_at(3u64), Ok(-8));
+    }
+
+    #[test]
+    fn integer_set_skips_gaps() {
+        let x = Interval::closed(0_i32, 9).union(Interval::closed(20, 29));
+        assert_eq!(x.quantile_at(10u64), Ok(9));
+        assert_eq!(x.quantile_at(11u64), Ok(20));
+        assert_eq!(x.rank(&15), Ok(10u64));
+        assert_eq!(x.rank(&-5), Ok(0u64));
+        assert_eq!(x.rank(&100), Ok(20u64));
+        for t in 1..=20u64 {
+            let q = x.quantile_at(t).unwrap();
+            assert_eq!(x.rank(&q), Ok(t));
+        }
+    }
+
+    #[test]
+    fn float_quantiles_within_ulp() {
+        let x = Interval::closed(0.0, 10.0).union(Interval::closed(20.0, 30.0));
+        let q: f64 = x.quantile(0.75).unwrap();
+        assert!((q - 25.0).abs() <= 25.0f64.next_up() - 25.0, "{q}");
+        assert_eq!(x.cdf(&25.0), Ok(0.75));
+        assert_eq!(x.cdf(&15.0), Ok(0.5));
+
+        // The median is the left edge of the gap; any point of the gap
+        // (e.g. the bisection midpoint) has the same rank.
+        assert_eq!(x.quantile(0.5), Ok(10.0));
+        let b = x.bisect(Side::Left).unwrap();
+        assert_eq!(x.rank(&b.midpoint), Ok(10.0));
+    }
+
+    #[test]
+    fn float_open_endpoints() {
+        let x = Interval::closed_open(0.0, 1.0);
+        assert_eq!(x.quantile(1.0), Ok(1.0));
+        let y = Interval::open(2.0, 4.0);
+        assert_eq!(y.quantile(0.0), Ok(2.0));
+        let q: f64 = y.quantile(0.25).unwrap();
+        assert!((q - 2.5).abs() < 1e-15);
+    }
+
+    #[test]
+    fn zero_measure_pieces() {
+        let x = IntervalSet::new([Interval::singleton(0.0), Interval::closed(1.0, 2.0)]);
+        assert_eq!(x.quantile(0.0), Ok(0.0));
+        assert_eq!(x.quantile(1.0), Ok(2.0));
+        assert_eq!(x.cdf(&0.5), Ok(0.0));
+        assert_eq!(
+            Interval::singleton(3.0).cdf(&3.0),
+            Err(Error::Math(MathError::Domain))
+        );
+    }
+
+    #[test]
+    fn domain_errors() {
+        let domain = Error::Math(MathError::Domain);
+        assert_eq!(Interval::<i32>::empty().quantile(0.5), Err(domain.clone()));
+        assert_eq!(
+            Interval::closed_unbound(0).quantile(0.5),
+            Err(domain.clone())
+        );
+        assert_eq!(Interval::closed(0, 9).quantile(1.5), Err(domain.clone()));
+        assert_eq!(
+            Interval::closed(0.0, 9.0).quantile(f64::NAN),
+            Err(domain.clone())
+        );
+        assert_eq!(
+            Interval::closed(0, 9).quantile_at(11u64),
+            Err(domain.clone())
+        );
+        assert_eq!(
+            Interval::closed(0.0, 9.0).quantile_at(-1.0),
+            Err(domain.clone())
+        );
+        assert_eq!(IntervalSet::<i32>::empty().cdf(&0), Err(domain.clone()));
+        assert_eq!(Interval::unbound_closed(0).rank(&-5), Err(domain));
+
+        // Unbounded above is fine for rank.
+        assert_eq!(Interval::closed_unbound(0).rank(&4), Ok(5u64));
+        assert_eq!(
+            Interval::closed(0.0, 1.0).rank(&f64::NAN),
+            Err(Error::InvalidElement)
+        );
+    }
+
+    #[quickcheck]
+    fn check_quantile_inverts_rank(set: IntervalSet<i16>, t: u16) -> bool {
+        let Ok(Extent::Finite(total)) = set.try_measure() else {
+            return true;
+        };
+        if total == 0 || !set.hull().is_fully_bounded() {
+            return true;
+        }
+        let t = 1 + (t as u32) % total;
+        let x = set.quantile_at(t).unwrap();
+        set.rank(&x) == Ok(t) && set.contains(&x)
+    }
+}
@@ -0,0 +1,273 @@
+//! Best approximations of an [`IntervalSet`] under a piece budget:
+//! [`Simplify`].
+//!
+//! Given a set of `n` pieces and a budget `k`:
+//!
+//! - The **outer** approximation is the superset with at most `k`
+//!   pieces that adds the least [`Measure`]. It fills the `n - k`
+//!   smallest gaps.
+//! - The **inner** approximation is the subset with at most `k` pieces
+//!   that removes the least measure. It drops the `n - k` smallest
+//!   pieces.
+//!
+//! Both are optimal: every other superset (subset) with at most `k`
+//! pieces adds (removes) at least as much. Ties go to the leftmost gap
+//! or piece. Gaps and pieces are compared by their own measure, so on
+//! discrete `T` the error is an element count.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::measure::Extent;
+//! use intervalsets::ops::Simplify;
+//!
+//! let x = IntervalSet::new([
+//!     Interval::closed(0i32, 9),
+//!     Interval::closed(12, 19),
+//!     Interval::closed(30, 30),
+//!     Interval::closed(35, 49),
+//! ]);
+//! let approx = x.simplify(2);
+//! assert_eq!(
+//!     approx.outer,
+//!     IntervalSet::new([Interval::closed(0, 19), Interval::closed(30, 49)])
+//! );
+//! assert_eq!(approx.outer_error, 2 + 4);
+//! assert_eq!(
+//!     approx.inner,
+//!     IntervalSet::new([Interval::closed(0, 9), Interval::closed(35, 49)])
+//! );
+//! assert_eq!(approx.inner_error, Extent::Finite(8 + 1));
+//! ```
+
+use intervalsets_core::measure::{Extent, Measure};
+use intervalsets_core::ops::math::TryAdd;
+
+use crate::error::{Error, MathError};
+use crate::factory::traits::*;
+use crate::numeric::{Element, Zero};
+use crate::ops::Rebound;
+use crate::{Interval, IntervalSet, SetBounds};
+
+/// Outer and inner approximations of a set; see [`Simplify`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simplified<T: Element> {
+    /// Smallest-measure superset with at most `k` pieces.
+    pub outer: IntervalSet<T>,
+    /// Measure added by `outer`: the sum of the filled gaps.
+    pub outer_error: T::Measure,
+    /// Largest-measure subset with at most `k` pieces.
+    pub inner: IntervalSet<T>,
+    /// Measure removed by `inner`: the sum of the dropped pieces.
+    /// `Infinite` if an unbounded piece had to go.
+    pub inner_error: Extent<T::Measure>,
+}
+
+/// Approximation of a set by at most `k` pieces.
+///
+/// See the [module docs](self) for definitions.
+///
+/// # Contract
+///
+/// Tier 3 (`try_*` + panicking sugar). The `try_*` form never panics.
+/// O(n log n) in the number of pieces.
+///
+/// # Errors
+///
+/// - `Error::Math(MathError::Domain)` if `k == 0` and the set is not
+///   empty, since no superset has zero pieces.
+/// - The error of [`Measure`] or of summing measures.
+pub trait Simplify<T: Element>: Sized {
+    /// The outer and inner approximations with at most `k` pieces.
+    fn try_simplify(&self, k: usize) -> Result<Simplified<T>, Error>;
+
+    /// Panicking form of [`try_simplify`](Self::try_simplify).
+    fn simplify(&self, k: usize) -> Simplified<T> {
+        self.try_simplify(k).unwrap()
+    }
+}
+
+fn sum<M>(parts: impl IntoIterator<Item = Extent<M>>) -> Result<Extent<M>, Error>
+where
+    M: Zero + TryAdd<Output = M>,
+    M::Error: Into<MathError>,
+{
+    parts
+        .into_iter()
+        .try_fold(Extent::Finite(M::zero()), |acc, m| {
+            acc.try_add(m).map_err(Into::into)
+        })
+        .map_err(Error::Math)
+}
+
+/// Indices of the `count` smallest `measures`, in ascending index
+/// order. `Infinite` sorts above every finite measure.
+fn smallest<M: PartialOrd>(measures: &[Extent<M>], count: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..measures.len()).collect();
+    order.sort_by(|&a, &b| match (&measures[a], &measures[b]) {
+        (Extent::Finite(x), Extent::Finite(y)) => {
+            x.partial_cmp(y).unwrap_or(core::cmp::Ordering::Equal)
+        }
+        (Extent::Finite(_), Extent::Infinite) => core::cmp::Ordering::Less,
+        (Extent::Infinite, Extent::Finite(_)) => core::cmp::Ordering::Greater,
+        (Extent::Infinite, Extent::Infinite) => core::cmp::Ordering::Equal,
+    });
+    order.truncate(count);
+    order.sort_unstable();
+    order
+}
+
+impl<T> Simplify<T> for IntervalSet<T>
+where
+    T: Element + Clone + Zero,
+    T::Measure: PartialOrd + Clone,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+{
+    fn try_simplify(&self, k: usize) -> Result<Simplified<T>, Error> {
+        let pieces = self.slice();
+        if k == 0 && !pieces.is_empty() {
+            return Err(Error::Math(MathError::Domain));
+        }
+        let excess = pieces.len().saturating_sub(k);
+
+        // Neighbours never touch, so both facing bounds are finite and
+        // the gap between them is inhabited.
+        let gaps = pieces
+            .windows(2)
+            .map(|w| {
+                let gap = Interval::try_satisfy_bounds(
+                    w[0].right().expect("followed by a piece").clone().flip(),
+                    w[1].left().expect("preceded by a piece").clone().flip(),
+                )?;
+                Ok(gap.try_measure()?)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let filled = smallest(&gaps, excess);
+
+        let mut outer: Vec<Interval<T>> = Vec::with_capacity(pieces.len() - excess);
+        let mut next = filled.iter().peekable();
+        for (i, piece) in pieces.iter().enumerate() {
+            // Gap `i - 1` sits between pieces `i - 1` and `i`.
+            if i > 0 && next.next_if(|&&g| g == i - 1).is_some() {
+                let prev = outer.last_mut().expect("gap follows a piece");
+                *prev = prev.clone().try_with_right(piece.right().cloned())?;
+            } else {
+                outer.push(piece.clone());
+            }
+        }
+        let outer_error = match sum(filled.iter().map(|&g| gaps[g].clone()))? {
+            Extent::Finite(m) => m,
+            Extent::Infinite => unreachable!("gaps are bounded"),
+        };
+
+        let sizes = pieces
+            .iter()
+            .map(|p| Ok(p.try_measure()?))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let dropped = smallest(&sizes, excess);
+        let mut next = dropped.iter().peekable();
+        let inner: Vec<_> = pieces
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| next.next_if(|&&d| d == *i).is_none())
+            .map(|(_, p)| p.clone())
+            .collect();
+        let inner_error = sum(dropped.iter().map(|&d| sizes[d].clone()))?;
+
+        Ok(Simplified {
+            outer: IntervalSet::new_assume_valid(outer),
+            outer_error,
+            inner: IntervalSet::new_assume_valid(inner),
+            inner_error,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::TestResult;
+
+    use super::*;
+    use crate::ops::{Contains, Union};
+
+    #[test]
+    fn within_budget_is_identity() {
+        let x = IntervalSet::new([Interval::closed(0.0, 1.0), Interval::open(2.0, 3.0)]);
+        let s = x.simplify(2);
+        assert_eq!((s.outer, s.outer_error), (x.clone(), 0.0));
+        assert_eq!((s.inner, s.inner_error), (x, Extent::Finite(0.0)));
+
+        let empty = IntervalSet::<i32>::empty();
+        assert_eq!(empty.simplify(0).outer, empty);
+    }
+
+    #[test]
+    fn continuous_errors_are_widths() {
+        let x = IntervalSet::new([
+            Interval::closed_open(0.0, 1.0),
+            Interval::open(1.0, 2.0),
+            Interval::closed(5.0, 5.5),
+            Interval::closed(6.0, 9.0),
+        ]);
+        let s = x.simplify(2);
+        assert_eq!(
+            s.outer,
+            IntervalSet::new([Interval::closed_open(0.0, 2.0), Interval::closed(5.0, 9.0)])
+        );
+        assert_eq!(s.outer_error, 0.5);
+        assert_eq!(
+            s.inner,
+            IntervalSet::new([Interval::open(1.0, 2.0), Interval::closed(6.0, 9.0)])
+        );
+        assert_eq!(s.inner_error, Extent::Finite(1.5));
+    }
+
+    #[test]
+    fn unbounded_pieces_are_dropped_last() {
+        let x = IntervalSet::new([
+            Interval::unbound_closed(0i32),
+            Interval::closed(10, 20),
+            Interval::closed_unbound(30),
+        ]);
+        let s = x.simplify(2);
+        assert_eq!(
+            s.outer,
+            IntervalSet::new([Interval::unbound_closed(20), Interval::closed_unbound(30)])
+        );
+        assert_eq!(s.outer_error, 9);
+        assert_eq!(s.inner_error, Extent::Finite(11));
+
+        let s = x.simplify(1);
+        assert_eq!(s.outer, IntervalSet::from(Interval::unbounded()));
+        assert_eq!(s.inner, IntervalSet::from(Interval::closed_unbound(30)));
+        assert_eq!(s.inner_error, Extent::Infinite);
+    }
+
+    #[test]
+    fn zero_budget() {
+        let x = IntervalSet::from(Interval::closed(0, 1));
+        assert_eq!(x.try_simplify(0), Err(Error::Math(MathError::Domain)));
+    }
+
+    #[quickcheck]
+    fn check_bracket_and_budget(x: IntervalSet<i16>, k: u8) -> TestResult {
+        let k = 1 + usize::from(k % 8);
+        let Ok(s) = x.try_simplify(k) else {
+            return TestResult::discard();
+        };
+        let (Ok(Extent::Finite(m)), Ok(Extent::Finite(outer)), Ok(Extent::Finite(inner))) = (
+            x.try_measure(),
+            s.outer.try_measure(),
+            s.inner.try_measure(),
+        ) else {
+            return TestResult::discard();
+        };
+        TestResult::from_bool(
+            s.outer.slice().len() <= k
+                && s.inner.slice().len() <= k
+                && x.iter().all(|p| s.outer.contains(p))
+                && s.inner.clone().union(x.clone()) == x
+                && outer == m + s.outer_error
+                && s.inner_error == Extent::Finite(m - inner),
+        )
+    }
+}
@@ -0,0 +1,243 @@
+//! Topological views of continuous sets: [`Topology`].
+//!
+//! With the usual topology on the real line:
+//!
+//! - [`closure`](Topology::closure) closes every open finite bound.
+//!   Pieces that only missed a shared endpoint are merged, so the
+//!   closure of `[0, 1) ∪ (1, 2]` is `[0, 2]`.
+//! - [`interior`](Topology::interior) opens every finite bound.
+//!   Singletons vanish.
+//! - [`boundary`](Topology::boundary) is the set of finite endpoints,
+//!   i.e. `closure \ interior`, as singletons.
+//! - [`isolated_points`](Topology::isolated_points) are the members
+//!   with no other member nearby: the singleton pieces.
+//!
+//! Unbounded sides have no endpoint, so `(-∞, ∞)` and the empty set
+//! are both open and closed.
+//!
+//! The trait is only implemented for [`ContinuousElement`]s. On a
+//! discrete type every set is both open and closed, and calling these
+//! methods is a compile error, as calling
+//! [`elements`](crate::Interval::elements) is on a continuous one.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::ops::Topology;
+//!
+//! let x = IntervalSet::new([
+//!     Interval::closed_open(0.0, 1.0),
+//!     Interval::open(1.0, 2.0),
+//!     Interval::singleton(5.0),
+//! ]);
+//! assert_eq!(
+//!     x.closure(),
+//!     IntervalSet::new([Interval::closed(0.0, 2.0), Interval::singleton(5.0)])
+//! );
+//! assert_eq!(
+//!     x.interior(),
+//!     IntervalSet::new([Interval::open(0.0, 1.0), Interval::open(1.0, 2.0)])
+//! );
+//! assert_eq!(x.boundary().slice().len(), 4);
+//! assert_eq!(x.isolated_points(), IntervalSet::from(Interval::singleton(5.0)));
+//! assert!(!x.is_open_set() && !x.is_closed_set());
+//! ```
+//!
+//! ```compile_fail
+//! use intervalsets::prelude::*;
+//! use intervalsets::ops::Topology;
+//!
+//! let _ = Interval::closed(0, 10).closure();
+//! ```
+
+use intervalsets_core::ops::MergeSortedByValue;
+
+use crate::bound::{BoundType, FiniteBound};
+use crate::factory::traits::*;
+use crate::numeric::{ContinuousElement, Zero};
+use crate::ops::Rebound;
+use crate::{Interval, IntervalSet, MaybeEmpty, SetBounds};
+
+/// Closure, interior, boundary and isolated points of a continuous set.
+///
+/// See the [module docs](self) for definitions.
+pub trait Topology<T: ContinuousElement> {
+    /// The type of the closure and interior.
+    type Output;
+
+    /// The smallest closed set containing `self`.
+    fn closure(&self) -> Self::Output;
+
+    /// The largest open set contained in `self`.
+    fn interior(&self) -> Self::Output;
+
+    /// The finite endpoints of `self`, as singletons.
+    fn boundary(&self) -> IntervalSet<T>;
+
+    /// The members of `self` that are not limits of other members.
+    fn isolated_points(&self) -> IntervalSet<T>;
+
+    /// `true` if every finite bound is open.
+    fn is_open_set(&self) -> bool;
+
+    /// `true` if every finite bound is closed.
+    fn is_closed_set(&self) -> bool;
+}
+
+/// Replaces the bound type of a finite bound, keeping unbounded sides.
+fn with_type<T: Clone>(bound: Option<&FiniteBound<T>>, kind: BoundType) -> Option<FiniteBound<T>> {
+    bound
+        .cloned()
+        .map(|b| if b.bound_type() == kind { b } else { b.flip() })
+}
+
+fn all_bounds<T>(x: &Interval<T>, kind: BoundType) -> bool {
+    [x.left(), x.right()]
+        .into_iter()
+        .flatten()
+        .all(|b| b.bound_type() == kind)
+}
+
+fn is_singleton<T: PartialEq>(x: &Interval<T>) -> bool {
+    matches!((x.lval(), x.rval()), (Some(l), Some(r)) if l == r)
+}
+
+impl<T: ContinuousElement + Clone + Zero> Topology<T> for Interval<T> {
+    type Output = Self;
+
+    fn closure(&self) -> Self {
+        self.clone()
+            .with_left(with_type(self.left(), BoundType::Closed))
+            .with_right(with_type(self.right(), BoundType::Closed))
+    }
+
+    fn interior(&self) -> Self {
+        self.clone()
+            .with_left(with_type(self.left(), BoundType::Open))
+            .with_right(with_type(self.right(), BoundType::Open))
+    }
+
+    fn boundary(&self) -> IntervalSet<T> {
+        let ends = [self.lval(), self.rval()].into_iter().flatten();
+        // A singleton's endpoints coincide; `new` merges them.
+        IntervalSet::new(ends.map(|v| Interval::singleton(v.clone())))
+    }
+
+    fn isolated_points(&self) -> IntervalSet<T> {
+        if is_singleton(self) {
+            self.clone().into()
+        } else {
+            IntervalSet::empty()
+        }
+    }
+
+    fn is_open_set(&self) -> bool {
+        all_bounds(self, BoundType::Open)
+    }
+
+    fn is_closed_set(&self) -> bool {
+        all_bounds(self, BoundType::Closed)
+    }
+}
+
+impl<T: ContinuousElement + Clone + Zero> Topology<T> for IntervalSet<T> {
+    type Output = Self;
+
+    fn closure(&self) -> Self {
+        // Closing keeps the pieces sorted by left bound; only
+        // neighbours that shared an excluded endpoint now overlap.
+        let closed: Vec<_> = self.iter().map(Topology::closure).collect();
+        Self::new_assume_valid(MergeSortedByValue::new(closed))
+    }
+
+    fn interior(&self) -> Self {
+        let open = self
+            .iter()
+            .map(Topology::interior)
+            .filter(MaybeEmpty::is_inhabited);
+        Self::new_assume_valid(open)
+    }
+
+    fn boundary(&self) -> IntervalSet<T> {
+        let mut ends: Vec<Interval<T>> = Vec::with_capacity(2 * self.slice().len());
+        for v in self.iter().flat_map(|p| [p.lval(), p.rval()]).flatten() {
+            // Ends arrive sorted; neighbours may share one.
+            if ends.last().and_then(|e| e.lval()) != Some(v) {
+                ends.push(Interval::singleton(v.clone()));
+            }
+        }
+        Self::new_assume_valid(ends)
+    }
+
+    fn isolated_points(&self) -> IntervalSet<T> {
+        // Pieces never touch, so a singleton piece is isolated.
+        Self::new_assume_valid(self.iter().filter(|p| is_singleton(p)).cloned())
+    }
+
+    fn is_open_set(&self) -> bool {
+        self.iter().all(Topology::is_open_set)
+    }
+
+    fn is_closed_set(&self) -> bool {
+        self.iter().all(Topology::is_closed_set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::{Contains, Difference};
+
+    #[test]
+    fn interval_views() {
+        let x = Interval::closed_open(0.0, 1.0);
+        assert_eq!(x.closure(), Interval::closed(0.0, 1.0));
+        assert_eq!(x.interior(), Interval::open(0.0, 1.0));
+        assert_eq!(
+            x.boundary(),
+            IntervalSet::new([Interval::singleton(0.0), Interval::singleton(1.0)])
+        );
+        assert!(x.isolated_points().is_empty());
+        assert!(!x.is_open_set() && !x.is_closed_set());
+
+        let p = Interval::singleton(2.0);
+        assert_eq!(p.closure(), p);
+        assert_eq!(p.interior(), Interval::empty());
+        assert_eq!(p.boundary(), IntervalSet::from(p));
+        assert_eq!(p.isolated_points(), IntervalSet::from(p));
+        assert!(p.is_closed_set());
+    }
+
+    #[test]
+    fn unbounded_and_empty_are_clopen() {
+        for x in [Interval::<f64>::unbounded(), Interval::empty()] {
+            assert!(x.is_open_set() && x.is_closed_set());
+            assert_eq!(x.closure(), x);
+            assert_eq!(x.interior(), x);
+            assert!(x.boundary().is_empty());
+        }
+        let x = Interval::open_unbound(3.0);
+        assert_eq!(x.closure(), Interval::closed_unbound(3.0));
+        assert_eq!(x.boundary(), IntervalSet::from(Interval::singleton(3.0)));
+    }
+
+    #[test]
+    fn set_closure_merges_and_boundary_dedups() {
+        let x = IntervalSet::new([
+            Interval::unbound_open(-1.0),
+            Interval::open(-1.0, 0.0),
+            Interval::closed(1.0, 2.0),
+        ]);
+        assert_eq!(
+            x.closure(),
+            IntervalSet::new([Interval::unbound_closed(0.0), Interval::closed(1.0, 2.0)])
+        );
+        assert_eq!(
+            x.boundary(),
+            IntervalSet::new([-1.0, 0.0, 1.0, 2.0].map(Interval::singleton))
+        );
+        assert_eq!(x.boundary(), x.closure().difference(x.interior()));
+        assert!(x.interior().is_open_set());
+        assert!(x.closure().is_closed_set());
+        assert!(x.closure().contains(&x));
+    }
+}
@@ -1,4 +1,4 @@
-//! `FromStr` for [`Interval`] and [`IntervalSet`].
+//! `FromStr` and [`FromNotation`] for [`Interval`] and [`IntervalSet`].
 //!
 //! [`Interval::from_str`](Interval) delegates to the inner
 //! [`EnumInterval`] parser. [`IntervalSet::from_str`](IntervalSet)
@@ -6,10 +6,24 @@
 //! interval, `{piece U piece U ...}` for multi-piece. Each piece is a
 //! valid interval per the [`EnumInterval`] grammar; `IntervalSet::new`
 //! handles sorting, merging, and dropping empty pieces.
+//!
+//! [`FromNotation`] parses the same types under a [`Notation`] that
+//! also accepts ISO 31-11 brackets, `∞`, Rust ranges and more; see
+//! [`Notation`] for the dialects.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::parse::{FromNotation, Notation};
+//!
+//! let x = IntervalSet::<f64>::from_notation("{]-∞, 0[ U [1; 2]}", &Notation::ALL).unwrap();
+//! assert_eq!(x, Interval::unbound_open(0.0).union(Interval::closed(1.0, 2.0)));
+//! ```
 
 use core::str::FromStr;
 
-use intervalsets_core::error::ParseIntervalError;
+use intervalsets_core::error::{Expected, ParseIntervalError, SyntaxError};
+use intervalsets_core::parse::{parse_piece, set_pieces};
+pub use intervalsets_core::parse::{FromNotation, Notation};
 use intervalsets_core::sets::EnumInterval;
 
 use crate::numeric::Element;
@@ -47,7 +61,18 @@ where
     type Err = ParseIntervalError<<T as FromStr>::Err>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        EnumInterval::<T>::from_str(s).map(Self::from)
+        Self::from_notation(s, &Notation::DISPLAY)
+    }
+}
+
+impl<T> FromNotation for Interval<T>
+where
+    T: Element + FromStr,
+{
+    type Err = ParseIntervalError<<T as FromStr>::Err>;
+
+    fn from_notation(s: &str, notation: &Notation) -> Result<Self, Self::Err> {
+        EnumInterval::<T>::from_notation(s, notation).map(Self::from)
     }
 }
 
@@ -96,60 +121,30 @@ where
     type Err = ParseIntervalError<<T as FromStr>::Err>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        if !s.starts_with('{') || !s.ends_with('}') {
-            return Err(ParseIntervalError::Syntax);
-        }
-        // Strip exactly one byte on each side (ASCII `{` and `}`).
-        let body = s[1..s.len() - 1].trim();
-        if body.is_empty() {
-            return Ok(IntervalSet::empty());
-        }
-        let segments = split_on_top_level_u(body)?;
-        let mut pieces = Vec::with_capacity(segments.len());
-        for seg in segments {
-            pieces.push(seg.trim().parse::<Interval<T>>()?);
-        }
-        Ok(IntervalSet::new(pieces))
+        Self::from_notation(s, &Notation::DISPLAY)
     }
 }
 
-/// Walk `body` char by char, tracking `[](){}` depth. At depth 0, split
-/// on a `U` that is flanked by ASCII whitespace on both sides. Reject
-/// empty segments (leading/trailing/double `U`).
-fn split_on_top_level_u<E>(body: &str) -> Result<Vec<&str>, ParseIntervalError<E>> {
-    let bytes = body.as_bytes();
-    let mut segments = Vec::new();
-    let mut depth: i32 = 0;
-    let mut seg_start: usize = 0;
-    let mut i: usize = 0;
-    while i < bytes.len() {
-        let b = bytes[i];
-        match b {
-            b'[' | b'(' | b'{' => depth += 1,
-            b']' | b')' | b'}' => depth -= 1,
-            b'U' if depth == 0 => {
-                let prev_ws = i > 0 && bytes[i - 1].is_ascii_whitespace();
-                let next_ws = i + 1 < bytes.len() && bytes[i + 1].is_ascii_whitespace();
-                if prev_ws && next_ws {
-                    let seg = body[seg_start..i].trim();
-                    if seg.is_empty() {
-                        return Err(ParseIntervalError::Syntax);
-                    }
-                    segments.push(seg);
-                    seg_start = i + 1;
-                }
-            }
-            _ => {}
-        }
-        i += 1;
-    }
-    let tail = body[seg_start..].trim();
-    if tail.is_empty() {
-        return Err(ParseIntervalError::Syntax);
+impl<T> FromNotation for IntervalSet<T>
+where
+    T: Element + FromStr,
+{
+    type Err = ParseIntervalError<<T as FromStr>::Err>;
+
+    fn from_notation(s: &str, notation: &Notation) -> Result<Self, Self::Err> {
+        let Some(pieces) = set_pieces(s, notation) else {
+            let start = s.len() - s.trim_start().len();
+            let span = start..start + s.trim().len();
+            return Err(ParseIntervalError::Syntax(SyntaxError::new(
+                Expected::SetBraces,
+                span,
+            )));
+        };
+        let pieces = pieces
+            .map(|(at, piece)| parse_piece::<Interval<T>, _>(at, piece, notation))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(IntervalSet::new(pieces))
     }
-    segments.push(tail);
-    Ok(segments)
 }
 
 #[cfg(test)]
@@ -256,37 +251,37 @@ mod tests {
     #[test]
     fn set_rejects_missing_braces() {
         let r: Result<IntervalSet<i32>, _> = "[0, 10]".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
 
         let r: Result<IntervalSet<i32>, _> = "{[0, 10]".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
 
         let r: Result<IntervalSet<i32>, _> = "[0, 10]}".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
     fn set_rejects_leading_separator() {
         let r: Result<IntervalSet<i32>, _> = "{ U [0, 10]}".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
     fn set_rejects_trailing_separator() {
         let r: Result<IntervalSet<i32>, _> = "{[0, 10] U }".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
     fn set_rejects_double_separator() {
         let r: Result<IntervalSet<i32>, _> = "{[0, 5] U  U [10, 15]}".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
     fn set_rejects_bad_piece() {
         let r: Result<IntervalSet<i32>, _> = "{[0, 5] U [10}".parse();
-        assert!(matches!(r, Err(ParseIntervalError::Syntax)));
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
     }
 
     #[test]
@@ -294,4 +289,67 @@ mod tests {
         let r: Result<IntervalSet<i32>, _> = "{[abc, 10]}".parse();
         assert!(matches!(r, Err(ParseIntervalError::Element(_))));
     }
+
+    // ---- notations ----
+
+    #[test]
+    fn interval_notation() {
+        let x = Interval::<i32>::from_notation("]0, 10]", &Notation::ALL).unwrap();
+        assert_eq!(x, Interval::open_closed(0, 10));
+        let x = Interval::<i32>::from_notation("..=10", &Notation::ALL).unwrap();
+        assert_eq!(x, Interval::unbound_closed(10));
+        assert!(Interval::<i32>::from_notation("..=10", &Notation::DISPLAY).is_err());
+    }
+
+    #[test]
+    fn set_notation() {
+        let ranges = Notation::DISPLAY.with_rust_ranges(true);
+        let x = IntervalSet::<i32>::from_notation("{10..15 U 0..=5}", &ranges).unwrap();
+        assert_eq!(
+            x,
+            Interval::closed(0, 5).union(Interval::closed_open(10, 15))
+        );
+
+        // Reversed brackets don't confuse the piece splitter.
+        let iso = Notation::DISPLAY.with_iso_brackets(true);
+        let x = IntervalSet::<i32>::from_notation("{]0, 5[ U ]10, 15[ U [20, 30]}", &iso).unwrap();
+        let expected = Interval::open(0, 5)
+            .union(Interval::open(10, 15))
+            .union(Interval::closed(20, 30));
+        assert_eq!(x, expected);
+
+        let r = IntervalSet::<i32>::from_notation("{]0, 5[ U }", &iso);
+        assert!(matches!(r, Err(ParseIntervalError::Syntax(_))));
+    }
+
+    #[test]
+    fn syntax_error_spans() {
+        let err = "{[0, 5] U [10 15]}"
+            .parse::<IntervalSet<i32>>()
+            .unwrap_err();
+        assert_eq!(err.span(), Some(11..16));
+
+        let err = " [0, 5] ".parse::<IntervalSet<i32>>().unwrap_err();
+        assert!(matches!(
+            err,
+            ParseIntervalError::Syntax(e) if e.expected() == Expected::SetBraces && e.span() == (1..7)
+        ));
+
+        let err = "{[0, 5] U [1, ..]}"
+            .parse::<IntervalSet<i32>>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "malformed interval syntax: unbounded side must use ')' at 16"
+        );
+    }
+
+    #[test]
+    fn quoted_elements() {
+        let x: IntervalSet<i32> = r##"{["0", 5] U [#"10"#, "15"]}"##.parse().unwrap();
+        assert_eq!(x, Interval::closed(0, 5).union(Interval::closed(10, 15)));
+        // `U` inside a quoted element doesn't split the set.
+        let r = r#"{["0 U 1", 5]}"#.parse::<IntervalSet<i32>>();
+        assert!(matches!(r, Err(ParseIntervalError::Element(_))));
+    }
 }
@@ -0,0 +1,450 @@
+//! Partitions of a domain into consecutive pieces: [`Partition`].
+//!
+//! A partition is defined by a domain and strictly increasing
+//! breakpoints inside it. Each breakpoint ends one piece and starts the
+//! next. The [`Side`] says which piece gets the breakpoint itself, as
+//! in [`Split`]: `Side::Left` gives `(.., b]` and `(b, ..)`,
+//! `Side::Right` gives `(.., b)` and `[b, ..)`. Every member of the
+//! domain lies in exactly one piece.
+//!
+//! Pieces are numbered from `0` on the left. [`Partition::bin`] finds
+//! the piece of a point with a binary search over the breakpoints.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::measure::Extent;
+//! use intervalsets::partition::Partition;
+//!
+//! // Tax brackets: (-∞, 10_000), [10_000, 40_000), [40_000, ∞).
+//! let brackets = Partition::unbounded([10_000_i32, 40_000], Side::Right);
+//! assert_eq!(brackets.len(), 3);
+//! assert_eq!(brackets.bin(&9_999), Some(0));
+//! assert_eq!(brackets.bin(&10_000), Some(1));
+//! assert_eq!(brackets[2], Interval::closed_unbound(40_000));
+//!
+//! let income = IntervalSet::from(Interval::closed_open(5_000, 50_000));
+//! assert_eq!(
+//!     brackets.overlay(&income),
+//!     Ok(vec![
+//!         (0, Extent::Finite(5_000)),
+//!         (1, Extent::Finite(30_000)),
+//!         (2, Extent::Finite(10_000)),
+//!     ])
+//! );
+//! ```
+
+use core::ops::{Index, Range};
+
+use intervalsets_core::measure::{Extent, Measure};
+use intervalsets_core::ops::math::TryAdd;
+
+use crate::bound::{SetBounds, Side};
+use crate::error::{Error, MathError};
+use crate::factory::traits::*;
+use crate::numeric::{Element, Zero};
+use crate::ops::{Contains, Intersection, Split};
+use crate::{Interval, IntervalSet, MaybeEmpty};
+
+/// Indices of the pieces hit by a set, each with the [`Measure`] of
+/// the overlap; see [`Partition::overlay`].
+pub type Overlay<M> = Vec<(usize, Extent<M>)>;
+
+/// Consecutive, disjoint pieces that exactly cover a domain.
+///
+/// See the [module docs](self) for the breakpoint convention.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partition<T> {
+    domain: Interval<T>,
+    breaks: Vec<T>,
+    closed: Side,
+    pieces: Vec<Interval<T>>,
+}
+
+impl<T: Element + Clone> Partition<T> {
+    /// Creates a partition of `domain` at `breakpoints`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidPartition`] if `domain` is empty or a piece
+    /// would be empty: the breakpoints are not strictly increasing, or
+    /// one lies outside the domain or on a bound it cannot split.
+    /// [`Error::InvalidElement`] if a breakpoint is incomparable.
+    pub fn try_new<I>(domain: Interval<T>, breakpoints: I, closed: Side) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if domain.is_empty() {
+            return Err(Error::InvalidPartition);
+        }
+        let breaks: Vec<T> = breakpoints.into_iter().collect();
+        let mut pieces = Vec::with_capacity(breaks.len() + 1);
+        let mut rest = domain.clone();
+        for b in breaks.iter() {
+            let (left, right) = rest.try_split(b.clone(), closed)?;
+            if left.is_empty() || right.is_empty() {
+                return Err(Error::InvalidPartition);
+            }
+            pieces.push(left);
+            rest = right;
+        }
+        pieces.push(rest);
+        Ok(Self {
+            domain,
+            breaks,
+            closed,
+            pieces,
+        })
+    }
+
+    /// Panicking form of [`try_new`](Self::try_new).
+    pub fn new<I: IntoIterator<Item = T>>(
+        domain: Interval<T>,
+        breakpoints: I,
+        closed: Side,
+    ) -> Self {
+        Self::try_new(domain, breakpoints, closed).unwrap()
+    }
+
+    /// Creates a partition of `(-∞, ∞)`; see [`try_new`](Self::try_new).
+    pub fn try_unbounded<I: IntoIterator<Item = T>>(
+        breakpoints: I,
+        closed: Side,
+    ) -> Result<Self, Error> {
+        Self::try_new(Interval::unbounded(), breakpoints, closed)
+    }
+
+    /// Panicking form of [`try_unbounded`](Self::try_unbounded).
+    pub fn unbounded<I: IntoIterator<Item = T>>(breakpoints: I, closed: Side) -> Self {
+        Self::try_unbounded(breakpoints, closed).unwrap()
+    }
+
+    /// The covered domain.
+    pub fn domain(&self) -> &Interval<T> {
+        &self.domain
+    }
+
+    /// The breakpoints, strictly increasing.
+    pub fn breakpoints(&self) -> &[T] {
+        &self.breaks
+    }
+
+    /// Which piece each breakpoint belongs to.
+    pub fn closed(&self) -> Side {
+        self.closed
+    }
+
+    /// The pieces, left to right.
+    pub fn pieces(&self) -> &[Interval<T>] {
+        &self.pieces
+    }
+
+    /// Number of pieces; one more than the number of breakpoints.
+    pub fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    /// Always `false`: a partition has at least one piece.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Index of the piece that would hold `x` if the domain were
+    /// unbounded.
+    fn position(&self, x: &T) -> usize {
+        match self.closed {
+            Side::Left => self.breaks.partition_point(|b| b < x),
+            Side::Right => self.breaks.partition_point(|b| b <= x),
+        }
+    }
+
+    /// Indices of the pieces that can intersect `x`.
+    fn span(&self, x: &Interval<T>) -> Range<usize> {
+        if x.is_empty() {
+            return 0..0;
+        }
+        let lo = x.lval().map_or(0, |v| self.position(v));
+        let hi = x.rval().map_or(self.len() - 1, |v| self.position(v));
+        lo..hi + 1
+    }
+
+    /// Index of the piece containing `x`, or `None` if `x` is outside
+    /// the domain. O(log n).
+    pub fn bin(&self, x: &T) -> Option<usize> {
+        self.domain.contains(x).then(|| self.position(x))
+    }
+
+    /// The common refinement: the partition at the breakpoints of both.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidPartition`] if the domains or sides differ.
+    pub fn try_refine(&self, other: &Self) -> Result<Self, Error> {
+        if self.domain != other.domain || self.closed != other.closed {
+            return Err(Error::InvalidPartition);
+        }
+        let (mut a, mut b) = (
+            self.breaks.iter().peekable(),
+            other.breaks.iter().peekable(),
+        );
+        let mut breaks = Vec::with_capacity(self.breaks.len() + other.breaks.len());
+        loop {
+            let next = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) if x < y => a.next(),
+                (Some(x), Some(y)) if y < x => b.next(),
+                (Some(_), Some(_)) => {
+                    b.next();
+                    a.next()
+                }
+                (Some(_), None) => a.next(),
+                (None, Some(_)) => b.next(),
+                (None, None) => break,
+            };
+            breaks.extend(next.cloned());
+        }
+        Self::try_new(self.domain.clone(), breaks, self.closed)
+    }
+
+    /// Panicking form of [`try_refine`](Self::try_refine).
+    pub fn refine(&self, other: &Self) -> Self {
+        self.try_refine(other).unwrap()
+    }
+
+    /// Removes the breakpoints for which `keep(index, breakpoint)` is
+    /// `false`, merging the pieces on either side. Breakpoint `i` ends
+    /// piece `i`.
+    pub fn coarsen(&self, mut keep: impl FnMut(usize, &T) -> bool) -> Self {
+        let breaks: Vec<T> = self
+            .breaks
+            .iter()
+            .enumerate()
+            .filter(|(i, b)| keep(*i, b))
+            .map(|(_, b)| b.clone())
+            .collect();
+        Self::try_new(self.domain.clone(), breaks, self.closed)
+            .expect("a subset of valid breakpoints is valid")
+    }
+
+    /// The pieces that intersect `set`, with the [`Measure`] of each
+    /// intersection, in ascending piece order. O(m log n + k) for `m`
+    /// pieces of `set` and `k` results.
+    ///
+    /// # Errors
+    ///
+    /// The error of [`Measure`] or of summing measures.
+    pub fn overlay(&self, set: &IntervalSet<T>) -> Result<Overlay<T::Measure>, Error>
+    where
+        <T::Measure as TryAdd>::Error: Into<MathError>,
+    {
+        let mut hits: Overlay<T::Measure> = Vec::new();
+        for x in set.iter() {
+            for i in self.span(x) {
+                let overlap = (&self.pieces[i]).intersection(x);
+                if overlap.is_empty() {
+                    continue;
+                }
+                let m = overlap.try_measure()?;
+                match hits.last_mut() {
+                    // Consecutive pieces of `set` can share a piece.
+                    Some((j, total)) if *j == i => {
+                        *total = total.clone().try_add(m).map_err(Into::into)?;
+                    }
+                    _ => hits.push((i, m)),
+                }
+            }
+        }
+        Ok(hits)
+    }
+
+    /// Splits `set` by the partition: element `i` is the part of `set`
+    /// in piece `i`. Members outside the domain are dropped.
+    pub fn split_many(&self, set: &IntervalSet<T>) -> Vec<IntervalSet<T>> {
+        let mut parts: Vec<Vec<Interval<T>>> = vec![Vec::new(); self.len()];
+        for x in set.iter() {
+            for i in self.span(x) {
+                let overlap = (&self.pieces[i]).intersection(x);
+                if overlap.is_inhabited() {
+                    parts[i].push(overlap);
+                }
+            }
+        }
+        // Pieces of `set` stay sorted and apart after clipping.
+        parts
+            .into_iter()
+            .map(IntervalSet::new_assume_valid)
+            .collect()
+    }
+}
+
+impl<T: Element + Clone + Zero> Partition<T> {
+    /// Partitions `(-∞, ∞)` into `n` pieces of width `step` starting
+    /// at `start`, plus the two unbounded pieces around them. Handy
+    /// for histograms.
+    ///
+    /// # Errors
+    ///
+    /// As for [`try_new`](Self::try_new), plus the [`TryAdd`] error if
+    /// a breakpoint overflows `T`.
+    pub fn try_uniform(start: T, step: T, n: usize, closed: Side) -> Result<Self, Error>
+    where
+        T: TryAdd<Output = T>,
+        <T as TryAdd>::Error: Into<MathError>,
+    {
+        let mut breaks = Vec::with_capacity(n + 1);
+        let mut b = start;
+        for _ in 0..n {
+            let next = b.clone().try_add(step.clone()).map_err(Into::into)?;
+            breaks.push(b);
+            b = next;
+        }
+        breaks.push(b);
+        Self::try_unbounded(breaks, closed)
+    }
+}
+
+impl<T> Index<usize> for Partition<T> {
+    type Output = Interval<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.pieces[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::TestResult;
+
+    use super::*;
+    use crate::ops::Union;
+
+    #[test]
+    fn breakpoint_sides() {
+        let left = Partition::unbounded([0, 10], Side::Left);
+        assert_eq!(
+            left.pieces(),
+            [
+                Interval::unbound_closed(0),
+                Interval::open_closed(0, 10),
+                Interval::open_unbound(10),
+            ]
+        );
+        assert_eq!(left.bin(&0), Some(0));
+        assert_eq!(left.bin(&10), Some(1));
+
+        let right = Partition::unbounded([0.0, 10.0], Side::Right);
+        assert_eq!(right[1], Interval::closed_open(0.0, 10.0));
+        assert_eq!(right.bin(&0.0), Some(1));
+        assert_eq!(right.bin(&10.0), Some(2));
+    }
+
+    #[test]
+    fn bounded_domain() {
+        let p = Partition::new(Interval::closed(0.0, 1.0), [0.25, 0.5], Side::Right);
+        assert_eq!(p.len(), 3);
+        assert_eq!(p[2], Interval::closed(0.5, 1.0));
+        assert_eq!(p.bin(&1.0), Some(2));
+        assert_eq!(p.bin(&1.5), None);
+        assert_eq!(p.bin(&f64::NAN), None);
+        assert_eq!(
+            Partition::new(Interval::closed(0, 9), [], Side::Left).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn invalid_breakpoints() {
+        let d = Interval::closed(0, 10);
+        let bad = [vec![5, 5], vec![6, 5], vec![11], vec![10]];
+        for b in bad {
+            assert_eq!(
+                Partition::try_new(d, b.clone(), Side::Left),
+                Err(Error::InvalidPartition),
+                "{b:?}"
+            );
+        }
+        // The left bound can still end a piece when it keeps the point.
+        assert!(Partition::try_new(d, [0], Side::Left).is_ok());
+        assert_eq!(
+            Partition::<i32>::try_new(Interval::empty(), [], Side::Left),
+            Err(Error::InvalidPartition)
+        );
+        assert_eq!(
+            Partition::try_unbounded([f64::NAN], Side::Left),
+            Err(Error::InvalidElement)
+        );
+    }
+
+    #[test]
+    fn refine_and_coarsen() {
+        let a = Partition::unbounded([0, 10, 20], Side::Right);
+        let b = Partition::unbounded([5, 10, 30], Side::Right);
+        assert_eq!(a.refine(&b).breakpoints(), [0, 5, 10, 20, 30]);
+        assert_eq!(
+            a.try_refine(&Partition::unbounded([5], Side::Left)),
+            Err(Error::InvalidPartition)
+        );
+
+        let coarse = a.refine(&b).coarsen(|i, _| i % 2 == 0);
+        assert_eq!(coarse.breakpoints(), [0, 10, 30]);
+        assert_eq!(coarse[1], Interval::closed_open(0, 10));
+    }
+
+    #[test]
+    fn overlay_and_split_many() {
+        let p = Partition::try_uniform(0.0, 1.0, 3, Side::Right).unwrap();
+        assert_eq!(p.breakpoints(), [0.0, 1.0, 2.0, 3.0]);
+        let x = IntervalSet::new([Interval::closed(0.25, 0.5), Interval::closed(0.75, 1.5)]);
+        assert_eq!(
+            p.overlay(&x),
+            Ok(vec![(1, Extent::Finite(0.5)), (2, Extent::Finite(0.5))])
+        );
+
+        let parts = p.split_many(&x);
+        assert_eq!(parts.len(), 5);
+        assert!(parts[0].is_empty() && parts[3].is_empty() && parts[4].is_empty());
+        assert_eq!(
+            parts[1],
+            IntervalSet::new([
+                Interval::closed(0.25, 0.5),
+                Interval::closed_open(0.75, 1.0)
+            ])
+        );
+        assert_eq!(parts[2], IntervalSet::from(Interval::closed(1.0, 1.5)));
+
+        // Members outside a bounded domain are dropped.
+        let q = Partition::new(Interval::closed(1, 5), [3], Side::Left);
+        let y = IntervalSet::from(Interval::closed(0, 10));
+        assert_eq!(
+            q.split_many(&y),
+            vec![Interval::closed(1, 3).into(), Interval::closed(4, 5).into()]
+        );
+    }
+
+    #[quickcheck]
+    fn check_split_many_covers(
+        set: IntervalSet<i16>,
+        mut breaks: Vec<i16>,
+        left: bool,
+    ) -> TestResult {
+        breaks.sort_unstable();
+        breaks.dedup();
+        let side = if left { Side::Left } else { Side::Right };
+        // A breakpoint at a discrete extreme leaves an empty piece.
+        let Ok(p) = Partition::try_unbounded(breaks, side) else {
+            return TestResult::discard();
+        };
+
+        let parts = p.split_many(&set);
+        let mut union = IntervalSet::empty();
+        for (i, part) in parts.iter().enumerate() {
+            if !part.iter().all(|x| p[i].contains(x)) {
+                return TestResult::failed();
+            }
+            union = union.union(part.clone());
+        }
+        let bins_ok = set
+            .iter()
+            .filter_map(|x| x.lval())
+            .all(|v| p[p.bin(v).unwrap()].contains(v));
+        TestResult::from_bool(union == set && bins_ok)
+    }
+}
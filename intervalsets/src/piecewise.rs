@@ -0,0 +1,475 @@
+//! Piecewise-defined functions: [`Piecewise`].
+//!
+//! A `Piecewise<T, F>` pairs disjoint, ascending intervals with one
+//! value `F` each. `F` is usually a constant (a step function), a
+//! [`Linear`] piece, or a closure. Outside its pieces the function is
+//! undefined, and lookups return `None`.
+//!
+//! Pieces may touch, so a [`Partition`] maps onto a piecewise function
+//! one-to-one. [`combine`](Piecewise::combine) evaluates two functions
+//! over the common refinement of their pieces, i.e. every non-empty
+//! intersection of one piece of each.
+//!
+//! # Integrals
+//!
+//! Pieces implementing [`Integrand`] have exact integrals with respect
+//! to the [`Measure`] of the set: Lebesgue width on continuous `T`,
+//! element count on discrete `T`. On a piece with measure `m` and
+//! endpoints `a`, `b`:
+//!
+//! - a constant `c` integrates to `m · c`;
+//! - a [`Linear`] piece `f` integrates to `m · (f(a) + f(b)) / 2`,
+//!   which on discrete `T` is the exact sum `f(a) + ... + f(b)`.
+//!
+//! An unbounded piece integrates to `0` if the function is zero on it
+//! and fails with `Error::Math(MathError::Domain)` otherwise.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::partition::Partition;
+//! use intervalsets::piecewise::Piecewise;
+//!
+//! // Marginal tax rates.
+//! let brackets = Partition::new(Interval::closed_unbound(0.0), [10_000.0, 40_000.0], Side::Right);
+//! let rate = Piecewise::from_partition(&brackets, [0.0, 0.2, 0.4]).unwrap();
+//! assert_eq!(rate.get(&25_000.0), Some(&0.2));
+//!
+//! // Tax owed on an income of 50_000.
+//! let income = IntervalSet::from(Interval::closed(0.0, 50_000.0));
+//! assert_eq!(rate.restrict(&income).integral(), Ok(6_000.0 + 4_000.0));
+//!
+//! // Where is the rate at least 20%?
+//! assert_eq!(
+//!     rate.preimage(|r| *r >= 0.2),
+//!     IntervalSet::from(Interval::closed_unbound(10_000.0))
+//! );
+//! ```
+
+use intervalsets_core::measure::{Extent, Measure};
+
+use crate::bound::ord::{FiniteOrdBound, FiniteOrdBoundKind, OrdBound, OrdBounded};
+use crate::error::{Error, MathError};
+use crate::numeric::{Element, NumCast, ToPrimitive, Zero};
+use crate::ops::{Contains, Intersection, TryAdd, TryDiv, TryMul};
+use crate::partition::Partition;
+use crate::{Interval, IntervalSet, MaybeEmpty, SetBounds};
+
+/// A function defined piece by piece over disjoint intervals.
+///
+/// See the [module docs](self).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Piecewise<T, F> {
+    pieces: Vec<(Interval<T>, F)>,
+}
+
+/// `true` if `a` ends before `b` does.
+fn ends_first<T: PartialOrd>(a: &Interval<T>, b: &Interval<T>) -> bool {
+    a.ord_bound_pair().into_raw().1 < b.ord_bound_pair().into_raw().1
+}
+
+impl<T: Element + Clone, F> Piecewise<T, F> {
+    /// Creates a piecewise function from `(piece, value)` pairs.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidIntervalSet`] if a piece is empty or the pieces
+    /// are not ascending and disjoint.
+    pub fn try_new<I: IntoIterator<Item = (Interval<T>, F)>>(pieces: I) -> Result<Self, Error> {
+        let pieces: Vec<_> = pieces.into_iter().collect();
+        let valid = pieces.iter().all(|(x, _)| x.is_inhabited())
+            && pieces.windows(2).all(|w| {
+                let (a, b) = (&w[0].0, &w[1].0);
+                ends_first(a, b) && (a.clone()).intersection(b.clone()).is_empty()
+            });
+        if !valid {
+            return Err(Error::InvalidIntervalSet);
+        }
+        Ok(Self { pieces })
+    }
+
+    /// Assigns `values` to the pieces of `partition`, in order.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ValueCountMismatch`] if the number of values differs
+    /// from the number of pieces.
+    pub fn from_partition<I: IntoIterator<Item = F>>(
+        partition: &Partition<T>,
+        values: I,
+    ) -> Result<Self, Error> {
+        Self::zip(partition.pieces(), values)
+    }
+
+    /// Assigns `values` to the pieces of `set`, in order.
+    ///
+    /// # Errors
+    ///
+    /// As for [`from_partition`](Self::from_partition).
+    pub fn from_set<I: IntoIterator<Item = F>>(
+        set: &IntervalSet<T>,
+        values: I,
+    ) -> Result<Self, Error> {
+        Self::zip(set.slice(), values)
+    }
+
+    fn zip<I: IntoIterator<Item = F>>(pieces: &[Interval<T>], values: I) -> Result<Self, Error> {
+        let mut values = values.into_iter();
+        let pieces: Vec<_> = pieces
+            .iter()
+            .map(|x| values.next().map(|v| (x.clone(), v)))
+            .collect::<Option<_>>()
+            .ok_or(Error::ValueCountMismatch)?;
+        if values.next().is_some() {
+            return Err(Error::ValueCountMismatch);
+        }
+        Ok(Self { pieces })
+    }
+
+    /// The `(piece, value)` pairs, in ascending order.
+    pub fn pieces(&self) -> &[(Interval<T>, F)] {
+        &self.pieces
+    }
+
+    /// The set on which the function is defined.
+    pub fn support(&self) -> IntervalSet<T> {
+        IntervalSet::new(self.pieces.iter().map(|(x, _)| x.clone()))
+    }
+
+    /// The value of the piece containing `x`, or `None` if `x` is in no
+    /// piece. O(log n).
+    pub fn get(&self, x: &T) -> Option<&F> {
+        let at = OrdBound::Finite(FiniteOrdBound(x, FiniteOrdBoundKind::Closed));
+        let i = self
+            .pieces
+            .partition_point(|(p, _)| p.ord_bound_pair().into_raw().1 < at);
+        self.pieces
+            .get(i)
+            .filter(|(p, _)| p.contains(x))
+            .map(|(_, v)| v)
+    }
+
+    /// Calls the closure of the piece containing `x`.
+    pub fn eval<R>(&self, x: &T) -> Option<R>
+    where
+        F: Fn(&T) -> R,
+    {
+        self.get(x).map(|f| f(x))
+    }
+
+    /// Applies `f` to every value, keeping the pieces.
+    pub fn map<G>(&self, mut f: impl FnMut(&F) -> G) -> Piecewise<T, G> {
+        let pieces = self.pieces.iter().map(|(x, v)| (x.clone(), f(v)));
+        Piecewise {
+            pieces: pieces.collect(),
+        }
+    }
+
+    /// Pointwise combination over the common refinement. The result is
+    /// defined where both `self` and `other` are. O(n + m).
+    pub fn combine<G, H>(
+        &self,
+        other: &Piecewise<T, G>,
+        mut f: impl FnMut(&F, &G) -> H,
+    ) -> Piecewise<T, H> {
+        let mut pieces = Vec::with_capacity(self.pieces.len() + other.pieces.len());
+        let (mut i, mut j) = (0, 0);
+        while let (Some((a, fa)), Some((b, gb))) = (self.pieces.get(i), other.pieces.get(j)) {
+            let both = a.intersection(b);
+            if both.is_inhabited() {
+                pieces.push((both, f(fa, gb)));
+            }
+            if ends_first(a, b) {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Piecewise { pieces }
+    }
+
+    /// The function restricted to the members of `set`. A piece that
+    /// `set` splits keeps its value on every part.
+    pub fn restrict(&self, set: &IntervalSet<T>) -> Self
+    where
+        F: Clone,
+    {
+        let mask = Piecewise {
+            pieces: set.iter().map(|x| (x.clone(), ())).collect(),
+        };
+        self.combine(&mask, |v, _| v.clone())
+    }
+
+    /// The members whose piece value satisfies `pred`.
+    pub fn preimage(&self, mut pred: impl FnMut(&F) -> bool) -> IntervalSet<T> {
+        let hits = self.pieces.iter().filter(|(_, v)| pred(v));
+        IntervalSet::new(hits.map(|(x, _)| x.clone()))
+    }
+
+    /// The integral of the function with respect to [`Measure`]; see the
+    /// [module docs](self#integrals).
+    ///
+    /// # Errors
+    ///
+    /// The error of [`Integrand::try_integral`] or of summing the
+    /// results.
+    pub fn integral(&self) -> Result<F::Output, Error>
+    where
+        F: Integrand<T>,
+        F::Output: Zero + TryAdd<Output = F::Output>,
+        <F::Output as TryAdd>::Error: Into<MathError>,
+    {
+        self.pieces
+            .iter()
+            .try_fold(F::Output::zero(), |acc, (x, f)| {
+                let part = f.try_integral(x)?;
+                acc.try_add(part).map_err(|e| Error::Math(e.into()))
+            })
+    }
+}
+
+/// A piece value with a closed-form integral over an interval.
+///
+/// Implemented for the primitive numbers, as constants, and for
+/// [`Linear`].
+pub trait Integrand<T> {
+    /// The type of the integral.
+    type Output;
+
+    /// The integral over `piece` with respect to [`Measure`].
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Math(MathError::Domain)` if `piece` is unbounded and
+    ///   the value is not zero on it, or a number does not convert to
+    ///   `Output`.
+    /// - The error of [`Measure`] or of the arithmetic.
+    fn try_integral(&self, piece: &Interval<T>) -> Result<Self::Output, Error>;
+}
+
+/// An affine piece `slope · x + intercept`.
+///
+/// `x` is converted to `V` with [`NumCast`], so integer breakpoints
+/// can carry float values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Linear<V> {
+    /// Change in value per unit of `x`.
+    pub slope: V,
+    /// Value at `x = 0`.
+    pub intercept: V,
+}
+
+fn cast<V: NumCast, N: ToPrimitive>(x: N) -> Result<V, Error> {
+    V::from(x).ok_or(Error::Math(MathError::Domain))
+}
+
+fn math<E: Into<MathError>>(e: E) -> Error {
+    Error::Math(e.into())
+}
+
+impl<V> Linear<V>
+where
+    V: Clone + TryAdd<Output = V> + TryMul<Output = V> + NumCast,
+    <V as TryAdd>::Error: Into<MathError>,
+    <V as TryMul>::Error: Into<MathError>,
+{
+    /// Creates the piece `slope · x + intercept`.
+    pub fn new(slope: V, intercept: V) -> Self {
+        Self { slope, intercept }
+    }
+
+    /// The value at `x`.
+    ///
+    /// # Errors
+    ///
+    /// `Error::Math(MathError::Domain)` if `x` does not convert to `V`,
+    /// otherwise the error of the arithmetic.
+    pub fn try_at<T: ToPrimitive + Clone>(&self, x: &T) -> Result<V, Error> {
+        let x: V = cast(x.clone())?;
+        let y = self.slope.clone().try_mul(x).map_err(math)?;
+        y.try_add(self.intercept.clone()).map_err(math)
+    }
+}
+
+fn integrate<T, V>(
+    piece: &Interval<T>,
+    is_zero: bool,
+    height: impl FnOnce(&T, &T) -> Result<V, Error>,
+) -> Result<V, Error>
+where
+    T: Element,
+    T::Measure: ToPrimitive,
+    V: Zero + NumCast + TryMul<Output = V>,
+    <V as TryMul>::Error: Into<MathError>,
+{
+    match (piece.lval(), piece.rval()) {
+        _ if piece.is_empty() => Ok(V::zero()),
+        (Some(a), Some(b)) => {
+            let Extent::Finite(m) = piece.try_measure()? else {
+                unreachable!("bounded pieces have finite measure")
+            };
+            cast::<V, _>(m)?.try_mul(height(a, b)?).map_err(math)
+        }
+        _ if is_zero => Ok(V::zero()),
+        _ => Err(Error::Math(MathError::Domain)),
+    }
+}
+
+impl<T, V> Integrand<T> for Linear<V>
+where
+    T: Element + Clone + ToPrimitive,
+    T::Measure: ToPrimitive,
+    V: Clone
+        + Zero
+        + PartialEq
+        + NumCast
+        + TryAdd<Output = V>
+        + TryMul<Output = V>
+        + TryDiv<Output = V>,
+    <V as TryAdd>::Error: Into<MathError>,
+    <V as TryMul>::Error: Into<MathError>,
+    <V as TryDiv>::Error: Into<MathError>,
+{
+    type Output = V;
+
+    fn try_integral(&self, piece: &Interval<T>) -> Result<V, Error> {
+        let is_zero = self.slope.is_zero() && self.intercept.is_zero();
+        // Multiply before halving: on integers `m · (f(a) + f(b))` is
+        // always even, so the division is exact.
+        let sum = integrate(piece, is_zero, |a, b| {
+            self.try_at(a)?.try_add(self.try_at(b)?).map_err(math)
+        })?;
+        sum.try_div(cast(2)?).map_err(math)
+    }
+}
+
+macro_rules! step_integrand {
+    ($($t:ty),*) => {
+        $(
+            impl<T> Integrand<T> for $t
+            where
+                T: Element,
+                T::Measure: ToPrimitive,
+            {
+                type Output = $t;
+
+                fn try_integral(&self, piece: &Interval<T>) -> Result<$t, Error> {
+                    integrate(piece, self.is_zero(), |_, _| Ok(*self))
+                }
+            }
+        )*
+    };
+}
+
+step_integrand!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bound::Side;
+    use crate::factory::traits::*;
+
+    fn tariff() -> Piecewise<f64, f64> {
+        let p = Partition::new(Interval::closed(0.0, 30.0), [10.0, 20.0], Side::Right);
+        Piecewise::from_partition(&p, [1.0, 2.0, 3.0]).unwrap()
+    }
+
+    #[test]
+    fn construction() {
+        assert_eq!(
+            Piecewise::from_partition(&Partition::unbounded([0], Side::Left), [1]),
+            Err(Error::ValueCountMismatch)
+        );
+        assert_eq!(
+            Piecewise::from_partition(&Partition::unbounded([0], Side::Left), [1, 2, 3]),
+            Err(Error::ValueCountMismatch)
+        );
+        assert_eq!(
+            Piecewise::try_new([(Interval::closed(0, 5), 'a'), (Interval::closed(5, 9), 'b')]),
+            Err(Error::InvalidIntervalSet)
+        );
+        let x = IntervalSet::new([Interval::closed(0, 4), Interval::closed(8, 9)]);
+        let f = Piecewise::from_set(&x, ['a', 'b']).unwrap();
+        assert_eq!(f.get(&4), Some(&'a'));
+        assert_eq!(f.get(&5), None);
+        assert_eq!(f.get(&9), Some(&'b'));
+        assert_eq!(f.support(), x);
+    }
+
+    #[test]
+    fn lookup_and_closures() {
+        let f = tariff();
+        assert_eq!(f.get(&10.0), Some(&2.0));
+        assert_eq!(f.get(&9.99), Some(&1.0));
+        assert_eq!(f.get(&30.0), Some(&3.0));
+        assert_eq!(f.get(&-1.0), None);
+
+        let g = f.map(|&k| move |x: &f64| k * x);
+        assert_eq!(g.eval(&15.0), Some(30.0));
+        assert_eq!(g.eval(&31.0), None);
+    }
+
+    #[test]
+    fn combine_over_refinement() {
+        let f = tariff();
+        let p = Partition::unbounded([5.0, 25.0], Side::Left);
+        let g = Piecewise::from_partition(&p, [10.0, 20.0, 30.0]).unwrap();
+        let h = f.combine(&g, |a, b| a + b);
+        let expect = [
+            (Interval::closed(0.0, 5.0), 11.0),
+            (Interval::open(5.0, 10.0), 21.0),
+            (Interval::closed_open(10.0, 20.0), 22.0),
+            (Interval::closed(20.0, 25.0), 23.0),
+            (Interval::open_closed(25.0, 30.0), 33.0),
+        ];
+        assert_eq!(h.pieces(), expect);
+        assert_eq!(h.support(), f.support());
+    }
+
+    #[test]
+    fn restrict_and_preimage() {
+        let f = tariff();
+        let x = IntervalSet::new([Interval::closed(5.0, 12.0), Interval::closed(25.0, 40.0)]);
+        let r = f.restrict(&x);
+        assert_eq!(
+            r.pieces(),
+            [
+                (Interval::closed_open(5.0, 10.0), 1.0),
+                (Interval::closed(10.0, 12.0), 2.0),
+                (Interval::closed(25.0, 30.0), 3.0),
+            ]
+        );
+        assert_eq!(
+            f.preimage(|&v| v != 2.0),
+            IntervalSet::new([
+                Interval::closed_open(0.0, 10.0),
+                Interval::closed(20.0, 30.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn step_and_linear_integrals() {
+        assert_eq!(tariff().integral(), Ok(10.0 + 20.0 + 30.0));
+
+        // Discrete: the sum over elements.
+        let p = Partition::new(Interval::closed(1, 10), [4], Side::Left);
+        let f = Piecewise::from_partition(&p, [Linear::new(1, 0), Linear::new(0, 2)]).unwrap();
+        assert_eq!(f.integral(), Ok(1 + 2 + 3 + 4 + 6 * 2));
+        let g = Piecewise::from_partition(&p, [3_i64, -1]).unwrap();
+        assert_eq!(g.integral(), Ok(4 * 3 - 6));
+
+        // Continuous: the area under the line.
+        let line =
+            Piecewise::try_new([(Interval::closed(0.0, 2.0), Linear::new(3.0, 1.0))]).unwrap();
+        assert_eq!(line.integral(), Ok(6.0 + 2.0));
+    }
+
+    #[test]
+    fn unbounded_integrals() {
+        let p = Partition::unbounded([0.0], Side::Left);
+        let zero_left = Piecewise::from_partition(&p, [0.0, 1.0]).unwrap();
+        assert_eq!(zero_left.integral(), Err(Error::Math(MathError::Domain)));
+        let only_zero = zero_left.restrict(&Interval::unbound_closed(0.0).into());
+        assert_eq!(only_zero.integral(), Ok(0.0));
+    }
+}
@@ -0,0 +1,349 @@
+//! Polynomials over intervals: range enclosures and real-root
+//! counting / isolation.
+//!
+//! [`Polynomial`] stores dense coefficients in ascending powers of
+//! `x`. Given an input [`Interval`] `X`, the range `p(X)` can be
+//! enclosed three ways, selected by [`RangeForm`]:
+//!
+//! - [`RangeForm::Naive`] — Horner evaluation in interval arithmetic.
+//!   Cheap and defined on unbounded `X`, but suffers from the
+//!   dependency problem: `x - x` over `[0, 1]` is `[-1, 1]`, not `0`.
+//! - [`RangeForm::Centered`] — the Taylor (centered) form about
+//!   `c = mid(X)`: `p(x) = Σ b_k (x - c)^k` with each power evaluated
+//!   exactly over the symmetric interval `X - c`. Overestimation
+//!   shrinks quadratically with the width of `X`.
+//! - [`RangeForm::Bernstein`] — the hull of the Bernstein coefficients
+//!   of `p` on `X`. The first and last coefficients are `p` at the
+//!   endpoints of `X`, so the bound is exact whenever they are the
+//!   extreme coefficients.
+//!
+//! [`Polynomial::range_subdivided`] refines any form by repeated
+//! [`Bisect`](crate::ops::Bisect)ion of `X` and hulling the pieces.
+//!
+//! [`Polynomial::count_roots`] counts distinct real roots in an
+//! arbitrary interval with a Sturm sequence, and
+//! [`Polynomial::isolate_roots`] brackets each one by bisection.
+//!
+//! # Arithmetic model
+//!
+//! Every operation goes through `T`'s
+//! [`TryAdd`] / [`TrySub`] / [`TryMul`] / [`TryDiv`], so overflow and
+//! non-finite results surface as `Err`. Enclosures and root counts
+//! are exact for exact `T` (e.g. `bigdecimal::BigDecimal` or a
+//! user-supplied rational type). For `f32` / `f64`, bounds are not
+//! outward-rounded, and Sturm sign tests see rounding residue as
+//! nonzero — use floats for well-separated roots and modest degree.
+//!
+//! # Example
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::poly::{Polynomial, RangeForm};
+//!
+//! // p(x) = x² - x, true range over [0, 1] is [-1/4, 0].
+//! let p = Polynomial::new(vec![0.0, -1.0, 1.0]);
+//! let x = Interval::closed(0.0, 1.0);
+//!
+//! assert_eq!(p.range(&x, RangeForm::Naive), Ok(Interval::closed(-1.0, 0.0)));
+//! assert_eq!(p.range(&x, RangeForm::Centered), Ok(Interval::closed(-0.25, 0.0)));
+//! assert_eq!(p.range(&x, RangeForm::Bernstein), Ok(Interval::closed(-0.5, 0.0)));
+//!
+//! // Roots 0 and 1.
+//! assert_eq!(p.count_roots(&x), Ok(2));
+//! assert_eq!(p.count_roots(&Interval::open(0.0, 1.0)), Ok(0));
+//! ```
+
+use core::convert::Infallible;
+
+use intervalsets_core::error::Error as CoreError;
+use intervalsets_core::numeric::{ContinuousElement, Midpointable};
+use num_traits::{One, Zero};
+
+use crate::bound::SetBounds;
+use crate::error::{Error, MathError};
+use crate::factory::TryFiniteFactory;
+use crate::ops::{TryAdd, TryDiv, TryMul, TrySub};
+use crate::Interval;
+
+mod range;
+mod roots;
+
+pub use range::RangeForm;
+pub use roots::RootBracket;
+
+/// Element types supported by [`Polynomial`].
+///
+/// Blanket-implemented for every continuous [`Element`](crate::Element)
+/// with closed [`TryAdd`] / [`TrySub`] / [`TryMul`] / [`TryDiv`] and
+/// an infallible [`Midpointable`] impl — `f32`, `f64`,
+/// `OrderedFloat<_>`, and `bigdecimal::BigDecimal` among the library
+/// types.
+///
+/// # Contract
+///
+/// - Implementations come only from the blanket impl; the trait is a
+///   named bound, not an extension point.
+/// - The infallible midpoint is what [`Bisect`](crate::ops::Bisect)
+///   requires for subdivision.
+pub trait PolyElement:
+    ContinuousElement
+    + Clone
+    + Zero
+    + One
+    + Midpointable<Error = Infallible>
+    + TryAdd<Output = Self, Error: Into<CoreError>>
+    + TrySub<Output = Self, Error: Into<CoreError>>
+    + TryMul<Output = Self, Error: Into<CoreError>>
+    + TryDiv<Output = Self, Error: Into<CoreError>>
+{
+}
+
+impl<T> PolyElement for T where
+    T: ContinuousElement
+        + Clone
+        + Zero
+        + One
+        + Midpointable<Error = Infallible>
+        + TryAdd<Output = T, Error: Into<CoreError>>
+        + TrySub<Output = T, Error: Into<CoreError>>
+        + TryMul<Output = T, Error: Into<CoreError>>
+        + TryDiv<Output = T, Error: Into<CoreError>>
+{
+}
+
+/// A polynomial `c_0 + c_1 x + ... + c_n x^n` with dense coefficients.
+///
+/// Trailing zero coefficients are trimmed on construction, so the
+/// stored leading coefficient is nonzero and the zero polynomial has
+/// no coefficients.
+///
+/// ```
+/// use intervalsets::poly::Polynomial;
+///
+/// let p = Polynomial::new(vec![1.0, 2.0, 0.0, 0.0]);
+/// assert_eq!(p.degree(), Some(1));
+/// assert_eq!(p.eval(&3.0), Ok(7.0));
+/// assert_eq!(Polynomial::<f64>::new(vec![0.0]).degree(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial<T> {
+    coeffs: Vec<T>,
+}
+
+impl<T: Zero> Polynomial<T> {
+    /// Creates a polynomial from coefficients in ascending powers.
+    pub fn new(mut coeffs: Vec<T>) -> Self {
+        while coeffs.last().is_some_and(Zero::is_zero) {
+            coeffs.pop();
+        }
+        Self { coeffs }
+    }
+}
+
+impl<T> Polynomial<T> {
+    /// Coefficients in ascending powers; empty for the zero polynomial.
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    /// Degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Is this the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Returns the underlying coefficient storage.
+    pub fn into_raw(self) -> Vec<T> {
+        self.coeffs
+    }
+}
+
+impl<T: PolyElement> Polynomial<T> {
+    /// Evaluates `p(x)` by Horner's scheme.
+    ///
+    /// # Errors
+    ///
+    /// Any arithmetic error from `T`.
+    pub fn eval(&self, x: &T) -> Result<T, Error> {
+        self.coeffs
+            .iter()
+            .rev()
+            .try_fold(T::zero(), |acc, c| add(mul(acc, x.clone())?, c.clone()))
+    }
+
+    /// Encloses `{ p(x) : x ∈ X }` by Horner's scheme in interval
+    /// arithmetic. Equivalent to [`range`](Self::range) with
+    /// [`RangeForm::Naive`].
+    ///
+    /// # Errors
+    ///
+    /// Any arithmetic error from `T`.
+    pub fn eval_interval(&self, x: &Interval<T>) -> Result<Interval<T>, Error> {
+        if crate::MaybeEmpty::is_empty(x) {
+            return Ok(Interval::empty());
+        }
+        self.coeffs
+            .iter()
+            .rev()
+            .try_fold(point(T::zero())?, |acc, c| {
+                acc.try_mul(x.clone())?.try_add(point(c.clone())?)
+            })
+    }
+
+    /// The derivative `p'`.
+    ///
+    /// # Errors
+    ///
+    /// Any arithmetic error from `T`.
+    pub fn derivative(&self) -> Result<Self, Error> {
+        let mut k = T::zero();
+        let mut out = Vec::with_capacity(self.coeffs.len().saturating_sub(1));
+        for c in self.coeffs.iter().skip(1) {
+            k = add(k, T::one())?;
+            out.push(mul(c.clone(), k.clone())?);
+        }
+        Ok(Self::new(out))
+    }
+
+    /// Coefficients of `p(x + c)` in ascending powers of `x`, by
+    /// repeated synthetic division.
+    fn taylor_shift(&self, c: &T) -> Result<Vec<T>, Error> {
+        let mut b = self.coeffs.clone();
+        let n = b.len();
+        for i in 0..n {
+            for j in (i..n - 1).rev() {
+                b[j] = add(b[j].clone(), mul(b[j + 1].clone(), c.clone())?)?;
+            }
+        }
+        Ok(b)
+    }
+}
+
+// ===== Scalar helpers =====
+//
+// `T`'s Try* errors are only known to convert into the core error;
+// these lift them the rest of the way so call sites can use `?`.
+
+fn add<T: PolyElement>(a: T, b: T) -> Result<T, Error> {
+    a.try_add(b).map_err(|e| Error::from(e.into()))
+}
+
+fn sub<T: PolyElement>(a: T, b: T) -> Result<T, Error> {
+    a.try_sub(b).map_err(|e| Error::from(e.into()))
+}
+
+fn mul<T: PolyElement>(a: T, b: T) -> Result<T, Error> {
+    a.try_mul(b).map_err(|e| Error::from(e.into()))
+}
+
+fn div<T: PolyElement>(a: T, b: T) -> Result<T, Error> {
+    a.try_div(b).map_err(|e| Error::from(e.into()))
+}
+
+fn neg<T: PolyElement>(a: T) -> Result<T, Error> {
+    sub(T::zero(), a)
+}
+
+fn abs<T: PolyElement>(a: T) -> Result<T, Error> {
+    if a < T::zero() {
+        neg(a)
+    } else {
+        Ok(a)
+    }
+}
+
+/// Degenerate interval `[x, x]`.
+fn point<T: PolyElement>(x: T) -> Result<Interval<T>, Error> {
+    Interval::try_singleton(x)
+}
+
+/// `{ t^k : |t| ≤ r }` given `rk = r^k`: `[-rk, rk]` for odd `k`,
+/// `[0, rk]` for even `k`.
+fn symmetric_power<T: PolyElement>(k: usize, rk: T) -> Result<Interval<T>, Error> {
+    let lo = if k % 2 == 1 {
+        neg(rk.clone())?
+    } else {
+        T::zero()
+    };
+    Interval::try_closed(lo, rk)
+}
+
+/// Finite endpoints of a nonempty interval; half-bounded and unbounded
+/// inputs are `MathError::Domain`.
+fn endpoints<T: PolyElement>(x: &Interval<T>) -> Result<(T, T), Error> {
+    match (x.lval(), x.rval()) {
+        (Some(l), Some(r)) => Ok((l.clone(), r.clone())),
+        _ => Err(Error::Math(MathError::Domain)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+
+    #[test]
+    fn trims_trailing_zeros() {
+        let p = Polynomial::new(vec![0.0, 0.0]);
+        assert!(p.is_zero());
+        assert_eq!(p.degree(), None);
+        assert_eq!(p.eval(&5.0), Ok(0.0));
+    }
+
+    #[test]
+    fn derivative() {
+        // 1 + 2x + 3x² → 2 + 6x
+        let p = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        assert_eq!(p.derivative(), Ok(Polynomial::new(vec![2.0, 6.0])));
+        assert!(Polynomial::new(vec![4.0]).derivative().unwrap().is_zero());
+    }
+
+    #[test]
+    fn taylor_shift() {
+        // x² shifted by 1: (x + 1)² = 1 + 2x + x²
+        let p = Polynomial::new(vec![0.0, 0.0, 1.0]);
+        assert_eq!(p.taylor_shift(&1.0), Ok(vec![1.0, 2.0, 1.0]));
+    }
+
+    #[test]
+    fn eval_interval_unbounded() {
+        // 1 + x over [0, ∞) is [1, ∞).
+        let p = Polynomial::new(vec![1.0, 1.0]);
+        let x = Interval::closed_unbound(0.0);
+        assert_eq!(p.eval_interval(&x), Ok(Interval::closed_unbound(1.0)));
+        assert_eq!(p.eval_interval(&Interval::empty()), Ok(Interval::empty()));
+    }
+
+    #[test]
+    fn overflow_surfaces() {
+        let p = Polynomial::new(vec![0.0, f64::MAX]);
+        assert_eq!(p.eval(&2.0), Err(Error::Math(MathError::Domain)));
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn bigdecimal_exact() {
+        use bigdecimal::BigDecimal;
+
+        use crate::ops::Contains;
+
+        let d = |v: i64| BigDecimal::from(v);
+        // 3x² - 1, roots ±1/√3
+        let p = Polynomial::new(vec![d(-1), d(0), d(3)]);
+        let x = Interval::closed(d(0), d(1));
+        assert_eq!(p.count_roots(&x), Ok(1));
+        assert_eq!(p.count_roots(&Interval::unbounded()), Ok(2));
+
+        let roots = p.isolate_roots(&Interval::unbounded(), 20).unwrap();
+        assert_eq!(roots.len(), 2);
+
+        let r = p.range(&x, RangeForm::Bernstein).unwrap();
+        assert_eq!(r, Interval::closed(d(-1), d(2)));
+        let r = p.range(&x, RangeForm::Centered).unwrap();
+        assert!(r.contains(&d(-1)) && r.contains(&d(2)));
+    }
+}
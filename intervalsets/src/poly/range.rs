@@ -0,0 +1,268 @@
+use super::{add, div, endpoints, mul, point, sub, symmetric_power, PolyElement, Polynomial};
+use crate::bound::Side;
+use crate::error::Error;
+use crate::ops::{Bisect, ConvexHull, Midpoint, TryAdd, TryMul};
+use crate::{Interval, MaybeEmpty};
+
+/// Range-enclosure method for [`Polynomial::range`].
+///
+/// See the [module docs](super) for how the forms compare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeForm {
+    /// Horner evaluation in interval arithmetic.
+    Naive,
+    /// Taylor expansion about the midpoint of the input.
+    Centered,
+    /// Hull of the Bernstein coefficients on the input.
+    Bernstein,
+}
+
+impl<T: PolyElement> Polynomial<T> {
+    /// Encloses `{ p(x) : x ∈ X }` using `form`.
+    ///
+    /// The result is a closed interval containing the range of `p`
+    /// over the closure of `X`; an empty `X` yields an empty range.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Math(MathError::Domain)` for [`RangeForm::Centered`]
+    ///   and [`RangeForm::Bernstein`] when `X` is half-bounded or
+    ///   unbounded. [`RangeForm::Naive`] accepts any `X`.
+    /// - Any arithmetic error from `T`.
+    pub fn range(&self, x: &Interval<T>, form: RangeForm) -> Result<Interval<T>, Error> {
+        if x.is_empty() {
+            return Ok(Interval::empty());
+        }
+        match form {
+            RangeForm::Naive => self.eval_interval(x),
+            RangeForm::Centered => self.range_centered(x),
+            RangeForm::Bernstein => self.range_bernstein(x),
+        }
+    }
+
+    /// Encloses `{ p(x) : x ∈ X }` by splitting `X` into `2^depth`
+    /// pieces with [`Bisect`] and hulling the per-piece enclosures from
+    /// `form`. `depth = 0` is the same as [`range`](Self::range).
+    ///
+    /// Subdivision never widens an enclosure; for the centered and
+    /// Bernstein forms the overestimation shrinks quadratically with
+    /// piece width.
+    ///
+    /// ```
+    /// use intervalsets::prelude::*;
+    /// use intervalsets::poly::{Polynomial, RangeForm};
+    ///
+    /// let p = Polynomial::new(vec![0.0, -1.0, 1.0]);
+    /// let x = Interval::closed(0.0, 1.0);
+    /// let r = p.range_subdivided(&x, RangeForm::Bernstein, 1).unwrap();
+    /// assert_eq!(r, Interval::closed(-0.25, 0.0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// As [`range`](Self::range).
+    pub fn range_subdivided(
+        &self,
+        x: &Interval<T>,
+        form: RangeForm,
+        depth: usize,
+    ) -> Result<Interval<T>, Error> {
+        if depth == 0 {
+            return self.range(x, form);
+        }
+        match x.bisect(Side::Left) {
+            Some(b) => Interval::try_hull([
+                self.range_subdivided(&b.left, form, depth - 1)?,
+                self.range_subdivided(&b.right, form, depth - 1)?,
+            ]),
+            // Empty, degenerate, or not fully bounded: nothing to split.
+            None => self.range(x, form),
+        }
+    }
+
+    fn range_centered(&self, x: &Interval<T>) -> Result<Interval<T>, Error> {
+        let (lo, hi) = endpoints(x)?;
+        let c = x.midpoint()?;
+        // `mid` may round; take the wider half so `X - c ⊆ [-r, r]`.
+        let r = sub(hi, c.clone())?;
+        let r = {
+            let l = sub(c.clone(), lo)?;
+            if l > r {
+                l
+            } else {
+                r
+            }
+        };
+        let b = self.taylor_shift(&c)?;
+        let mut acc = point(T::zero())?;
+        let mut pow = T::one();
+        for (k, bk) in b.into_iter().enumerate() {
+            let term = if k == 0 {
+                point(bk)?
+            } else {
+                pow = mul(pow, r.clone())?;
+                point(bk)?.try_mul(symmetric_power(k, pow.clone())?)?
+            };
+            acc = acc.try_add(term)?;
+        }
+        Ok(acc)
+    }
+
+    fn range_bernstein(&self, x: &Interval<T>) -> Result<Interval<T>, Error> {
+        let (lo, hi) = endpoints(x)?;
+        if self.is_zero() {
+            return point(T::zero());
+        }
+        let w = sub(hi, lo.clone())?;
+        // q(t) = p(lo + w t) on t ∈ [0, 1].
+        let mut q = self.taylor_shift(&lo)?;
+        let mut scale = T::one();
+        for qk in q.iter_mut().skip(1) {
+            scale = mul(scale, w.clone())?;
+            *qk = mul(qk.clone(), scale.clone())?;
+        }
+
+        // β_i = Σ_{k ≤ i} C(i, k) / C(n, k) · q_k
+        let n = q.len() - 1;
+        let binom = pascal::<T>(n)?;
+        let mut coeffs = Vec::with_capacity(n + 1);
+        for i in 0..=n {
+            let mut beta = T::zero();
+            for (k, qk) in q.iter().enumerate().take(i + 1) {
+                let ratio = div(binom[i][k].clone(), binom[n][k].clone())?;
+                beta = add(beta, mul(ratio, qk.clone())?)?;
+            }
+            coeffs.push(beta);
+        }
+        Interval::try_hull(coeffs)
+    }
+}
+
+/// Rows `0..=n` of Pascal's triangle in `T`.
+fn pascal<T: PolyElement>(n: usize) -> Result<Vec<Vec<T>>, Error> {
+    let mut rows: Vec<Vec<T>> = Vec::with_capacity(n + 1);
+    for i in 0..=n {
+        let mut row = Vec::with_capacity(i + 1);
+        for k in 0..=i {
+            let v = if k == 0 || k == i {
+                T::one()
+            } else {
+                add(rows[i - 1][k - 1].clone(), rows[i - 1][k].clone())?
+            };
+            row.push(v);
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::MathError;
+    use crate::factory::traits::*;
+    use crate::ops::Contains;
+
+    const FORMS: [RangeForm; 3] = [RangeForm::Naive, RangeForm::Centered, RangeForm::Bernstein];
+
+    /// p(x) = x³ - 2x² - x + 2 = (x + 1)(x - 1)(x - 2)
+    fn cubic() -> Polynomial<f64> {
+        Polynomial::new(vec![2.0, -1.0, -2.0, 1.0])
+    }
+
+    fn width(x: &Interval<f64>) -> f64 {
+        use crate::SetBounds;
+        x.rval().unwrap() - x.lval().unwrap()
+    }
+
+    #[test]
+    fn every_form_encloses_samples() {
+        let p = cubic();
+        let x = Interval::closed(-1.5, 2.5);
+        for form in FORMS {
+            for depth in 0..4 {
+                let r = p.range_subdivided(&x, form, depth).unwrap();
+                for i in 0..=400 {
+                    let t = -1.5 + 4.0 * (i as f64) / 400.0;
+                    assert!(r.contains(&p.eval(&t).unwrap()), "{form:?} {depth} {t}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subdivision_never_widens() {
+        let p = cubic();
+        let x = Interval::closed(-1.5, 2.5);
+        for form in FORMS {
+            let mut prev = f64::INFINITY;
+            for depth in 0..6 {
+                let w = width(&p.range_subdivided(&x, form, depth).unwrap());
+                assert!(w <= prev, "{form:?} depth {depth}");
+                prev = w;
+            }
+        }
+    }
+
+    #[test]
+    fn bernstein_endpoint_coefficients() {
+        // x³ on [1, 2] has Bernstein coefficients 1, 2, 4, 8: the
+        // extremes are the end coefficients, so the bound is exact.
+        let p = Polynomial::new(vec![0.0, 0.0, 0.0, 1.0]);
+        let x = Interval::closed(1.0, 2.0);
+        assert_eq!(
+            p.range(&x, RangeForm::Bernstein),
+            Ok(Interval::closed(1.0, 8.0))
+        );
+
+        // On [-1, 2] the interior coefficients 2 and -4 escape the
+        // true range [-1, 8].
+        let x = Interval::closed(-1.0, 2.0);
+        assert_eq!(
+            p.range(&x, RangeForm::Bernstein),
+            Ok(Interval::closed(-4.0, 8.0))
+        );
+    }
+
+    #[test]
+    fn dependency_problem() {
+        // p(x) = x - x²: Horner evaluates (1 - x)·x, where the two
+        // occurrences of x vary independently.
+        let p = Polynomial::new(vec![0.0, 1.0, -1.0]);
+        let x = Interval::closed(0.0, 1.0);
+        let naive = p.range(&x, RangeForm::Naive).unwrap();
+        let centered = p.range(&x, RangeForm::Centered).unwrap();
+        assert_eq!(centered, Interval::closed(0.0, 0.25));
+        assert!(width(&naive) > width(&centered));
+    }
+
+    #[test]
+    fn degenerate_and_empty_inputs() {
+        let p = cubic();
+        let x = Interval::singleton(3.0);
+        for form in FORMS {
+            assert_eq!(p.range(&x, form), Ok(Interval::singleton(8.0)));
+            assert_eq!(p.range(&Interval::empty(), form), Ok(Interval::empty()));
+        }
+        let zero = Polynomial::<f64>::new(vec![]);
+        assert_eq!(
+            zero.range(&Interval::closed(0.0, 1.0), RangeForm::Bernstein),
+            Ok(Interval::singleton(0.0))
+        );
+    }
+
+    #[test]
+    fn unbounded_input() {
+        let p = cubic();
+        let x = Interval::closed_unbound(0.0);
+        assert!(p.range(&x, RangeForm::Naive).is_ok());
+        assert_eq!(
+            p.range(&x, RangeForm::Centered),
+            Err(Error::Math(MathError::Domain))
+        );
+        assert_eq!(
+            p.range_subdivided(&x, RangeForm::Bernstein, 3),
+            Err(Error::Math(MathError::Domain))
+        );
+    }
+}
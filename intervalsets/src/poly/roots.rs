@@ -0,0 +1,313 @@
+use core::cmp::Ordering;
+
+use super::{abs, add, div, mul, neg, sub, PolyElement, Polynomial};
+use crate::bound::{SetBounds, Side};
+use crate::error::{Error, MathError};
+use crate::factory::TryFiniteFactory;
+use crate::ops::{Bisect, Intersection};
+use crate::{Interval, MaybeEmpty};
+
+/// An interval holding `count` distinct real roots of a polynomial.
+///
+/// Produced by [`Polynomial::isolate_roots`]. `count == 1` means the
+/// root is isolated; larger counts only occur when the depth budget
+/// ran out before the roots separated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RootBracket<T> {
+    /// The bracketing interval.
+    pub interval: Interval<T>,
+    /// Number of distinct roots inside `interval`.
+    pub count: usize,
+}
+
+/// Where a Sturm sequence is evaluated.
+enum At<'a, T> {
+    NegInf,
+    Point(&'a T),
+    PosInf,
+}
+
+impl<T: PolyElement> Polynomial<T> {
+    /// Counts the distinct real roots of `p` in `x`, honouring open
+    /// and closed bounds. `x` may be half-bounded or unbounded.
+    ///
+    /// Uses a Sturm sequence: the number of roots in `(a, b]` is the
+    /// drop in sign variations from `a` to `b`, with endpoint roots
+    /// then added or removed to match `x`'s bound types.
+    ///
+    /// ```
+    /// use intervalsets::prelude::*;
+    /// use intervalsets::poly::Polynomial;
+    ///
+    /// // (x + 1)(x - 1)(x - 2)
+    /// let p = Polynomial::new(vec![2.0, -1.0, -2.0, 1.0]);
+    /// assert_eq!(p.count_roots(&Interval::unbounded()), Ok(3));
+    /// assert_eq!(p.count_roots(&Interval::closed(1.0, 2.0)), Ok(2));
+    /// assert_eq!(p.count_roots(&Interval::closed_open(1.0, 2.0)), Ok(1));
+    /// assert_eq!(p.count_roots(&Interval::unbound_open(-1.0)), Ok(0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Math(MathError::Domain)` for the zero polynomial on a
+    ///   nonempty `x` (every point is a root).
+    /// - Any arithmetic error from `T`.
+    pub fn count_roots(&self, x: &Interval<T>) -> Result<usize, Error> {
+        if x.is_empty() {
+            return Ok(0);
+        }
+        if self.is_zero() {
+            return Err(Error::Math(MathError::Domain));
+        }
+        let seq = self.sturm_sequence()?;
+        let at_left = match x.lval() {
+            Some(a) => At::Point(a),
+            None => At::NegInf,
+        };
+        let at_right = match x.rval() {
+            Some(b) => At::Point(b),
+            None => At::PosInf,
+        };
+        let mut n = variations(&seq, at_left)?.saturating_sub(variations(&seq, at_right)?);
+
+        if let Some(a) = x.left() {
+            if a.is_closed() && self.eval(a.value())?.is_zero() {
+                n += 1;
+            }
+        }
+        if let Some(b) = x.right() {
+            if b.is_open() && self.eval(b.value())?.is_zero() {
+                n = n.saturating_sub(1);
+            }
+        }
+        Ok(n)
+    }
+
+    /// Brackets each distinct real root of `p` in `x`.
+    ///
+    /// `x` is repeatedly [`Bisect`]ed; pieces without roots are
+    /// dropped and pieces holding one root are returned. A piece still
+    /// holding several roots after `max_depth` bisections is returned
+    /// with its count. Brackets are disjoint and in ascending order.
+    ///
+    /// Half-bounded and unbounded `x` are first clipped to the Cauchy
+    /// bound `1 + max |c_i / c_n|`, which contains every real root.
+    ///
+    /// ```
+    /// use intervalsets::prelude::*;
+    /// use intervalsets::poly::Polynomial;
+    ///
+    /// // x² - 2
+    /// let p = Polynomial::new(vec![-2.0, 0.0, 1.0]);
+    /// let roots = p.isolate_roots(&Interval::unbounded(), 60).unwrap();
+    /// assert_eq!(roots.len(), 2);
+    /// assert!(roots.iter().all(|r| r.count == 1));
+    /// assert!(roots[1].interval.contains(&2f64.sqrt()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// As [`count_roots`](Self::count_roots).
+    pub fn isolate_roots(
+        &self,
+        x: &Interval<T>,
+        max_depth: usize,
+    ) -> Result<Vec<RootBracket<T>>, Error> {
+        if x.is_empty() {
+            return Ok(Vec::new());
+        }
+        if self.is_zero() {
+            return Err(Error::Math(MathError::Domain));
+        }
+        let x = if x.is_fully_bounded() {
+            x.clone()
+        } else {
+            let b = self.cauchy_bound()?;
+            x.intersection(&Interval::try_closed(neg(b.clone())?, b)?)
+        };
+        let mut out = Vec::new();
+        self.isolate_into(x, max_depth, &mut out)?;
+        Ok(out)
+    }
+
+    fn isolate_into(
+        &self,
+        x: Interval<T>,
+        depth: usize,
+        out: &mut Vec<RootBracket<T>>,
+    ) -> Result<(), Error> {
+        let count = self.count_roots(&x)?;
+        if count == 0 {
+            return Ok(());
+        }
+        if count > 1 && depth > 0 {
+            if let Some(b) = x.bisect(Side::Left) {
+                self.isolate_into(b.left, depth - 1, out)?;
+                return self.isolate_into(b.right, depth - 1, out);
+            }
+        }
+        out.push(RootBracket { interval: x, count });
+        Ok(())
+    }
+
+    /// `1 + max_{i < n} |c_i / c_n|`.
+    fn cauchy_bound(&self) -> Result<T, Error> {
+        let (lead, rest) = self.coeffs.split_last().expect("nonzero polynomial");
+        let mut m = T::zero();
+        for c in rest {
+            let r = abs(div(c.clone(), lead.clone())?)?;
+            if r > m {
+                m = r;
+            }
+        }
+        add(T::one(), m)
+    }
+
+    /// `p, p', -rem(p, p'), ...` up to the last nonzero remainder.
+    fn sturm_sequence(&self) -> Result<Vec<Self>, Error> {
+        let mut seq = vec![self.clone(), self.derivative()?];
+        loop {
+            let n = seq.len();
+            if seq[n - 1].is_zero() {
+                seq.pop();
+                return Ok(seq);
+            }
+            let r = seq[n - 2].rem(&seq[n - 1])?;
+            seq.push(Self::new(
+                r.coeffs.into_iter().map(neg).collect::<Result<_, _>>()?,
+            ));
+        }
+    }
+
+    /// Remainder of `self / d` for nonzero `d`.
+    fn rem(&self, d: &Self) -> Result<Self, Error> {
+        let dn = d.coeffs.len();
+        let lead = d.coeffs[dn - 1].clone();
+        let mut r = self.coeffs.clone();
+        while r.len() >= dn {
+            let shift = r.len() - dn;
+            let q = div(r[r.len() - 1].clone(), lead.clone())?;
+            for (k, dk) in d.coeffs.iter().enumerate().take(dn - 1) {
+                r[shift + k] = sub(r[shift + k].clone(), mul(q.clone(), dk.clone())?)?;
+            }
+            // The leading term cancels by construction; drop it rather
+            // than trusting `T`'s rounding to produce an exact zero.
+            r.pop();
+            while r.last().is_some_and(|c| c.is_zero()) {
+                r.pop();
+            }
+        }
+        Ok(Self::new(r))
+    }
+}
+
+/// Sign changes in the Sturm sequence at `at`, ignoring zeros.
+fn variations<T: PolyElement>(seq: &[Polynomial<T>], at: At<'_, T>) -> Result<usize, Error> {
+    let mut last: Option<Ordering> = None;
+    let mut n = 0;
+    for p in seq {
+        let s = match at {
+            At::Point(v) => p.eval(v)?.partial_cmp(&T::zero()),
+            At::PosInf | At::NegInf => {
+                let (lead, deg) = (p.coeffs.last().expect("nonzero"), p.coeffs.len() - 1);
+                let s = lead.partial_cmp(&T::zero());
+                match at {
+                    At::NegInf if deg % 2 == 1 => s.map(Ordering::reverse),
+                    _ => s,
+                }
+            }
+        };
+        let s = s.ok_or(Error::InvalidElement)?;
+        if s == Ordering::Equal {
+            continue;
+        }
+        if last.is_some_and(|l| l != s) {
+            n += 1;
+        }
+        last = Some(s);
+    }
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::Contains;
+
+    /// (x + 1)(x - 1)(x - 2)
+    fn cubic() -> Polynomial<f64> {
+        Polynomial::new(vec![2.0, -1.0, -2.0, 1.0])
+    }
+
+    #[test]
+    fn counts_respect_bound_types() {
+        let p = cubic();
+        assert_eq!(p.count_roots(&Interval::closed(-1.0, 2.0)), Ok(3));
+        assert_eq!(p.count_roots(&Interval::open(-1.0, 2.0)), Ok(1));
+        assert_eq!(p.count_roots(&Interval::open_closed(-1.0, 2.0)), Ok(2));
+        assert_eq!(p.count_roots(&Interval::singleton(1.0)), Ok(1));
+        assert_eq!(p.count_roots(&Interval::singleton(0.0)), Ok(0));
+        assert_eq!(p.count_roots(&Interval::empty()), Ok(0));
+    }
+
+    #[test]
+    fn counts_half_bounded() {
+        let p = cubic();
+        assert_eq!(p.count_roots(&Interval::closed_unbound(1.0)), Ok(2));
+        assert_eq!(p.count_roots(&Interval::open_unbound(1.0)), Ok(1));
+        assert_eq!(p.count_roots(&Interval::unbound_closed(-1.0)), Ok(1));
+    }
+
+    #[test]
+    fn repeated_root_counts_once() {
+        // (x - 1)²(x + 2) = x³ - 3x + 2
+        let p = Polynomial::new(vec![2.0, -3.0, 0.0, 1.0]);
+        assert_eq!(p.count_roots(&Interval::unbounded()), Ok(2));
+        assert_eq!(p.count_roots(&Interval::closed(0.0, 5.0)), Ok(1));
+    }
+
+    #[test]
+    fn no_real_roots() {
+        // x² + 1
+        let p = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        assert_eq!(p.count_roots(&Interval::unbounded()), Ok(0));
+        assert_eq!(p.isolate_roots(&Interval::unbounded(), 10), Ok(vec![]));
+    }
+
+    #[test]
+    fn constant_and_zero() {
+        let c = Polynomial::new(vec![3.0]);
+        assert_eq!(c.count_roots(&Interval::unbounded()), Ok(0));
+        let z = Polynomial::<f64>::new(vec![]);
+        assert_eq!(
+            z.count_roots(&Interval::closed(0.0, 1.0)),
+            Err(Error::Math(MathError::Domain))
+        );
+        assert_eq!(z.count_roots(&Interval::empty()), Ok(0));
+    }
+
+    #[test]
+    fn isolates_each_root() {
+        let p = cubic();
+        let roots = p.isolate_roots(&Interval::unbounded(), 30).unwrap();
+        assert_eq!(roots.len(), 3);
+        for (r, want) in roots.iter().zip([-1.0, 1.0, 2.0]) {
+            assert_eq!(r.count, 1);
+            assert!(r.interval.contains(&want));
+        }
+    }
+
+    #[test]
+    fn depth_budget_reports_clusters() {
+        let p = cubic();
+        let roots = p.isolate_roots(&Interval::closed(0.0, 4.0), 0).unwrap();
+        assert_eq!(
+            roots,
+            vec![RootBracket {
+                interval: Interval::closed(0.0, 4.0),
+                count: 2
+            }]
+        );
+    }
+}
@@ -0,0 +1,668 @@
+//! Probabilities of sets under statistical distributions (feature
+//! `statrs`).
+//!
+//! [`Probability`] gives `P(X ∈ S)` for a set `S` and a [`Univariate`]
+//! distribution of `X`: the [statrs] normal, uniform and exponential
+//! distributions on `f64`, the Poisson and binomial distributions on
+//! `i64`, and an [`Empirical`] distribution of a sample on either.
+//! [`Conditional`] samples `X` given `X ∈ S`.
+//!
+//! Each piece of `S` is evaluated from the distribution's CDF, or its
+//! survival function for pieces in the upper tail, so small tail
+//! probabilities are not lost to cancellation. Unbounded sides use the
+//! exact limits 0 and 1. Bound types decide whether the mass of an
+//! atom at an endpoint is counted: `P(X ∈ [2, 5])` and
+//! `P(X ∈ (2, 5))` differ for a Poisson `X`.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::probability::{Normal, Poisson, Probability};
+//!
+//! let z = Normal::standard();
+//! let tails = IntervalSet::new([Interval::unbound_open(-1.96), Interval::open_unbound(1.96)]);
+//! assert!((tails.probability(&z) - 0.05).abs() < 1e-4);
+//!
+//! let arrivals = Poisson::new(3.0).unwrap();
+//! let closed = Interval::closed(2_i64, 5).probability(&arrivals);
+//! let open = Interval::open(2_i64, 5).probability(&arrivals);
+//! assert!(closed > open);
+//! ```
+//!
+//! [statrs]: https://docs.rs/statrs
+
+use rand::distributions::Distribution;
+use rand::Rng;
+pub use statrs::distribution::{Binomial, Exp, Normal, Poisson, Uniform};
+use statrs::distribution::{ContinuousCDF, Discrete, DiscreteCDF};
+
+use crate::bound::FiniteBound;
+use crate::error::{Error, MathError};
+use crate::numeric::Element;
+use crate::ops::Contains;
+use crate::sample::TrySample;
+use crate::{Interval, IntervalSet, MaybeEmpty, SetBounds};
+
+/// A univariate probability distribution over `T`.
+///
+/// # Contract
+///
+/// - `cdf` is non-decreasing and `sf(x) == 1 - cdf(x)` up to rounding;
+///   `sf` should stay accurate where `cdf` is close to 1.
+/// - `mass(x)` is `P(X = x)`: zero everywhere for a continuous
+///   distribution.
+/// - `quantile` and `upper_quantile` are the generalized inverses of
+///   `cdf` and `sf`, and are only called with arguments in `(0, 1)`.
+pub trait Univariate<T> {
+    /// `P(X ≤ x)`.
+    fn cdf(&self, x: &T) -> f64;
+
+    /// `P(X > x)`.
+    fn sf(&self, x: &T) -> f64;
+
+    /// `P(X = x)`.
+    fn mass(&self, x: &T) -> f64;
+
+    /// The smallest `x` with `cdf(x) ≥ p`.
+    fn quantile(&self, p: f64) -> T;
+
+    /// The smallest `x` with `sf(x) ≤ q`.
+    fn upper_quantile(&self, q: f64) -> T;
+}
+
+impl Univariate<f64> for Normal {
+    fn cdf(&self, x: &f64) -> f64 {
+        ContinuousCDF::cdf(self, *x)
+    }
+
+    fn sf(&self, x: &f64) -> f64 {
+        ContinuousCDF::sf(self, *x)
+    }
+
+    fn mass(&self, _: &f64) -> f64 {
+        0.0
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        self.inverse_cdf(p)
+    }
+
+    fn upper_quantile(&self, q: f64) -> f64 {
+        // Reflect about the mean rather than invert `1 - q`.
+        let mean = statrs::statistics::Distribution::mean(self).unwrap_or_default();
+        2.0 * mean - self.inverse_cdf(q)
+    }
+}
+
+impl Univariate<f64> for Uniform {
+    fn cdf(&self, x: &f64) -> f64 {
+        ContinuousCDF::cdf(self, *x)
+    }
+
+    fn sf(&self, x: &f64) -> f64 {
+        ContinuousCDF::sf(self, *x)
+    }
+
+    fn mass(&self, _: &f64) -> f64 {
+        0.0
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        self.inverse_cdf(p)
+    }
+
+    fn upper_quantile(&self, q: f64) -> f64 {
+        self.inverse_cdf(1.0 - q)
+    }
+}
+
+impl Univariate<f64> for Exp {
+    fn cdf(&self, x: &f64) -> f64 {
+        ContinuousCDF::cdf(self, *x)
+    }
+
+    fn sf(&self, x: &f64) -> f64 {
+        ContinuousCDF::sf(self, *x)
+    }
+
+    fn mass(&self, _: &f64) -> f64 {
+        0.0
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        -(-p).ln_1p() / self.rate()
+    }
+
+    fn upper_quantile(&self, q: f64) -> f64 {
+        -q.ln() / self.rate()
+    }
+}
+
+/// The smallest `k ≥ 0` satisfying a monotone predicate, or `i64::MAX`.
+fn first_nonnegative(pred: impl Fn(i64) -> bool) -> i64 {
+    if pred(0) {
+        return 0;
+    }
+    // Gallop to a bracket (lo, hi], then bisect.
+    let mut lo = 0;
+    let mut hi = 1;
+    while !pred(hi) {
+        if hi == i64::MAX {
+            return hi;
+        }
+        lo = hi;
+        hi = hi.saturating_mul(2);
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+macro_rules! discrete_univariate {
+    ($($t:ty),*) => {
+        $(
+            impl Univariate<i64> for $t {
+                fn cdf(&self, x: &i64) -> f64 {
+                    u64::try_from(*x).map_or(0.0, |k| DiscreteCDF::cdf(self, k))
+                }
+
+                fn sf(&self, x: &i64) -> f64 {
+                    u64::try_from(*x).map_or(1.0, |k| DiscreteCDF::sf(self, k))
+                }
+
+                fn mass(&self, x: &i64) -> f64 {
+                    u64::try_from(*x).map_or(0.0, |k| self.pmf(k))
+                }
+
+                fn quantile(&self, p: f64) -> i64 {
+                    first_nonnegative(|k| Univariate::cdf(self, &k) >= p)
+                }
+
+                fn upper_quantile(&self, q: f64) -> i64 {
+                    first_nonnegative(|k| Univariate::sf(self, &k) <= q)
+                }
+            }
+        )*
+    };
+}
+
+discrete_univariate!(Poisson, Binomial);
+
+/// The empirical distribution of a sample: each observation has mass
+/// `1 / n`.
+///
+/// ```
+/// use intervalsets::prelude::*;
+/// use intervalsets::probability::{Empirical, Probability};
+///
+/// let latency = Empirical::try_new([12.0, 15.0, 15.0, 40.0]).unwrap();
+/// assert_eq!(Interval::closed(15.0, 40.0).probability(&latency), 0.75);
+/// assert_eq!(Interval::open(15.0, 40.0).probability(&latency), 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Empirical<T> {
+    sorted: Vec<T>,
+}
+
+impl<T: PartialOrd> Empirical<T> {
+    /// Creates the empirical distribution of `samples`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Math(MathError::Domain)` if `samples` is empty.
+    /// - `Error::InvalidElement` if a sample is not comparable with
+    ///   itself, such as `NaN`.
+    pub fn try_new<I: IntoIterator<Item = T>>(samples: I) -> Result<Self, Error> {
+        let mut sorted: Vec<T> = samples.into_iter().collect();
+        if sorted.is_empty() {
+            return Err(Error::Math(MathError::Domain));
+        }
+        if sorted.iter().any(|x| x.partial_cmp(x).is_none()) {
+            return Err(Error::InvalidElement);
+        }
+        sorted.sort_by(|a, b| a.partial_cmp(b).expect("validated comparable"));
+        Ok(Self { sorted })
+    }
+
+    /// The sorted observations.
+    pub fn samples(&self) -> &[T] {
+        &self.sorted
+    }
+
+    fn fraction(&self, count: usize) -> f64 {
+        count as f64 / self.sorted.len() as f64
+    }
+}
+
+impl<T: PartialOrd + Clone> Univariate<T> for Empirical<T> {
+    fn cdf(&self, x: &T) -> f64 {
+        self.fraction(self.sorted.partition_point(|s| s <= x))
+    }
+
+    fn sf(&self, x: &T) -> f64 {
+        self.fraction(self.sorted.len() - self.sorted.partition_point(|s| s <= x))
+    }
+
+    fn mass(&self, x: &T) -> f64 {
+        let below = self.sorted.partition_point(|s| s < x);
+        let at_or_below = self.sorted.partition_point(|s| s <= x);
+        self.fraction(at_or_below - below)
+    }
+
+    fn quantile(&self, p: f64) -> T {
+        let n = self.sorted.len();
+        let rank = ((p * n as f64).ceil() as usize).clamp(1, n);
+        self.sorted[rank - 1].clone()
+    }
+
+    fn upper_quantile(&self, q: f64) -> T {
+        let n = self.sorted.len();
+        let above = ((q * n as f64).floor() as usize).min(n - 1);
+        self.sorted[n - 1 - above].clone()
+    }
+}
+
+/// The probability mass of one inhabited piece, as `to - from` in
+/// whichever tail is better conditioned.
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    upper: bool,
+    from: f64,
+    to: f64,
+}
+
+impl Span {
+    fn new<T, D: Univariate<T>>(
+        dist: &D,
+        left: Option<&FiniteBound<T>>,
+        right: Option<&FiniteBound<T>>,
+    ) -> Self {
+        let upper = left.is_some_and(|a| dist.cdf(a.value()) > 0.5);
+        if upper {
+            // `P(X ≥ left) - P(X > right)` in survival terms, counted
+            // downward; `from > to`.
+            let from = left.map_or(1.0, |a| {
+                if a.is_closed() {
+                    dist.sf(a.value()) + dist.mass(a.value())
+                } else {
+                    dist.sf(a.value())
+                }
+            });
+            let to = right.map_or(0.0, |b| {
+                if b.is_closed() {
+                    dist.sf(b.value())
+                } else {
+                    dist.sf(b.value()) + dist.mass(b.value())
+                }
+            });
+            Self { upper, from, to }
+        } else {
+            let from = left.map_or(0.0, |a| {
+                if a.is_closed() {
+                    dist.cdf(a.value()) - dist.mass(a.value())
+                } else {
+                    dist.cdf(a.value())
+                }
+            });
+            let to = right.map_or(1.0, |b| {
+                if b.is_closed() {
+                    dist.cdf(b.value())
+                } else {
+                    dist.cdf(b.value()) - dist.mass(b.value())
+                }
+            });
+            Self { upper, from, to }
+        }
+    }
+
+    fn of<T, D: Univariate<T>>(dist: &D, piece: &Interval<T>) -> Self {
+        if piece.is_empty() {
+            return Self {
+                upper: false,
+                from: 0.0,
+                to: 0.0,
+            };
+        }
+        Self::new(dist, piece.left(), piece.right())
+    }
+
+    fn probability(&self) -> f64 {
+        if self.upper {
+            self.from - self.to
+        } else {
+            self.to - self.from
+        }
+        .max(0.0)
+    }
+
+    /// Inverse-transform draw from the distribution restricted to the
+    /// piece.
+    fn draw<T, D: Univariate<T>, R: Rng + ?Sized>(&self, dist: &D, rng: &mut R) -> T {
+        let u: f64 = rng.gen();
+        if self.upper {
+            // In [to, from): `upper_quantile` excludes `from`'s atom.
+            dist.upper_quantile(self.to + (self.from - self.to) * u)
+        } else {
+            // In (from, to]: `quantile` excludes `from`'s atom.
+            dist.quantile(self.to - (self.to - self.from) * u)
+        }
+    }
+}
+
+/// The probability that a random variable falls in a set.
+///
+/// See the [module docs](self) for how pieces are evaluated.
+pub trait Probability<T> {
+    /// `P(X ∈ self)` where `X` is distributed as `dist`.
+    fn probability<D: Univariate<T>>(&self, dist: &D) -> f64;
+}
+
+impl<T> Probability<T> for Interval<T> {
+    fn probability<D: Univariate<T>>(&self, dist: &D) -> f64 {
+        Span::of(dist, self).probability()
+    }
+}
+
+impl<T> Probability<T> for IntervalSet<T> {
+    fn probability<D: Univariate<T>>(&self, dist: &D) -> f64 {
+        let total: f64 = self.iter().map(|p| p.probability(dist)).sum();
+        total.min(1.0)
+    }
+}
+
+/// A distribution conditioned on a set: draws `X` given `X ∈ S`.
+///
+/// A piece is picked with probability proportional to its
+/// [`Probability`], then drawn inside by inverse transform, so no
+/// draws are rejected however unlikely `S` is.
+///
+/// ```
+/// use rand::distributions::Distribution;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use intervalsets::prelude::*;
+/// use intervalsets::probability::{Conditional, Normal};
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// let extremes = IntervalSet::new([Interval::unbound_open(-6.0), Interval::open_unbound(6.0)]);
+/// let dist = Conditional::try_new(Normal::standard(), &extremes).unwrap();
+/// for x in dist.sample_iter(&mut rng).take(100) {
+///     assert!(extremes.contains(&x));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Conditional<T, D> {
+    dist: D,
+    pieces: Vec<(Interval<T>, Span)>,
+    cumulative: Vec<f64>,
+}
+
+/// Draws that land on an excluded endpoint through rounding are
+/// retried at most this many times.
+const MAX_RETRIES: usize = 64;
+
+impl<T: Element + Clone, D: Univariate<T>> Conditional<T, D> {
+    /// Conditions `dist` on `set`.
+    ///
+    /// # Errors
+    ///
+    /// `Error::Math(MathError::Domain)` if `P(X ∈ set)` is zero,
+    /// including when `set` is empty, or is not a finite number (from
+    /// a distribution breaking the [`Univariate`] contract).
+    pub fn try_new(dist: D, set: &IntervalSet<T>) -> Result<Self, Error> {
+        let mut total = 0.0;
+        let mut pieces = Vec::with_capacity(set.slice().len());
+        let mut cumulative = Vec::with_capacity(set.slice().len());
+        for piece in set.iter() {
+            let span = Span::of(&dist, piece);
+            total += span.probability();
+            pieces.push((piece.clone(), span));
+            cumulative.push(total);
+        }
+        if !(total.is_finite() && total > 0.0) {
+            return Err(Error::Math(MathError::Domain));
+        }
+        Ok(Self {
+            dist,
+            pieces,
+            cumulative,
+        })
+    }
+
+    /// The unconditioned distribution.
+    pub fn inner(&self) -> &D {
+        &self.dist
+    }
+
+    /// `P(X ∈ S)`.
+    pub fn probability(&self) -> f64 {
+        self.cumulative.last().copied().unwrap_or_default()
+    }
+}
+
+impl<T: Element + Clone, D: Univariate<T>> TrySample<T> for Conditional<T, D> {
+    /// # Errors
+    ///
+    /// `Error::Math(MathError::Domain)` if the chosen piece holds no
+    /// value the distribution's quantile function reaches, e.g. an open
+    /// interval narrower than the quantile's rounding.
+    fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T, Error> {
+        let u = rng.gen_range(0.0..self.probability());
+        let i = self
+            .cumulative
+            .partition_point(|c| *c <= u)
+            .min(self.pieces.len() - 1);
+        let (piece, span) = &self.pieces[i];
+        for _ in 0..MAX_RETRIES {
+            let x = span.draw(&self.dist, rng);
+            if piece.contains(&x) {
+                return Ok(x);
+            }
+        }
+        Err(Error::Math(MathError::Domain))
+    }
+}
+
+/// # Panics
+///
+/// Panics where [`TrySample::try_sample`] would return an error.
+impl<T: Element + Clone, D: Univariate<T>> Distribution<T> for Conditional<T, D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        self.try_sample(rng)
+            .expect("conditioning piece holds no reachable value")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::factory::traits::*;
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(7)
+    }
+
+    #[test]
+    fn continuous_unbounded_and_tails() {
+        let z = Normal::standard();
+        assert_eq!(Interval::<f64>::unbounded().probability(&z), 1.0);
+        assert_eq!(Interval::unbound_closed(0.0).probability(&z), 0.5);
+        // 1 - cdf(10) would round to 0.
+        assert_relative_eq!(
+            Interval::open_unbound(10.0).probability(&z),
+            7.619853024160527e-24,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            Interval::closed(10.0, 11.0).probability(&z),
+            7.619853024160527e-24 - 1.9106595744986776e-28,
+            max_relative = 1e-9
+        );
+
+        let e = Exp::new(2.0).unwrap();
+        assert_relative_eq!(
+            Interval::closed_unbound(1.0).probability(&e),
+            (-2.0f64).exp()
+        );
+        let u = Uniform::new(0.0, 4.0).unwrap();
+        let s = IntervalSet::new([Interval::closed(-1.0, 1.0), Interval::open(3.0, 10.0)]);
+        assert_relative_eq!(s.probability(&u), 0.5);
+        assert_eq!(IntervalSet::<f64>::empty().probability(&u), 0.0);
+    }
+
+    #[test]
+    fn discrete_endpoints_matter() {
+        let b = Binomial::new(0.5, 4).unwrap();
+        // pmf: 1, 4, 6, 4, 1 over 16.
+        assert_relative_eq!(
+            Interval::closed(1_i64, 3).probability(&b),
+            14.0 / 16.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Interval::open(1_i64, 3).probability(&b),
+            6.0 / 16.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Interval::closed_unbound(3_i64).probability(&b),
+            5.0 / 16.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Interval::unbound_open(0_i64).probability(&b),
+            0.0,
+            epsilon = 1e-12
+        );
+        let s = IntervalSet::new([Interval::singleton(0_i64), Interval::closed(4, 100)]);
+        assert_relative_eq!(s.probability(&b), 2.0 / 16.0, epsilon = 1e-12);
+
+        let p = Poisson::new(1.0).unwrap();
+        assert!(Interval::closed_unbound(30_i64).probability(&p) > 0.0);
+    }
+
+    #[test]
+    fn empirical_atoms() {
+        let e = Empirical::try_new([1_i64, 2, 2, 3]).unwrap();
+        assert_eq!(Interval::closed(2_i64, 3).probability(&e), 0.75);
+        assert_eq!(Interval::closed_unbound(3_i64).probability(&e), 0.25);
+        let f = Empirical::try_new([0.5, 1.0, 1.0, 2.0]).unwrap();
+        assert_eq!(Interval::closed_open(0.5, 1.0).probability(&f), 0.25);
+        assert_eq!(Interval::open_closed(0.5, 1.0).probability(&f), 0.5);
+        assert_eq!(
+            Empirical::try_new([1.0, f64::NAN]),
+            Err(Error::InvalidElement)
+        );
+        assert_eq!(
+            Empirical::<f64>::try_new([]),
+            Err(Error::Math(MathError::Domain))
+        );
+    }
+
+    #[test]
+    fn conditional_stays_in_set() {
+        let mut rng = rng();
+        let set = IntervalSet::new([Interval::unbound_open(-8.0), Interval::open(1.0, 1.5)]);
+        let dist = Conditional::try_new(Normal::standard(), &set).unwrap();
+        for x in (&dist).sample_iter(&mut rng).take(1000) {
+            assert!(set.contains(&x), "{x}");
+        }
+
+        let set = IntervalSet::new([Interval::open(1_i64, 3), Interval::closed_unbound(40)]);
+        let dist = Conditional::try_new(Poisson::new(2.0).unwrap(), &set).unwrap();
+        for x in (&dist).sample_iter(&mut rng).take(1000) {
+            assert!(set.contains(&x), "{x}");
+        }
+        // Deep in the tail, where `cdf` has rounded to 1.
+        let tail = IntervalSet::from(Interval::closed_unbound(40_i64));
+        let dist = Conditional::try_new(Poisson::new(2.0).unwrap(), &tail).unwrap();
+        assert!(dist.probability() > 0.0);
+        for x in (&dist).sample_iter(&mut rng).take(100) {
+            assert!((40..50).contains(&x), "{x}");
+        }
+    }
+
+    #[test]
+    fn conditional_matches_weights() {
+        let mut rng = rng();
+        let b = Binomial::new(0.5, 4).unwrap();
+        let set = IntervalSet::new([Interval::singleton(0_i64), Interval::closed(3, 4)]);
+        let dist = Conditional::try_new(b, &set).unwrap();
+        // P(0 | S) = 1 / 6.
+        let zeros = (&dist)
+            .sample_iter(&mut rng)
+            .take(6000)
+            .filter(|&x| x == 0)
+            .count();
+        assert!((850..1150).contains(&zeros), "{zeros}");
+    }
+
+    #[test]
+    fn conditional_on_null_set_is_error() {
+        let u = Uniform::new(0.0, 1.0).unwrap();
+        let set = IntervalSet::from(Interval::closed(2.0, 3.0));
+        assert!(matches!(
+            Conditional::try_new(u, &set),
+            Err(Error::Math(MathError::Domain))
+        ));
+        assert!(Conditional::try_new(u, &IntervalSet::empty()).is_err());
+    }
+
+    #[test]
+    fn conditional_on_broken_probability_is_error() {
+        /// Jumps from 0 to `self.0` at 0.
+        struct Broken(f64);
+        impl Univariate<f64> for Broken {
+            fn cdf(&self, x: &f64) -> f64 {
+                if *x > 0.0 {
+                    self.0
+                } else {
+                    0.0
+                }
+            }
+            fn sf(&self, x: &f64) -> f64 {
+                1.0 - self.cdf(x)
+            }
+            fn mass(&self, _: &f64) -> f64 {
+                0.0
+            }
+            fn quantile(&self, _: f64) -> f64 {
+                0.0
+            }
+            fn upper_quantile(&self, _: f64) -> f64 {
+                0.0
+            }
+        }
+        let set = IntervalSet::from(Interval::closed(0.0, 1.0));
+        for value in [f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                Conditional::try_new(Broken(value), &set),
+                Err(Error::Math(MathError::Domain))
+            ));
+        }
+    }
+
+    #[quickcheck]
+    fn check_probability_is_additive(a: i8, b: i8, c: i8, d: i8) -> bool {
+        let dist = Binomial::new(0.3, 20).unwrap();
+        let [a, b, c, d] = [a, b, c, d].map(|x| i64::from(x % 25));
+        let set = IntervalSet::new([
+            Interval::closed(a.min(b), a.max(b)),
+            Interval::open(c.min(d), c.max(d) + 2),
+        ]);
+        let expected: f64 = (0..=20)
+            .filter(|k| set.contains(k))
+            .map(|k| dist.pmf(k as u64))
+            .sum();
+        (set.probability(&dist) - expected).abs() < 1e-12
+    }
+}
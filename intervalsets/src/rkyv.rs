@@ -0,0 +1,415 @@
+//! Zero-copy archives of sets (feature `rkyv`).
+//!
+//! [`Interval`] and [`IntervalSet`] implement rkyv's `Archive`,
+//! `Serialize` and `Deserialize`, as do the core bound and interval
+//! types they are built from. `Deserialize` goes through the validating
+//! constructors, as serde does, so `rkyv::from_bytes` rejects a crossed
+//! or NaN bound, an empty piece, or pieces out of order.
+//!
+//! rkyv's own validation, which `rkyv::access` runs, only checks that
+//! an archive is well formed. [`SetView::try_new`] checks the
+//! [`IntervalSet` invariants](IntervalSet#invariants) on the archived
+//! data without building the set, after which the view answers queries
+//! in place, decoding only the pieces a query touches. [`access`] does
+//! both checks at once.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::rkyv::{access, from_bytes};
+//!
+//! let set = Interval::closed(0, 10).union(Interval::closed(20, 30));
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&set).unwrap();
+//!
+//! let view = access::<i32>(&bytes).unwrap();
+//! assert_eq!(view.len(), 2);
+//! assert!(view.contains(&25));
+//! assert!(!view.contains(&15));
+//! assert_eq!(view.find(&5), Some(Interval::closed(0, 10)));
+//! assert_eq!(view.measure().finite(), 22);
+//!
+//! assert_eq!(from_bytes::<i32>(&bytes).unwrap(), set);
+//! ```
+
+use core::any::Any;
+use core::fmt;
+use core::mem;
+
+use intervalsets_core::error::Error as CoreError;
+use intervalsets_core::measure::{Extent, Measure};
+use rkyv::api::high::HighValidator;
+use rkyv::api::low::{deserialize, LowDeserializer};
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::{self, Source, Trace};
+use rkyv::{Archive, Deserialize};
+
+use crate::bound::ord::{FiniteOrdBound, FiniteOrdBoundKind, OrdBound, OrdBounded};
+use crate::error::{Error, MathError, TotalOrderError};
+use crate::numeric::{Element, Zero};
+use crate::ops::{Contains, Intersects, TryAdd};
+pub use crate::sets::{ArchivedInterval, ArchivedIntervalSet};
+use crate::{Interval, IntervalSet, MaybeEmpty};
+
+/// Lets [`Error`] be the error of an rkyv deserializer, so that
+/// decoding a set reports why it was rejected. Errors of this crate
+/// and [`TotalOrderError`] keep their meaning; any other error, such
+/// as a failure inside rkyv, becomes [`Error::Archive`].
+impl Trace for Error {
+    fn trace<R>(self, _: R) -> Self
+    where
+        R: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+        self
+    }
+}
+
+impl Source for Error {
+    fn new<E: core::error::Error + Send + Sync + 'static>(source: E) -> Self {
+        let source: &dyn Any = &source;
+        if let Some(e) = source.downcast_ref::<Error>() {
+            e.clone()
+        } else if let Some(&e) = source.downcast_ref::<CoreError>() {
+            e.into()
+        } else if source.is::<TotalOrderError>() {
+            Error::InvalidElement
+        } else {
+            Error::Archive
+        }
+    }
+}
+
+/// An [`Element`] whose archived form decodes with [`Error`] as the
+/// deserializer's error, as every primitive's does.
+pub trait ArchivedElement:
+    Element + Archive<Archived: Deserialize<Self, LowDeserializer<Error>>>
+{
+}
+
+impl<T> ArchivedElement for T
+where
+    T: Element + Archive,
+    T::Archived: Deserialize<T, LowDeserializer<Error>>,
+{
+}
+
+/// Decodes `piece` through the validating constructors.
+fn decode<T: ArchivedElement>(piece: &ArchivedInterval<T>) -> Result<Interval<T>, Error> {
+    deserialize::<Interval<T>, Error>(piece)
+}
+
+/// Decodes a piece of a view, which [`SetView::try_new`] has checked.
+fn decode_checked<T: ArchivedElement>(piece: &ArchivedInterval<T>) -> Interval<T> {
+    decode(piece).expect("checked in SetView::try_new")
+}
+
+/// A read-only view of an [`ArchivedIntervalSet`] whose invariants
+/// have been checked.
+///
+/// Lookups binary search the archived pieces, so [`find`](Self::find),
+/// [`Contains`] and [`Intersects`] decode O(log n) of them.
+pub struct SetView<'a, T: Archive> {
+    pieces: &'a [ArchivedInterval<T>],
+}
+
+impl<T: Archive> Clone for SetView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Archive> Copy for SetView<'_, T> {}
+
+impl<T: ArchivedElement + fmt::Debug> fmt::Debug for SetView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: ArchivedElement> SetView<'a, T> {
+    /// Checks that `set` is an `IntervalSet` in canonical form.
+    ///
+    /// Every piece must decode, which runs it through the validating
+    /// constructors as `Deserialize` does, and the pieces must satisfy
+    /// [`IntervalSet::satisfies_invariants`].
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidElement`] for a rejected bound value,
+    /// [`Error::InvalidBoundPair`] for crossed bounds, and
+    /// [`Error::InvalidIntervalSet`] for an empty piece or pieces out
+    /// of order or connected.
+    pub fn try_new(set: &'a ArchivedIntervalSet<T>) -> Result<Self, Error> {
+        let pieces = set.slice();
+        let mut window = [Interval::empty(), Interval::empty()];
+        for (i, piece) in pieces.iter().enumerate() {
+            window[0] = mem::replace(&mut window[1], decode(piece)?);
+            let checked = if i == 0 { &window[1..] } else { &window[..] };
+            if !IntervalSet::satisfies_invariants(checked) {
+                return Err(Error::InvalidIntervalSet);
+            }
+        }
+        Ok(Self { pieces })
+    }
+
+    /// The number of pieces.
+    pub fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    /// True if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    /// The piece at `index`, decoded.
+    pub fn get(&self, index: usize) -> Option<Interval<T>> {
+        self.pieces.get(index).map(decode_checked)
+    }
+
+    /// Decodes the pieces in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + 'a {
+        self.pieces.iter().map(decode_checked)
+    }
+
+    /// Decodes the whole set.
+    pub fn to_set(&self) -> IntervalSet<T> {
+        IntervalSet::new_assume_valid(self.iter())
+    }
+
+    /// The index of the first piece that does not end before `left`.
+    fn partition(&self, left: &OrdBound<&T>) -> usize {
+        self.pieces
+            .partition_point(|p| decode_checked(p).ord_bound_pair().into_raw().1 < *left)
+    }
+
+    /// The index of the piece containing `value`, or `None` if no piece
+    /// does. O(log n).
+    pub fn position(&self, value: &T) -> Option<usize> {
+        let i = self.partition(&OrdBound::Finite(FiniteOrdBound(
+            value,
+            FiniteOrdBoundKind::Closed,
+        )));
+        self.get(i).filter(|p| p.contains(value)).map(|_| i)
+    }
+
+    /// The piece containing `value`, decoded. O(log n).
+    pub fn find(&self, value: &T) -> Option<Interval<T>> {
+        self.position(value).and_then(|i| self.get(i))
+    }
+}
+
+impl<T: ArchivedElement> Contains<&T> for SetView<'_, T> {
+    fn contains(&self, rhs: &T) -> bool {
+        self.position(rhs).is_some()
+    }
+}
+
+impl<T: ArchivedElement> Contains<&Interval<T>> for SetView<'_, T> {
+    fn contains(&self, rhs: &Interval<T>) -> bool {
+        if rhs.is_empty() {
+            return !self.is_empty();
+        }
+        let i = self.partition(&rhs.ord_bound_pair().into_raw().0);
+        self.get(i).is_some_and(|p| p.contains(rhs))
+    }
+}
+
+impl<T: ArchivedElement> Intersects<&Interval<T>> for SetView<'_, T> {
+    fn intersects(&self, rhs: &Interval<T>) -> bool {
+        if rhs.is_empty() {
+            return false;
+        }
+        let i = self.partition(&rhs.ord_bound_pair().into_raw().0);
+        self.get(i).is_some_and(|p| p.intersects(rhs))
+    }
+}
+
+impl<T> Measure for SetView<'_, T>
+where
+    T: ArchivedElement,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+{
+    type Output = T::Measure;
+    type Error = MathError;
+
+    /// Sums per-piece measures, as for [`IntervalSet`].
+    fn try_measure(&self) -> Result<Extent<Self::Output>, Self::Error> {
+        self.iter().try_fold(
+            Extent::Finite(<T::Measure as Zero>::zero()),
+            |accum, subset| {
+                accum.try_binop_map(subset.try_measure()?, |a, b| {
+                    a.try_add(b).map_err(Into::into)
+                })
+            },
+        )
+    }
+}
+
+/// Validates `bytes` as an archived `IntervalSet<T>` and checks its
+/// invariants, as by [`SetView::try_new`].
+///
+/// `bytes` must be aligned as rkyv requires, e.g. the `AlignedVec`
+/// returned by `rkyv::to_bytes`.
+pub fn access<T>(bytes: &[u8]) -> Result<SetView<'_, T>, rancor::Error>
+where
+    T: ArchivedElement,
+    ArchivedIntervalSet<T>: for<'a> CheckBytes<HighValidator<'a, rancor::Error>>,
+{
+    let set = rkyv::access::<ArchivedIntervalSet<T>, rancor::Error>(bytes)?;
+    SetView::try_new(set).map_err(rancor::Error::new)
+}
+
+/// Validates and decodes an archived `IntervalSet<T>`, like
+/// `rkyv::from_bytes`.
+pub fn from_bytes<T>(bytes: &[u8]) -> Result<IntervalSet<T>, rancor::Error>
+where
+    T: ArchivedElement,
+    ArchivedIntervalSet<T>: for<'a> CheckBytes<HighValidator<'a, rancor::Error>>,
+{
+    access(bytes).map(|view| view.to_set())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::Union;
+
+    type Serializer<'a> = rkyv::api::high::HighSerializer<
+        rkyv::util::AlignedVec,
+        rkyv::ser::allocator::ArenaHandle<'a>,
+        rancor::Error,
+    >;
+
+    fn archive<T>(set: &IntervalSet<T>) -> rkyv::util::AlignedVec
+    where
+        T: Element + for<'a> rkyv::Serialize<Serializer<'a>>,
+    {
+        rkyv::to_bytes::<rancor::Error>(set).unwrap()
+    }
+
+    #[test]
+    fn view_answers_queries() {
+        let set = Interval::unbound_open(0.0)
+            .union(Interval::closed(1.0, 2.0))
+            .union(Interval::open_unbound(5.0));
+        let bytes = archive(&set);
+        let view = access::<f64>(&bytes).unwrap();
+
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.iter().collect::<Vec<_>>(), set.slice());
+        assert_eq!(view.position(&-1.0), Some(0));
+        assert_eq!(view.position(&0.0), None);
+        assert_eq!(view.find(&2.0), Some(Interval::closed(1.0, 2.0)));
+        assert_eq!(view.position(&5.0), None);
+        assert_eq!(view.position(&5.5), Some(2));
+
+        assert!(view.contains(&Interval::closed(1.0, 1.5)));
+        assert!(!view.contains(&Interval::closed(1.5, 3.0)));
+        assert!(view.intersects(&Interval::closed(3.0, 6.0)));
+        assert!(!view.intersects(&Interval::closed(3.0, 5.0)));
+        assert!(!view.intersects(&Interval::empty()));
+        assert_eq!(view.measure(), Extent::Infinite);
+        assert_eq!(from_bytes::<f64>(&bytes).unwrap(), set);
+    }
+
+    #[test]
+    fn view_of_empty_set() {
+        let bytes = archive(&IntervalSet::<i32>::empty());
+        let view = access::<i32>(&bytes).unwrap();
+        assert!(view.is_empty());
+        assert!(!view.contains(&0));
+        assert!(!view.contains(&Interval::empty()));
+        assert_eq!(view.measure(), Extent::Finite(0));
+        assert_eq!(format!("{view:?}"), "[]");
+    }
+
+    #[test]
+    fn access_rejects_non_canonical() {
+        let cases = [
+            (
+                vec![Interval::closed(5, 6), Interval::closed(0, 1)],
+                Error::InvalidIntervalSet,
+            ),
+            (
+                vec![Interval::closed(0, 4), Interval::closed(5, 6)],
+                Error::InvalidIntervalSet,
+            ),
+            (
+                vec![Interval::closed(0, 1), Interval::empty()],
+                Error::InvalidIntervalSet,
+            ),
+        ];
+        for (pieces, expected) in cases {
+            let bytes = archive(&IntervalSet::new_assume_valid(pieces));
+            let set = rkyv::access::<ArchivedIntervalSet<i32>, rancor::Error>(&bytes).unwrap();
+            assert_eq!(SetView::try_new(set).unwrap_err(), expected);
+            assert!(access::<i32>(&bytes).is_err());
+            assert!(from_bytes::<i32>(&bytes).is_err());
+            assert!(rkyv::from_bytes::<IntervalSet<i32>, rancor::Error>(&bytes).is_err());
+            assert_eq!(
+                rkyv::from_bytes::<IntervalSet<i32>, Error>(&bytes).unwrap_err(),
+                expected
+            );
+        }
+    }
+
+    fn patched<T: Copy>(bytes: &mut [u8], from: T, to: T, encode: fn(T) -> [u8; 8]) {
+        let at = bytes.windows(8).position(|w| w == encode(from)).unwrap();
+        bytes[at..at + 8].copy_from_slice(&encode(to));
+    }
+
+    #[test]
+    fn access_rejects_invalid_bounds() {
+        let cases = [
+            (1000.0, 3000.0, Error::InvalidBoundPair),
+            (1000.0, f64::NAN, Error::InvalidElement),
+        ];
+        for (from, to, expected) in cases {
+            let mut bytes = archive(&IntervalSet::from(Interval::closed(1000.0, 2000.0)));
+            patched(&mut bytes, from, to, f64::to_le_bytes);
+
+            let set = rkyv::access::<ArchivedIntervalSet<f64>, rancor::Error>(&bytes).unwrap();
+            assert_eq!(SetView::try_new(set).unwrap_err(), expected);
+            assert!(rkyv::from_bytes::<IntervalSet<f64>, rancor::Error>(&bytes).is_err());
+            assert_eq!(
+                rkyv::from_bytes::<IntervalSet<f64>, Error>(&bytes).unwrap_err(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn foreign_errors_are_archive_errors() {
+        let mut bytes = archive(&IntervalSet::from(Interval::closed(0, 10)));
+        let len = bytes.len();
+        bytes[len - 8..].fill(0xff);
+        assert_eq!(
+            rkyv::from_bytes::<IntervalSet<i32>, Error>(&bytes).unwrap_err(),
+            Error::Archive
+        );
+
+        assert_eq!(<Error as Source>::new(fmt::Error), Error::Archive);
+        assert_eq!(
+            <Error as Source>::new(TotalOrderError),
+            Error::InvalidElement
+        );
+        assert_eq!(
+            <Error as Source>::new(CoreError::InvalidBoundPair),
+            Error::InvalidBoundPair
+        );
+    }
+
+    #[quickcheck]
+    fn check_view_agrees_with_set(set: IntervalSet<i32>, x: i32, a: i16, b: i16) {
+        let bytes = archive(&set);
+        let view = access::<i32>(&bytes).unwrap();
+        let probe = Interval::closed(i32::from(a.min(b)), i32::from(a.max(b)));
+
+        assert_eq!(view.to_set(), set);
+        assert_eq!(view.contains(&x), set.contains(&x));
+        assert_eq!(view.find(&x), set.iter().find(|p| p.contains(&x)).cloned());
+        assert_eq!(view.contains(&probe), set.contains(&probe));
+        assert_eq!(view.intersects(&probe), set.intersects(&probe));
+        assert_eq!(view.try_measure(), set.try_measure());
+    }
+}
@@ -0,0 +1,472 @@
+//! Interop with roaring bitmaps (feature `roaring`).
+//!
+//! An [`IntervalSet<u32>`] converts to and from a [`RoaringBitmap`],
+//! and an [`IntervalSet<u64>`] to and from a [`RoaringTreemap`], with
+//! `From`, except that a set converts to a treemap with `TryFrom`.
+//! Both directions work a run at a time: pieces are inserted as ranges
+//! and the bitmap optimized to run containers, and runs are read back
+//! as pieces, so a set is never expanded element by element.
+//!
+//! The element type is an integer, so bounds at the ends of its range
+//! come back finite: `(.., 5]` round-trips as `[0, 5]`. A treemap holds
+//! one bitmap per block of 2³² values a set touches, each up to half a
+//! megabyte of runs, so a set touching more than
+//! [`MAX_TREEMAP_BLOCKS`] blocks, such as `[0, ..)`, is refused rather
+//! than exhausting memory.
+//!
+//! Intersecting a set with a bitmap gives an [`Overlap`], which answers
+//! [`Measure`] from the bitmap's cardinality over each piece without
+//! building either side, and converts to a set or a bitmap on demand.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::measure::Extent;
+//! use roaring::RoaringBitmap;
+//!
+//! let active: RoaringBitmap = (0..1_000).chain(5_000..6_000).collect();
+//! let window = IntervalSet::from(Interval::closed(500_u32, 5_499));
+//!
+//! let both = (&window).intersection(&active);
+//! assert_eq!(both.measure(), Extent::Finite(1_000));
+//! assert_eq!(
+//!     both.to_set(),
+//!     Interval::closed(500, 999).union(Interval::closed(5_000, 5_499))
+//! );
+//! assert!(window.intersects(&active));
+//!
+//! let back = IntervalSet::from(&RoaringBitmap::from(&window));
+//! assert_eq!(back, window);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use ::roaring::{RoaringBitmap, RoaringTreemap};
+use intervalsets_core::measure::{Extent, Measure};
+use num_traits::{Bounded, CheckedAdd, CheckedSub, One};
+
+use crate::bound::{BoundType, SetBounds};
+use crate::error::MathError;
+use crate::factory::traits::*;
+use crate::numeric::{Element, Zero};
+use crate::ops::{Intersection, Intersects, TryAdd};
+use crate::{Interval, IntervalSet, MaybeEmpty};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A roaring bitmap: [`RoaringBitmap`] of `u32` or [`RoaringTreemap`]
+/// of `u64`.
+pub trait Bitmap: private::Sealed + Sized {
+    /// The integer type the bitmap holds.
+    type Element: Element + Copy + Ord + fmt::Debug + Bounded + CheckedAdd + CheckedSub + One + Zero;
+
+    /// Calls `f` with the runs of the bitmap within `[lo, hi]`, in
+    /// ascending order. Runs that touch may be split.
+    #[doc(hidden)]
+    fn runs(
+        &self,
+        lo: Self::Element,
+        hi: Self::Element,
+        f: &mut dyn FnMut(Self::Element, Self::Element),
+    );
+
+    /// The number of members within `[lo, hi]`.
+    #[doc(hidden)]
+    fn count(&self, lo: Self::Element, hi: Self::Element) -> u64;
+
+    /// Builds a bitmap of run containers from ascending runs.
+    #[doc(hidden)]
+    fn from_runs(runs: impl IntoIterator<Item = (Self::Element, Self::Element)>) -> Self;
+}
+
+impl private::Sealed for RoaringBitmap {}
+
+impl Bitmap for RoaringBitmap {
+    type Element = u32;
+
+    fn runs(&self, lo: u32, hi: u32, f: &mut dyn FnMut(u32, u32)) {
+        let mut iter = self.range(lo..=hi);
+        while let Some(run) = iter.next_range() {
+            f(*run.start(), *run.end());
+        }
+    }
+
+    fn count(&self, lo: u32, hi: u32) -> u64 {
+        self.range_cardinality(lo..=hi)
+    }
+
+    fn from_runs(runs: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut bitmap = RoaringBitmap::new();
+        for (lo, hi) in runs {
+            bitmap.insert_range(lo..=hi);
+        }
+        bitmap.optimize();
+        bitmap
+    }
+}
+
+fn split(value: u64) -> (u32, u32) {
+    ((value >> 32) as u32, value as u32)
+}
+
+fn join(high: u32, low: u32) -> u64 {
+    (u64::from(high) << 32) | u64::from(low)
+}
+
+impl private::Sealed for RoaringTreemap {}
+
+/// A treemap is one [`RoaringBitmap`] per block of 2³² values, so runs
+/// are read and written a block at a time.
+impl Bitmap for RoaringTreemap {
+    type Element = u64;
+
+    fn runs(&self, lo: u64, hi: u64, f: &mut dyn FnMut(u64, u64)) {
+        let ((lo_high, lo_low), (hi_high, hi_low)) = (split(lo), split(hi));
+        let blocks = self
+            .bitmaps()
+            .skip_while(|(high, _)| *high < lo_high)
+            .take_while(|(high, _)| *high <= hi_high);
+        for (high, bitmap) in blocks {
+            let from = if high == lo_high { lo_low } else { 0 };
+            let to = if high == hi_high { hi_low } else { u32::MAX };
+            bitmap.runs(from, to, &mut |a, b| f(join(high, a), join(high, b)));
+        }
+    }
+
+    fn count(&self, lo: u64, hi: u64) -> u64 {
+        self.rank(hi) - lo.checked_sub(1).map_or(0, |below| self.rank(below))
+    }
+
+    fn from_runs(runs: impl IntoIterator<Item = (u64, u64)>) -> Self {
+        let mut blocks = BTreeMap::<u32, RoaringBitmap>::new();
+        for (lo, hi) in runs {
+            let mut start = lo;
+            loop {
+                let (high, low) = split(start);
+                let end = hi.min(join(high, u32::MAX));
+                blocks
+                    .entry(high)
+                    .or_default()
+                    .insert_range(low..=split(end).1);
+                if end == hi {
+                    break;
+                }
+                start = end + 1;
+            }
+        }
+        for bitmap in blocks.values_mut() {
+            bitmap.optimize();
+        }
+        RoaringTreemap::from_bitmaps(blocks)
+    }
+}
+
+/// The closed range of the integers in `piece`, or `None` if it has
+/// none.
+fn closed<T: Bitmap>(piece: &Interval<T::Element>) -> Option<(T::Element, T::Element)> {
+    if piece.is_empty() {
+        return None;
+    }
+    let lo = match piece.left() {
+        None => T::Element::min_value(),
+        Some(b) if b.bound_type() == BoundType::Closed => *b.value(),
+        Some(b) => b.value().checked_add(&One::one())?,
+    };
+    let hi = match piece.right() {
+        None => T::Element::max_value(),
+        Some(b) if b.bound_type() == BoundType::Closed => *b.value(),
+        Some(b) => b.value().checked_sub(&One::one())?,
+    };
+    Some((lo, hi))
+}
+
+/// The runs of `bitmap` within `ranges`, with touching runs joined.
+fn runs_within<B: Bitmap>(
+    bitmap: &B,
+    ranges: impl IntoIterator<Item = (B::Element, B::Element)>,
+) -> Vec<(B::Element, B::Element)> {
+    let mut runs: Vec<(B::Element, B::Element)> = Vec::new();
+    for (lo, hi) in ranges {
+        bitmap.runs(lo, hi, &mut |a, b| match runs.last_mut() {
+            Some(last) if last.1.checked_add(&One::one()) == Some(a) => last.1 = b,
+            _ => runs.push((a, b)),
+        });
+    }
+    runs
+}
+
+fn to_set<T: Element + Copy>(runs: Vec<(T, T)>) -> IntervalSet<T> {
+    IntervalSet::new_assume_valid(runs.into_iter().map(|(a, b)| Interval::closed(a, b)))
+}
+
+fn set_of<B: Bitmap>(bitmap: &B) -> IntervalSet<B::Element> {
+    let all = (B::Element::min_value(), B::Element::max_value());
+    to_set(runs_within(bitmap, [all]))
+}
+
+fn bitmap_of<B: Bitmap>(set: &IntervalSet<B::Element>) -> B {
+    B::from_runs(set.iter().filter_map(closed::<B>))
+}
+
+impl From<&IntervalSet<u32>> for RoaringBitmap {
+    fn from(set: &IntervalSet<u32>) -> Self {
+        bitmap_of(set)
+    }
+}
+
+impl From<&RoaringBitmap> for IntervalSet<u32> {
+    fn from(bitmap: &RoaringBitmap) -> Self {
+        set_of(bitmap)
+    }
+}
+
+/// The most blocks of 2³² values a set may touch to convert to a
+/// [`RoaringTreemap`]: 2⁴⁰ values, or up to 128 MiB of runs.
+pub const MAX_TREEMAP_BLOCKS: u64 = 1 << 8;
+
+/// The number of blocks of 2³² values that ascending, disjoint `runs`
+/// touch.
+fn blocks(runs: &[(u64, u64)]) -> u64 {
+    let mut count = 0;
+    let mut last = None;
+    for &(lo, hi) in runs {
+        let (first, end) = (split(lo).0, split(hi).0);
+        let shared = u64::from(last == Some(first));
+        count += u64::from(end - first) + 1 - shared;
+        last = Some(end);
+    }
+    count
+}
+
+impl TryFrom<&IntervalSet<u64>> for RoaringTreemap {
+    type Error = MathError;
+
+    /// # Errors
+    ///
+    /// [`MathError::Range`] if `set` touches more than
+    /// [`MAX_TREEMAP_BLOCKS`] blocks of 2³² values.
+    fn try_from(set: &IntervalSet<u64>) -> Result<Self, MathError> {
+        let runs: Vec<_> = set.iter().filter_map(closed::<Self>).collect();
+        if blocks(&runs) > MAX_TREEMAP_BLOCKS {
+            return Err(MathError::Range);
+        }
+        Ok(Self::from_runs(runs))
+    }
+}
+
+impl From<&RoaringTreemap> for IntervalSet<u64> {
+    fn from(bitmap: &RoaringTreemap) -> Self {
+        set_of(bitmap)
+    }
+}
+
+/// The intersection of an [`IntervalSet`] and a [`Bitmap`], evaluated
+/// against the bitmap one piece at a time.
+pub struct Overlap<'a, B: Bitmap> {
+    set: &'a IntervalSet<B::Element>,
+    bitmap: &'a B,
+}
+
+impl<B: Bitmap> Clone for Overlap<'_, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B: Bitmap> Copy for Overlap<'_, B> {}
+
+impl<B: Bitmap> fmt::Debug for Overlap<'_, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Overlap")
+            .field("set", self.set)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B: Bitmap> Overlap<'_, B> {
+    fn ranges(&self) -> impl Iterator<Item = (B::Element, B::Element)> + '_ {
+        self.set.iter().filter_map(closed::<B>)
+    }
+
+    /// The intersection as a set.
+    pub fn to_set(&self) -> IntervalSet<B::Element> {
+        to_set(runs_within(self.bitmap, self.ranges()))
+    }
+
+    /// The intersection as a bitmap.
+    pub fn to_bitmap(&self) -> B {
+        B::from_runs(runs_within(self.bitmap, self.ranges()))
+    }
+}
+
+impl<B> Measure for Overlap<'_, B>
+where
+    B: Bitmap,
+    <B::Element as Element>::Measure: From<u64>,
+    <<B::Element as Element>::Measure as TryAdd>::Error: Into<MathError>,
+{
+    type Output = <B::Element as Element>::Measure;
+    type Error = MathError;
+
+    /// Sums the bitmap's cardinality over each piece.
+    fn try_measure(&self) -> Result<Extent<Self::Output>, Self::Error> {
+        self.ranges()
+            .try_fold(<Self::Output as Zero>::zero(), |accum, (lo, hi)| {
+                accum
+                    .try_add(self.bitmap.count(lo, hi).into())
+                    .map_err(Into::into)
+            })
+            .map(Extent::Finite)
+    }
+}
+
+impl<'a, B: Bitmap> Intersection<&'a B> for &'a IntervalSet<B::Element> {
+    type Output = Overlap<'a, B>;
+
+    fn intersection(self, rhs: &'a B) -> Self::Output {
+        Overlap {
+            set: self,
+            bitmap: rhs,
+        }
+    }
+}
+
+impl<B: Bitmap> Intersects<&B> for IntervalSet<B::Element> {
+    fn intersects(&self, rhs: &B) -> bool {
+        self.iter()
+            .filter_map(closed::<B>)
+            .any(|(lo, hi)| rhs.count(lo, hi) > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use super::*;
+    use crate::ops::Union;
+
+    /// Scaled so pieces span a handful of containers rather than
+    /// thousands.
+    fn set_u32(pairs: Vec<(u16, u16)>) -> IntervalSet<u32> {
+        pairs
+            .into_iter()
+            .map(|(a, b)| (u32::from(a.min(b)) * 97, u32::from(a.max(b)) * 97))
+            .map(|(a, b)| Interval::closed(a, b))
+            .fold(IntervalSet::empty(), |acc, x| acc.union(x))
+    }
+
+    #[test]
+    fn test_bitmap_round_trip() {
+        let set = Interval::closed(3_u32, 9)
+            .union(Interval::closed(20, 20))
+            .union(Interval::closed(u32::MAX - 4, u32::MAX));
+        let bitmap = RoaringBitmap::from(&set);
+        assert_eq!(bitmap.len(), 7 + 1 + 5);
+        assert_eq!(IntervalSet::from(&bitmap), set);
+
+        let empty = IntervalSet::<u32>::empty();
+        assert!(RoaringBitmap::from(&empty).is_empty());
+        assert_eq!(IntervalSet::from(&RoaringBitmap::new()), empty);
+    }
+
+    #[test]
+    fn test_unbounded_ends_come_back_finite() {
+        let set = IntervalSet::from(Interval::unbound_closed(5_u32));
+        let back = IntervalSet::from(&RoaringBitmap::from(&set));
+        assert_eq!(back, IntervalSet::from(Interval::closed(0, 5)));
+
+        let set = IntervalSet::from(Interval::closed_unbound(u64::MAX - 5));
+        let back = IntervalSet::from(&RoaringTreemap::try_from(&set).unwrap());
+        assert_eq!(
+            back,
+            IntervalSet::from(Interval::closed(u64::MAX - 5, u64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_treemap_runs_cross_blocks() {
+        let edge = 1_u64 << 32;
+        let set =
+            Interval::closed(edge - 10, edge + 10).union(Interval::closed(3 * edge - 1, 3 * edge));
+        let treemap = RoaringTreemap::try_from(&set).unwrap();
+        assert_eq!(treemap.len(), 21 + 2);
+        assert_eq!(IntervalSet::from(&treemap), set);
+
+        let window = IntervalSet::from(Interval::closed(edge - 2, 3 * edge - 1));
+        let both = (&window).intersection(&treemap);
+        assert_eq!(both.measure(), Extent::Finite(13 + 1));
+        assert_eq!(
+            both.to_set(),
+            Interval::closed(edge - 2, edge + 10)
+                .union(Interval::closed(3 * edge - 1, 3 * edge - 1))
+        );
+        assert_eq!(IntervalSet::from(&both.to_bitmap()), both.to_set());
+    }
+
+    #[test]
+    fn test_large_ranges_stay_runs() {
+        let set = IntervalSet::from(Interval::closed(0_u32, u32::MAX));
+        let bitmap = RoaringBitmap::from(&set);
+        assert_eq!(bitmap.len(), 1 << 32);
+        // bitset containers would need 512 MiB; runs fit in well under 1 MiB.
+        assert!(bitmap.serialized_size() < 1 << 20);
+        assert_eq!(IntervalSet::from(&bitmap), set);
+
+        let huge = IntervalSet::from(Interval::closed(1_u64 << 40, (1 << 40) + (1 << 34)));
+        let treemap = RoaringTreemap::try_from(&huge).unwrap();
+        assert!(treemap.serialized_size() < treemap.bitmaps().count() << 20);
+        assert_eq!(IntervalSet::from(&treemap), huge);
+        assert_eq!(
+            (&huge).intersection(&treemap).measure(),
+            Extent::Finite((1 << 34) + 1)
+        );
+    }
+
+    #[test]
+    fn test_treemap_width_limit() {
+        let unbounded = IntervalSet::from(Interval::<u64>::unbounded());
+        assert_eq!(RoaringTreemap::try_from(&unbounded), Err(MathError::Range));
+
+        let block = 1_u64 << 32;
+        let widest = (block, (MAX_TREEMAP_BLOCKS + 1) * block - 1);
+        assert_eq!(blocks(&[widest]), MAX_TREEMAP_BLOCKS);
+        let over = Interval::singleton(0).union(Interval::closed(widest.0, widest.1));
+        assert_eq!(RoaringTreemap::try_from(&over), Err(MathError::Range));
+        // Pieces sharing a block count it once.
+        let spread: Vec<_> = (0..2 * MAX_TREEMAP_BLOCKS)
+            .map(|i| (i * 7, i * 7 + 3))
+            .collect();
+        assert_eq!(blocks(&spread), 1);
+        assert_eq!(blocks(&[(0, block), (block + 5, 3 * block)]), 4);
+    }
+
+    #[test]
+    fn test_intersects() {
+        let bitmap: RoaringBitmap = [10, 20].into_iter().collect();
+        assert!(IntervalSet::from(Interval::closed(5_u32, 10)).intersects(&bitmap));
+        assert!(!IntervalSet::from(Interval::open(10_u32, 20)).intersects(&bitmap));
+        assert!(!IntervalSet::<u32>::empty().intersects(&bitmap));
+    }
+
+    #[quickcheck]
+    fn check_bitmap_round_trip(pairs: Vec<(u16, u16)>) -> bool {
+        let set = set_u32(pairs);
+        IntervalSet::from(&RoaringBitmap::from(&set)) == set
+    }
+
+    #[quickcheck]
+    fn check_overlap_matches_set(lhs: Vec<(u16, u16)>, rhs: Vec<(u16, u16)>) -> bool {
+        let (lhs, rhs) = (set_u32(lhs), set_u32(rhs));
+        let bitmap = RoaringBitmap::from(&rhs);
+        let expected = lhs.clone().intersection(rhs);
+        let both = (&lhs).intersection(&bitmap);
+
+        both.to_set() == expected
+            && both.measure() == expected.measure()
+            && IntervalSet::from(&both.to_bitmap()) == expected
+            && lhs.intersects(&bitmap) != expected.is_empty()
+    }
+}
@@ -0,0 +1,339 @@
+//! Uniform random sampling from sets (feature `rand`).
+//!
+//! [`Interval`] and [`IntervalSet`] implement rand's
+//! [`Distribution`], drawing elements uniformly from the set:
+//!
+//! - **Discrete** element types are exactly uniform by cardinality —
+//!   every member is equally likely.
+//! - **Continuous** element types are uniform by width; open endpoints
+//!   are never returned.
+//!
+//! An [`IntervalSet`] picks a piece with probability proportional to
+//! its [`Measure`] and then samples inside that piece, so the two
+//! steps compose to a uniform draw over the whole set. Sets of measure
+//! zero (e.g. only continuous singletons) pick a piece uniformly.
+//!
+//! # Errors and panics
+//!
+//! Empty and unbounded sets have no uniform distribution.
+//! [`TrySample::try_sample`] and [`UniformSet::try_new`] report them
+//! as `Error::Math(MathError::Domain)`, the same as
+//! [`Midpoint`](crate::ops::Midpoint); the [`Distribution`] impls on
+//! the set types panic instead. Prefer [`UniformSet`] when drawing
+//! many values from one [`IntervalSet`] — it validates once and
+//! caches the cumulative weights.
+//!
+//! ```
+//! use rand::distributions::Distribution;
+//! use rand::rngs::StdRng;
+//! use rand::SeedableRng;
+//! use intervalsets::prelude::*;
+//! use intervalsets::sample::UniformSet;
+//!
+//! let mut rng = StdRng::seed_from_u64(0);
+//! let set = IntervalSet::new([Interval::closed(0, 9), Interval::closed(100, 109)]);
+//! let dist = UniformSet::try_new(&set).unwrap();
+//! for x in dist.sample_iter(&mut rng).take(100) {
+//!     assert!(set.contains(&x));
+//! }
+//! ```
+
+use intervalsets_core::measure::{Extent, Measure};
+pub use intervalsets_core::numeric::SampleElement;
+use intervalsets_core::ops::math::TryAdd;
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use crate::error::{Error, MathError};
+use crate::numeric::{Element, Zero};
+use crate::{Interval, IntervalSet, MaybeEmpty, SetBounds};
+
+/// Fallible uniform sampling of an element from a set.
+///
+/// # Contract
+///
+/// - A successful draw is a member of the set.
+/// - Draws are uniform as described in the [module docs](self).
+/// - Sets without a uniform distribution — empty, half-bounded, or
+///   unbounded — return `Error::Math(MathError::Domain)` and consume
+///   no randomness.
+pub trait TrySample<T> {
+    /// Draws one element uniformly from `self`.
+    ///
+    /// # Errors
+    ///
+    /// `Error::Math(MathError::Domain)` if `self` is empty, not fully
+    /// bounded, or the chosen piece holds no representable member
+    /// (e.g. an open float interval narrower than two ULPs). Measure
+    /// overflow while weighting pieces surfaces as its `MathError`.
+    fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T, Error>;
+}
+
+impl<T: SampleElement> TrySample<T> for Interval<T> {
+    fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T, Error> {
+        match (self.left(), self.right()) {
+            (Some(lhs), Some(rhs)) => {
+                T::sample_between(lhs, rhs, rng).ok_or(Error::Math(MathError::Domain))
+            }
+            _ => Err(Error::Math(MathError::Domain)),
+        }
+    }
+}
+
+/// # Panics
+///
+/// Panics where [`TrySample::try_sample`] would return an error.
+impl<T: SampleElement> Distribution<T> for Interval<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        self.try_sample(rng)
+            .expect("sampling requires a nonempty, fully bounded interval")
+    }
+}
+
+impl<T> TrySample<T> for IntervalSet<T>
+where
+    T: SampleElement + Clone,
+    T::Measure: SampleUniform + PartialOrd,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+{
+    /// Builds a one-off [`UniformSet`]; O(n) in the number of pieces.
+    fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T, Error> {
+        UniformSet::try_new(self)?.try_sample(rng)
+    }
+}
+
+/// # Panics
+///
+/// Panics where [`TrySample::try_sample`] would return an error.
+impl<T> Distribution<T> for IntervalSet<T>
+where
+    T: SampleElement + Clone,
+    T::Measure: SampleUniform + PartialOrd,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        self.try_sample(rng)
+            .expect("sampling requires a nonempty, fully bounded set")
+    }
+}
+
+/// A validated uniform distribution over an [`IntervalSet`].
+///
+/// Holds the set's pieces with their cumulative
+/// [`Element::Measure`]s; each draw is one weighted piece selection
+/// (binary search) plus one [`SampleElement`] draw.
+#[derive(Debug, Clone)]
+pub struct UniformSet<T: Element> {
+    pieces: Vec<Interval<T>>,
+    cumulative: Vec<T::Measure>,
+}
+
+impl<T> UniformSet<T>
+where
+    T: SampleElement + Clone,
+    T::Measure: SampleUniform + PartialOrd,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+{
+    /// Prepares uniform sampling over `set`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Math(MathError::Domain)` if `set` is empty or any
+    ///   piece is not fully bounded.
+    /// - The `MathError` from measuring a piece or summing the
+    ///   measures, e.g. `f64` widths whose total overflows.
+    pub fn try_new(set: &IntervalSet<T>) -> Result<Self, Error> {
+        if set.is_empty() {
+            return Err(Error::Math(MathError::Domain));
+        }
+        let mut total = <T::Measure as Zero>::zero();
+        let mut cumulative = Vec::with_capacity(set.slice().len());
+        for piece in set.iter() {
+            let m = match piece.try_measure()? {
+                Extent::Finite(m) => m,
+                Extent::Infinite => return Err(Error::Math(MathError::Domain)),
+            };
+            total = total.try_add(m).map_err(Into::into)?;
+            cumulative.push(total.clone());
+        }
+        Ok(Self {
+            pieces: set.slice().to_vec(),
+            cumulative,
+        })
+    }
+
+    /// Index of the piece holding the next draw.
+    fn pick<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let total = self.cumulative.last().expect("nonempty by construction");
+        if *total <= <T::Measure as Zero>::zero() {
+            return rng.gen_range(0..self.pieces.len());
+        }
+        let u = rng.gen_range(<T::Measure as Zero>::zero()..total.clone());
+        // First piece whose cumulative measure exceeds `u`; zero-measure
+        // pieces share their predecessor's total and are never chosen.
+        self.cumulative.partition_point(|c| *c <= u)
+    }
+}
+
+impl<T> TrySample<T> for UniformSet<T>
+where
+    T: SampleElement + Clone,
+    T::Measure: SampleUniform + PartialOrd,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+{
+    fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T, Error> {
+        self.pieces[self.pick(rng)].try_sample(rng)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the chosen piece holds no representable member; see
+/// [`TrySample::try_sample`].
+impl<T> Distribution<T> for UniformSet<T>
+where
+    T: SampleElement + Clone,
+    T::Measure: SampleUniform + PartialOrd,
+    <T::Measure as TryAdd>::Error: Into<MathError>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        self.try_sample(rng)
+            .expect("interval piece holds no representable member")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::Contains;
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(42)
+    }
+
+    #[test]
+    fn interval_endpoints() {
+        let mut rng = rng();
+        let x = Interval::open(0.0, 1.0);
+        for v in x.sample_iter(&mut rng).take(1000) {
+            assert!(0.0 < v && v < 1.0);
+        }
+        let x = Interval::closed_open(5, 7);
+        let mut seen = [0usize; 2];
+        for v in x.sample_iter(&mut rng).take(2000) {
+            seen[(v - 5) as usize] += 1;
+        }
+        assert!(seen.iter().all(|&n| n > 850), "{seen:?}");
+    }
+
+    #[test]
+    fn set_is_uniform_by_cardinality() {
+        let mut rng = rng();
+        // 1 member and 3 members: a piece-uniform draw would pick `0`
+        // half the time, a member-uniform draw a quarter of the time.
+        let set = IntervalSet::new([Interval::singleton(0), Interval::closed(10, 12)]);
+        let dist = UniformSet::try_new(&set).unwrap();
+        let zeros = dist
+            .sample_iter(&mut rng)
+            .take(4000)
+            .filter(|&v| v == 0)
+            .count();
+        assert!((850..1150).contains(&zeros), "{zeros}");
+    }
+
+    #[test]
+    fn set_is_uniform_by_width() {
+        let mut rng = rng();
+        let set = IntervalSet::new([Interval::closed(0.0, 1.0), Interval::closed(10.0, 13.0)]);
+        let mut low = 0;
+        for v in (&set).sample_iter(&mut rng).take(4000) {
+            assert!(set.contains(&v));
+            if v <= 1.0 {
+                low += 1;
+            }
+        }
+        assert!((850..1150).contains(&low), "{low}");
+    }
+
+    #[test]
+    fn zero_measure_set_picks_pieces() {
+        let mut rng = rng();
+        let set = IntervalSet::new([Interval::singleton(1.0), Interval::singleton(2.0)]);
+        let ones = set
+            .sample_iter(&mut rng)
+            .take(1000)
+            .filter(|&v| v == 1.0)
+            .count();
+        assert!((400..600).contains(&ones), "{ones}");
+    }
+
+    #[test]
+    fn empty_and_unbounded_are_errors() {
+        let mut rng = rng();
+        let domain = Error::Math(MathError::Domain);
+        assert_eq!(
+            Interval::<i32>::empty().try_sample(&mut rng),
+            Err(domain.clone())
+        );
+        assert_eq!(
+            Interval::closed_unbound(0.0).try_sample(&mut rng),
+            Err(domain.clone())
+        );
+        assert_eq!(
+            Interval::<f64>::unbounded().try_sample(&mut rng),
+            Err(domain.clone())
+        );
+        assert_eq!(
+            Interval::open(1.0, 1.0f64.next_up()).try_sample(&mut rng),
+            Err(domain.clone())
+        );
+
+        assert_eq!(
+            IntervalSet::<i32>::empty().try_sample(&mut rng),
+            Err(domain.clone())
+        );
+        let set = IntervalSet::new([Interval::closed(0, 1), Interval::closed_unbound(5)]);
+        assert_eq!(UniformSet::try_new(&set).map(|_| ()), Err(domain));
+    }
+
+    #[test]
+    #[should_panic(expected = "nonempty, fully bounded")]
+    fn distribution_panics_on_unbounded() {
+        let _: f64 = Interval::unbound_closed(0.0).sample(&mut rng());
+    }
+
+    #[test]
+    fn measure_overflow_is_reported() {
+        let set = IntervalSet::new([
+            Interval::closed(f64::MIN, 0.0),
+            Interval::closed(1.0, f64::MAX),
+        ]);
+        assert!(matches!(UniformSet::try_new(&set), Err(Error::Math(_))));
+    }
+
+    #[cfg(all(feature = "rust_decimal", feature = "fixed"))]
+    #[test]
+    fn decimal_and_fixed() {
+        use fixed::types::I16F16;
+        use rust_decimal::Decimal;
+
+        let mut rng = rng();
+        let x = Interval::open(Decimal::new(1, 1), Decimal::new(2, 1));
+        for v in (&x).sample_iter(&mut rng).take(200) {
+            assert!(x.contains(&v), "{v}");
+        }
+
+        let set = IntervalSet::new([
+            Interval::closed(I16F16::from_num(0), I16F16::from_num(1)),
+            Interval::open(I16F16::from_num(3), I16F16::from_num(4)),
+        ]);
+        for v in (&set).sample_iter(&mut rng).take(200) {
+            assert!(set.contains(&v), "{v}");
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Alternative serde encodings, for use with `#[serde(with = ...)]`
+//! (feature `serde`).
+//!
+//! These are the adapters of [`intervalsets_core::serde`], which
+//! documents the wire forms, with [`Compact`] also implemented for
+//! [`Interval`] and [`IntervalSet`]. Every type encodes the same way in
+//! both crates, so an `Interval` can be read back as an `EnumInterval`
+//! and an `IntervalSet` of at most two pieces as a `MaybeDisjoint`.
+//!
+//! | module      | wire form                                  | non-canonical input |
+//! |-------------|--------------------------------------------|---------------------|
+//! | [`string`]  | `"[0, 10)"`, `"{[0, 1] U [5, 6]}"`         | repaired, as by `FromStr` |
+//! | [`compact`] | `[0, 10, "[)"]`, `[[0, 1], [5, 6]]`        | rejected            |
+//! | [`lenient`] | as `compact`                               | repaired, as by [`IntervalSet::new`] |
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "intervalsets::serde::string")]
+//!     window: Interval<f64>,
+//!     #[serde(with = "intervalsets::serde::lenient")]
+//!     blackout: IntervalSet<i32>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(
+//!     r#"{"window": "[0, 0.5)", "blackout": [[20, 30], [0, 10], [5, 12]]}"#,
+//! )
+//! .unwrap();
+//! assert_eq!(config.window, Interval::closed_open(0.0, 0.5));
+//! assert_eq!(
+//!     config.blackout,
+//!     IntervalSet::new([Interval::closed(0, 12), Interval::closed(20, 30)])
+//! );
+//! assert_eq!(
+//!     serde_json::to_string(&config).unwrap(),
+//!     r#"{"window":"[0, 0.5)","blackout":[[0,12],[20,30]]}"#
+//! );
+//! ```
+
+use ::serde::de;
+pub use intervalsets_core::serde::{compact, lenient, string, Compact};
+use intervalsets_core::sets::EnumInterval;
+
+use crate::numeric::Element;
+use crate::{Interval, IntervalSet};
+
+impl<T: Element> Compact<T> for Interval<T> {
+    const SET: bool = false;
+    type Piece = Self;
+
+    fn piece(&self, index: usize) -> Option<&Self> {
+        (index == 0).then_some(self)
+    }
+
+    fn from_pieces<I, E>(mut pieces: I, _: bool) -> Result<Self, E>
+    where
+        I: Iterator<Item = Result<EnumInterval<T>, E>>,
+        E: de::Error,
+    {
+        let piece = pieces
+            .next()
+            .unwrap_or_else(|| Err(E::invalid_length(0, &"one interval")))?;
+        Ok(Self(piece))
+    }
+}
+
+impl<T: Element> Compact<T> for IntervalSet<T> {
+    const SET: bool = true;
+    type Piece = Interval<T>;
+
+    fn piece(&self, index: usize) -> Option<&Interval<T>> {
+        self.slice().get(index)
+    }
+
+    fn from_pieces<I, E>(pieces: I, repair: bool) -> Result<Self, E>
+    where
+        I: Iterator<Item = Result<EnumInterval<T>, E>>,
+        E: de::Error,
+    {
+        let pieces = pieces
+            .map(|piece| piece.map(Interval))
+            .collect::<Result<Vec<_>, E>>()?;
+        if repair {
+            Ok(Self::new(pieces))
+        } else {
+            Self::try_new(pieces).map_err(E::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use intervalsets_core::sets::MaybeDisjoint;
+
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::Union;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Strict {
+        #[serde(with = "compact")]
+        interval: Interval<i32>,
+        #[serde(with = "compact")]
+        set: IntervalSet<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Lenient {
+        #[serde(with = "lenient")]
+        interval: Interval<i32>,
+        #[serde(with = "lenient")]
+        set: IntervalSet<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Text {
+        #[serde(with = "string")]
+        interval: Interval<f64>,
+        #[serde(with = "string")]
+        set: IntervalSet<f64>,
+    }
+
+    #[test]
+    fn compact_round_trip() {
+        let value = Strict {
+            interval: Interval::closed_unbound(3),
+            set: Interval::unbound_closed(0)
+                .union(Interval::closed(5, 6))
+                .union(Interval::closed(9, 9)),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            r#"{"interval":[3,null],"set":[[null,0],[5,6],[9,9]]}"#
+        );
+        assert_eq!(serde_json::from_str::<Strict>(&json).unwrap(), value);
+
+        let empty = r#"{"interval":[],"set":[]}"#;
+        let parsed: Strict = serde_json::from_str(empty).unwrap();
+        assert_eq!(parsed.interval, Interval::empty());
+        assert_eq!(parsed.set, IntervalSet::empty());
+    }
+
+    #[test]
+    fn compact_rejects_non_canonical() {
+        for json in [
+            r#"{"interval":[5,0],"set":[]}"#,
+            r#"{"interval":[],"set":[[5,6],[0,1]]}"#,
+            r#"{"interval":[],"set":[[0,4],[5,6]]}"#,
+            r#"{"interval":[],"set":[[0,1],[]]}"#,
+        ] {
+            assert!(serde_json::from_str::<Strict>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn lenient_repairs() {
+        let json = r#"{"interval":[5,0],"set":[[20,30],[],[0,4],[5,6],[3,3]]}"#;
+        let parsed: Lenient = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed.interval, Interval::empty());
+        assert_eq!(
+            parsed.set,
+            IntervalSet::new([Interval::closed(0, 6), Interval::closed(20, 30)])
+        );
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            r#"{"interval":[],"set":[[0,6],[20,30]]}"#
+        );
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let value = Text {
+            interval: Interval::open(0.0, 0.25),
+            set: Interval::unbound_open(0.0).union(Interval::closed(1.0, 2.0)),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            r#"{"interval":"(0, 0.25)","set":"{(.., 0) U [1, 2]}"}"#
+        );
+        assert_eq!(serde_json::from_str::<Text>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn encodings_agree_with_core() {
+        let set = Interval::closed(0, 1).union(Interval::open_unbound(5));
+        let mut json = Vec::new();
+        compact::serialize(&set, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        let core: MaybeDisjoint<i32> =
+            compact::deserialize(&mut serde_json::Deserializer::from_slice(&json)).unwrap();
+        assert_eq!(
+            core,
+            MaybeDisjoint::from_pair(EnumInterval::closed(0, 1), EnumInterval::closed_unbound(6))
+        );
+
+        let interval = Interval::closed_open(0.0, 1.0);
+        let mut json = Vec::new();
+        compact::serialize(&interval, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        let core: EnumInterval<f64> =
+            compact::deserialize(&mut serde_json::Deserializer::from_slice(&json)).unwrap();
+        assert_eq!(core, EnumInterval::closed_open(0.0, 1.0));
+    }
+}
@@ -29,6 +29,10 @@ use crate::MaybeEmpty;
     feature = "serde",
     serde(bound(deserialize = "T: Element + serde::Deserialize<'de>"))
 )]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Interval<T>(pub(crate) EnumInterval<T>);
 
 impl<T> Interval<T> {
@@ -187,6 +191,7 @@ impl<T> OrdBounded<T> for Interval<T> {
     feature = "serde",
     serde(bound(deserialize = "T: Element + serde::Deserialize<'de>"))
 )]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
 pub struct IntervalSet<T> {
     intervals: Vec<Interval<T>>,
 }
@@ -210,6 +215,32 @@ impl<T: Element> TryFrom<RawIntervalSet<T>> for IntervalSet<T> {
     }
 }
 
+/// Validates through [`IntervalSet::try_new`], as serde does; the
+/// pieces validate through the core interval types.
+#[cfg(feature = "rkyv")]
+impl<T, D> rkyv::Deserialize<IntervalSet<T>, D> for ArchivedIntervalSet<T>
+where
+    T: Element + rkyv::Archive,
+    T::Archived: rkyv::Deserialize<T, D>,
+    D: rkyv::rancor::Fallible + ?Sized,
+    D::Error: rkyv::rancor::Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<IntervalSet<T>, D::Error> {
+        let intervals: Vec<Interval<T>> =
+            rkyv::Deserialize::deserialize(&self.intervals, deserializer)?;
+        IntervalSet::try_new(intervals).map_err(rkyv::rancor::Source::new)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: rkyv::Archive> ArchivedIntervalSet<T> {
+    /// The archived intervals, in stored order. See
+    /// [`SetView`](crate::rkyv::SetView) for checked access.
+    pub fn slice(&self) -> &[ArchivedInterval<T>] {
+        &self.intervals
+    }
+}
+
 impl<T: Element> IntervalSet<T> {
     /// Create an `IntervalSet` from any iterable of intervals,
     /// **repairing** any invariant violations along the way.
@@ -0,0 +1,615 @@
+//! Standalone SVG diagrams of sets on a number line (feature `svg`).
+//!
+//! A [`Plot`] draws labeled rows of [`Interval`]s and [`IntervalSet`]s
+//! over a shared axis with tick marks. Closed endpoints are filled
+//! dots, open endpoints hollow ones, and sides that are unbounded or
+//! reach past the viewport end in an arrowhead. The output is a
+//! complete `<svg>` document: it needs no stylesheet, script or
+//! renderer beyond a browser.
+//!
+//! Positions come from [`Project`], which maps elements onto an `f64`
+//! axis. It is implemented for the primitive numbers, `Saturating`
+//! wrappers, and the storage types of the enabled features
+//! (`OrderedFloat`, `NotNan`, `Decimal`, `BigDecimal`, `BigInt`,
+//! `BigUint` and the `fixed` types); implement it for dates,
+//! timestamps or other element types of your own. The axis spans the
+//! hull of the rows' finite endpoints unless pinned with
+//! [`Plot::with_viewport`], and tick labels are in projected units.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::svg::Plot;
+//!
+//! let outage = Interval::closed_open(12.5, 14.0);
+//! let degraded = IntervalSet::new([Interval::closed(10.0, 12.5), Interval::closed(14.0, 16.0)]);
+//! let svg = Plot::new()
+//!     .row("outage", outage)
+//!     .row("degraded", degraded)
+//!     .render()
+//!     .unwrap();
+//!
+//! assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+//! assert!(svg.contains(">outage</text>"));
+//! assert!(svg.ends_with("</svg>\n"));
+//! ```
+
+use core::fmt::Write;
+
+use num_traits::ToPrimitive;
+
+use crate::bound::{BoundType, FiniteBound, Side};
+use crate::error::MathError;
+use crate::numeric::Element;
+use crate::ops::ConvexHull;
+use crate::{Interval, IntervalSet, MaybeEmpty, SetBounds};
+
+/// Maps an element onto the `f64` axis of a [`Plot`].
+///
+/// `project` must be monotone: `a < b` implies
+/// `a.project() <= b.project()`. `None`, or a non-finite value, means
+/// the element cannot be placed.
+pub trait Project {
+    /// The element's position on the axis.
+    fn project(&self) -> Option<f64>;
+}
+
+/// `Project` through [`ToPrimitive::to_f64`], one impl per type so
+/// that other types stay free to implement it their own way.
+macro_rules! project_to_f64 {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Project for $t {
+                fn project(&self) -> Option<f64> {
+                    ToPrimitive::to_f64(self)
+                }
+            }
+        )*
+    };
+}
+
+project_to_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+#[cfg(feature = "ordered-float")]
+project_to_f64!(
+    ordered_float::OrderedFloat<f32>,
+    ordered_float::OrderedFloat<f64>,
+    ordered_float::NotNan<f32>,
+    ordered_float::NotNan<f64>,
+);
+
+#[cfg(feature = "rust_decimal")]
+project_to_f64!(rust_decimal::Decimal);
+
+#[cfg(feature = "bigdecimal")]
+project_to_f64!(bigdecimal::BigDecimal);
+
+#[cfg(feature = "num-bigint")]
+project_to_f64!(num_bigint::BigInt, num_bigint::BigUint);
+
+#[cfg(feature = "fixed")]
+macro_rules! project_fixed {
+    ($($t:ident),*) => {
+        $(
+            impl<Frac> Project for fixed::$t<Frac>
+            where
+                Self: ToPrimitive,
+            {
+                fn project(&self) -> Option<f64> {
+                    ToPrimitive::to_f64(self)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "fixed")]
+project_fixed!(
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
+    FixedU128
+);
+
+impl<T: Project> Project for core::num::Saturating<T> {
+    fn project(&self) -> Option<f64> {
+        self.0.project()
+    }
+}
+
+/// Labeled sets drawn as an SVG number line; see the
+/// [module docs](self).
+#[derive(Debug, Clone)]
+pub struct Plot<'a, T> {
+    rows: Vec<(&'a str, IntervalSet<T>)>,
+    width: f64,
+    ticks: usize,
+    viewport: Option<(T, T)>,
+}
+
+impl<T> Default for Plot<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Plot<'a, T> {
+    /// An empty plot 640 pixels wide with about 5 ticks.
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            width: 640.0,
+            ticks: 5,
+            viewport: None,
+        }
+    }
+
+    /// Adds a row drawing `set`, labeled `label`.
+    pub fn row(mut self, label: &'a str, set: impl Into<IntervalSet<T>>) -> Self {
+        self.rows.push((label, set.into()));
+        self
+    }
+
+    /// Make the whole image `width` pixels wide, labels included.
+    pub fn with_width(self, width: f64) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Aim for about `ticks` tick marks, placed at round values; 0
+    /// draws none.
+    pub fn with_ticks(self, ticks: usize) -> Self {
+        Self { ticks, ..self }
+    }
+
+    /// Show `[lo, hi]` rather than the hull of the rows' endpoints.
+    /// Pieces reaching past it end in arrowheads.
+    pub fn with_viewport(self, lo: T, hi: T) -> Self {
+        Self {
+            viewport: Some((lo, hi)),
+            ..self
+        }
+    }
+}
+
+const ROW: f64 = 28.0;
+const PAD: f64 = 12.0;
+const CHAR: f64 = 7.0;
+const AXIS: f64 = 32.0;
+const DOT: f64 = 4.0;
+const COLOR: &str = "#1f6feb";
+const MUTED: &str = "#8c959f";
+
+impl<T> Plot<'_, T>
+where
+    T: Element + Clone + Project,
+{
+    /// The plot as a standalone SVG document.
+    ///
+    /// # Errors
+    ///
+    /// `Err(MathError::Domain)` if an endpoint or viewport end does not
+    /// project to a finite value, or the viewport's ends are crossed.
+    pub fn render(&self) -> Result<String, MathError> {
+        let axis = self.axis()?;
+        let label_width = self
+            .rows
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let left = PAD + label_width as f64 * CHAR + if label_width > 0 { PAD } else { 0.0 };
+        let scale = Scale {
+            lo: axis.0,
+            hi: axis.1,
+            left,
+            right: (self.width - PAD).max(left + 1.0),
+        };
+        let height = PAD + self.rows.len() as f64 * ROW + AXIS;
+
+        let mut rows = Vec::with_capacity(self.rows.len());
+        for (label, set) in &self.rows {
+            let mut pieces = Vec::new();
+            for piece in set.iter().filter(|p| !p.is_empty()) {
+                if let Some(ends) = scale.ends(piece)? {
+                    pieces.push(ends);
+                }
+            }
+            rows.push((*label, pieces));
+        }
+
+        let mut svg = String::new();
+        self.write(&mut svg, &scale, &rows, height)
+            .expect("writing to a String");
+        Ok(svg)
+    }
+
+    /// The projected viewport.
+    fn axis(&self) -> Result<(f64, f64), MathError> {
+        let (lo, hi) = match &self.viewport {
+            Some((lo, hi)) => (project(lo)?, project(hi)?),
+            None => {
+                let ends = self
+                    .rows
+                    .iter()
+                    .flat_map(|(_, set)| set.iter())
+                    .flat_map(|piece| [piece.left(), piece.right()])
+                    .flatten()
+                    .map(FiniteBound::value);
+                let hull = Interval::try_hull(ends).map_err(|_| MathError::Domain)?;
+                match (hull.left(), hull.right()) {
+                    (Some(lo), Some(hi)) => (project(lo.value())?, project(hi.value())?),
+                    _ => (0.0, 1.0),
+                }
+            }
+        };
+        if lo > hi {
+            return Err(MathError::Domain);
+        }
+        if lo == hi {
+            // A lone point sits mid-axis. Far from zero `lo - 1.0 == lo`,
+            // so the padding grows with its magnitude.
+            let pad = (lo.abs() * 1e-9).max(1.0);
+            return Ok((lo - pad, hi + pad));
+        }
+        Ok((lo, hi))
+    }
+
+    fn write(
+        &self,
+        out: &mut String,
+        scale: &Scale,
+        rows: &[(&str, Vec<[End; 2]>)],
+        height: f64,
+    ) -> core::fmt::Result {
+        let width = self.width;
+        writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" font-family=\"sans-serif\" font-size=\"12\">"
+        )?;
+        for (i, (label, pieces)) in rows.iter().enumerate() {
+            let y = PAD + (i as f64 + 0.5) * ROW;
+            if !label.is_empty() {
+                writeln!(
+                    out,
+                    "<text x=\"{}\" y=\"{y}\" text-anchor=\"end\" dominant-baseline=\"middle\">{}</text>",
+                    Px(scale.left - PAD),
+                    Escaped(label)
+                )?;
+            }
+            writeln!(
+                out,
+                "<line x1=\"{}\" y1=\"{y}\" x2=\"{}\" y2=\"{y}\" stroke=\"{MUTED}\" stroke-width=\"1\"/>",
+                Px(scale.left),
+                Px(scale.right)
+            )?;
+            for [start, end] in pieces {
+                writeln!(
+                    out,
+                    "<line x1=\"{}\" y1=\"{y}\" x2=\"{}\" y2=\"{y}\" stroke=\"{COLOR}\" stroke-width=\"4\"/>",
+                    Px(start.x),
+                    Px(end.x)
+                )?;
+                write_end(out, start, Side::Left, y)?;
+                write_end(out, end, Side::Right, y)?;
+            }
+        }
+        let y = PAD + rows.len() as f64 * ROW + PAD / 2.0;
+        writeln!(
+            out,
+            "<line x1=\"{}\" y1=\"{y}\" x2=\"{}\" y2=\"{y}\" stroke=\"{MUTED}\" stroke-width=\"1\"/>",
+            Px(scale.left),
+            Px(scale.right)
+        )?;
+        for (value, text) in ticks(scale.lo, scale.hi, self.ticks) {
+            let x = Px(scale.x(value));
+            writeln!(
+                out,
+                "<line x1=\"{x}\" y1=\"{y}\" x2=\"{x}\" y2=\"{}\" stroke=\"{MUTED}\" stroke-width=\"1\"/>",
+                Px(y + 4.0)
+            )?;
+            writeln!(
+                out,
+                "<text x=\"{x}\" y=\"{}\" text-anchor=\"middle\" fill=\"{MUTED}\">{text}</text>",
+                Px(y + 16.0)
+            )?;
+        }
+        writeln!(out, "</svg>")
+    }
+}
+
+fn project<T: Project>(value: &T) -> Result<f64, MathError> {
+    value
+        .project()
+        .filter(|x| x.is_finite())
+        .ok_or(MathError::Domain)
+}
+
+/// Maps projected values to pixels.
+struct Scale {
+    lo: f64,
+    hi: f64,
+    left: f64,
+    right: f64,
+}
+
+/// One end of a piece: where it is drawn and how.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct End {
+    x: f64,
+    /// `None` for an arrowhead.
+    bound: Option<BoundType>,
+}
+
+impl Scale {
+    fn x(&self, value: f64) -> f64 {
+        self.left + (value - self.lo) / (self.hi - self.lo) * (self.right - self.left)
+    }
+
+    /// The ends of a piece, or `None` if it lies outside the viewport.
+    fn ends<T: Element + Project>(
+        &self,
+        piece: &Interval<T>,
+    ) -> Result<Option<[End; 2]>, MathError> {
+        let beyond = |x| End { x, bound: None };
+        let left = match piece.left() {
+            Some(b) => {
+                let v = project(b.value())?;
+                if v > self.hi {
+                    return Ok(None);
+                }
+                if v < self.lo {
+                    beyond(self.left)
+                } else {
+                    End {
+                        x: self.x(v),
+                        bound: Some(b.bound_type()),
+                    }
+                }
+            }
+            None => beyond(self.left),
+        };
+        let right = match piece.right() {
+            Some(b) => {
+                let v = project(b.value())?;
+                if v < self.lo {
+                    return Ok(None);
+                }
+                if v > self.hi {
+                    beyond(self.right)
+                } else {
+                    End {
+                        x: self.x(v),
+                        bound: Some(b.bound_type()),
+                    }
+                }
+            }
+            None => beyond(self.right),
+        };
+        Ok(Some([left, right]))
+    }
+}
+
+fn write_end(out: &mut String, end: &End, side: Side, y: f64) -> core::fmt::Result {
+    let x = end.x;
+    match end.bound {
+        Some(BoundType::Closed) => writeln!(
+            out,
+            "<circle cx=\"{}\" cy=\"{y}\" r=\"{DOT}\" fill=\"{COLOR}\"/>",
+            Px(x)
+        ),
+        Some(BoundType::Open) => writeln!(
+            out,
+            "<circle cx=\"{}\" cy=\"{y}\" r=\"{DOT}\" fill=\"white\" stroke=\"{COLOR}\" stroke-width=\"2\"/>",
+            Px(x)
+        ),
+        None => {
+            let tip = side.select(x - DOT, x + DOT);
+            let back = side.select(x + DOT, x - DOT);
+            writeln!(
+                out,
+                "<polygon points=\"{},{y} {},{} {},{}\" fill=\"{COLOR}\"/>",
+                Px(tip),
+                Px(back),
+                Px(y - DOT * 1.5),
+                Px(back),
+                Px(y + DOT * 1.5)
+            )
+        }
+    }
+}
+
+/// About `n` round values in `[lo, hi]` — multiples of 1, 2 or 5 times
+/// a power of ten — with their labels.
+fn ticks(lo: f64, hi: f64, n: usize) -> Vec<(f64, String)> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let raw = (hi - lo) / n as f64;
+    if !(raw.is_finite() && raw > 0.0) {
+        return Vec::new();
+    }
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|m| m * magnitude)
+        .find(|&s| s >= raw)
+        .unwrap_or(10.0 * magnitude);
+    if !(step.is_finite() && step > 0.0) {
+        return Vec::new();
+    }
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    let first = (lo / step).ceil() as i64;
+    let last = (hi / step).floor() as i64;
+    (first..=last)
+        .map(|k| {
+            // `+ 0.0` turns `-0.0` into `0.0`.
+            let value = k as f64 * step + 0.0;
+            (value, format!("{value:.decimals$}"))
+        })
+        .collect()
+}
+
+/// A pixel coordinate, rounded to 0.1.
+struct Px(f64);
+
+impl core::fmt::Display for Px {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let tenths = (self.0 * 10.0).round() / 10.0 + 0.0;
+        write!(f, "{tenths}")
+    }
+}
+
+/// Text escaped for SVG character data.
+struct Escaped<'a>(&'a str);
+
+impl core::fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::Union;
+
+    fn count(svg: &str, needle: &str) -> usize {
+        svg.matches(needle).count()
+    }
+
+    #[test]
+    fn endpoint_glyphs() {
+        let set = Interval::unbound_open(0.0)
+            .union(Interval::closed(2.0, 4.0))
+            .union(Interval::open_unbound(8.0));
+        let svg = Plot::new().row("s", set).render().unwrap();
+        assert_eq!(count(&svg, "fill=\"white\""), 2);
+        assert_eq!(count(&svg, &format!("r=\"{DOT}\" fill=\"{COLOR}\"")), 2);
+        assert_eq!(count(&svg, "<polygon"), 2);
+    }
+
+    #[test]
+    fn axis_spans_finite_hull() {
+        let svg = Plot::new()
+            .with_width(100.0 + 2.0 * PAD)
+            .row("", Interval::closed(10, 20))
+            .row("", Interval::closed(30, 60))
+            .render()
+            .unwrap();
+        // 10..60 over 100px, so 2px per unit.
+        assert!(svg.contains("<circle cx=\"12\""));
+        assert!(svg.contains("<circle cx=\"112\""));
+        assert!(svg.contains("<circle cx=\"52\""));
+        let labels: Vec<_> = ticks(10.0, 60.0, 5).into_iter().map(|(_, t)| t).collect();
+        assert_eq!(labels, ["10", "20", "30", "40", "50", "60"]);
+        for label in labels {
+            assert!(svg.contains(&format!(">{label}</text>")));
+        }
+    }
+
+    #[test]
+    fn tick_labels_are_round() {
+        let labels =
+            |lo, hi| -> Vec<String> { ticks(lo, hi, 5).into_iter().map(|(_, t)| t).collect() };
+        assert_eq!(labels(0.0, 1.0), ["0.0", "0.2", "0.4", "0.6", "0.8", "1.0"]);
+        assert_eq!(labels(-0.3, 0.3), ["-0.2", "0.0", "0.2"]);
+        assert_eq!(
+            labels(0.0, 1000.0),
+            ["0", "200", "400", "600", "800", "1000"]
+        );
+        assert!(ticks(0.0, 1.0, 0).is_empty());
+        assert!(ticks(1e17, 1e17, 5).is_empty());
+        assert!(ticks(0.0, f64::MAX * 2.0, 5).is_empty());
+    }
+
+    #[test]
+    fn large_singleton_gets_an_axis() {
+        let svg = Plot::new()
+            .row("x", Interval::singleton(1e17))
+            .render()
+            .unwrap();
+        assert!(!svg.contains("NaN"));
+        assert!(svg.contains(">100000000000000000</text>"));
+    }
+
+    #[test]
+    fn viewport_clips_and_skips() {
+        let svg = Plot::new()
+            .with_viewport(50, 60)
+            .row("in", Interval::closed(0, 100))
+            .row("out", Interval::closed(200, 300))
+            .render()
+            .unwrap();
+        assert_eq!(count(&svg, "<polygon"), 2);
+        assert_eq!(count(&svg, "<circle"), 0);
+
+        let crossed = Plot::new()
+            .with_viewport(2, 1)
+            .row("", Interval::closed(0, 3));
+        assert_eq!(crossed.render(), Err(MathError::Domain));
+    }
+
+    #[test]
+    fn labels_are_escaped() {
+        let svg = Plot::new()
+            .row("a < b & \"c\"", Interval::closed(0, 1))
+            .render()
+            .unwrap();
+        assert!(svg.contains(">a &lt; b &amp; &quot;c&quot;</text>"));
+    }
+
+    /// Minutes after an epoch, plotted in hours; earlier times have
+    /// no position.
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Minute(i32);
+
+    impl Element for Minute {
+        type Kind = crate::numeric::DiscreteKind;
+        type Measure = u64;
+
+        fn try_adjacent(&self, side: Side) -> Option<Self> {
+            self.0.try_adjacent(side).map(Self)
+        }
+
+        fn try_measure_finite(left: &Self, right: &Self) -> Option<u64> {
+            i32::try_measure_finite(&left.0, &right.0)
+        }
+    }
+
+    impl Project for Minute {
+        fn project(&self) -> Option<f64> {
+            (self.0 >= 0).then(|| f64::from(self.0) / 60.0)
+        }
+    }
+
+    #[test]
+    fn wrapped_elements_project() {
+        assert_eq!(core::num::Saturating(90_i32).project(), Some(90.0));
+        #[cfg(feature = "ordered-float")]
+        assert_eq!(
+            ordered_float::NotNan::new(1.5).unwrap().project(),
+            Some(1.5)
+        );
+        #[cfg(feature = "rust_decimal")]
+        assert_eq!(rust_decimal::Decimal::new(25, 1).project(), Some(2.5));
+    }
+
+    #[test]
+    fn custom_projection() {
+        let shift = Interval::closed(Minute(0), Minute(150));
+        let svg = Plot::new().row("", shift).render().unwrap();
+        assert!(svg.contains(">2.0</text>"));
+        assert!(!svg.contains(">150</text>"));
+
+        let early = Plot::new().row("", Interval::closed(Minute(-5), Minute(10)));
+        assert_eq!(early.render(), Err(MathError::Domain));
+    }
+}
@@ -0,0 +1,448 @@
+//! Sets drawn as aligned number lines, for terminals, tests and logs.
+//!
+//! A [`Timeline`] draws labeled [`IntervalSet`]s one per row over a
+//! shared viewport, above an axis marking the viewport's ends. It
+//! writes through [`fmt::Write`] with [`Timeline::render`], which
+//! reports a viewport it cannot scale as a [`TimelineError`], and
+//! implements `Display`, which draws only the unbounded pieces of such
+//! a timeline, without an axis.
+//!
+//! | ASCII     | Unicode   | meaning                            |
+//! |-----------|-----------|------------------------------------|
+//! | `[` / `]` | `●`       | closed endpoint                    |
+//! | `(` / `)` | `○`       | open endpoint                      |
+//! | `<` / `>` | `◀` / `▶` | continues past the viewport        |
+//! | `=`       | `━`       | covered                            |
+//! | `-`       | `─`       | not covered                        |
+//! | `\|`      | `●` / `○` | a piece narrower than one column   |
+//!
+//! The viewport defaults to the [`ConvexHull`] of every finite
+//! endpoint, and columns are scaled by its [`Span`]; pin it with
+//! [`Timeline::with_viewport`] to zoom in or to line up several
+//! timelines.
+//!
+//! ```
+//! use intervalsets::prelude::*;
+//! use intervalsets::timeline::Timeline;
+//!
+//! let busy = IntervalSet::new([Interval::closed(0.0, 4.0), Interval::closed_open(10.0, 20.0)]);
+//! let free = busy.clone().complement();
+//! let timeline = Timeline::new()
+//!     .with_width(21)
+//!     .row("busy", &busy)
+//!     .row("free", &free);
+//! let lines: Vec<String> = timeline.to_string().lines().map(str::to_owned).collect();
+//! assert_eq!(lines, [
+//!     "busy [===]-----[=========)",
+//!     "free <---(=====)--------->",
+//!     "     0                  20",
+//! ]);
+//! ```
+
+use core::fmt::{self, Write};
+
+use intervalsets_core::measure::Extent;
+use intervalsets_core::ops::math::TrySub;
+use intervalsets_core::ops::Span;
+use num_traits::{ToPrimitive, Zero};
+use thiserror::Error as ThisError;
+
+use crate::bound::{BoundType, FiniteBound, Side};
+use crate::factory::traits::TryFiniteFactory;
+use crate::numeric::Element;
+use crate::ops::ConvexHull;
+use crate::{Interval, IntervalSet, MaybeEmpty, SetBounds};
+
+/// Errors from [`Timeline::render`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ThisError)]
+#[non_exhaustive]
+pub enum TimelineError {
+    /// The writer failed.
+    #[error(transparent)]
+    Write(#[from] fmt::Error),
+
+    /// The viewport is crossed, or its ends do not compare (`NaN`).
+    #[error("timeline viewport is not an interval")]
+    Viewport,
+
+    /// The viewport's width overflows `T`'s subtraction (e.g.
+    /// `i32::MIN` to `i32::MAX`) or has no `f64` value.
+    #[error("timeline viewport width is not representable")]
+    Span,
+}
+
+/// Labeled sets drawn over a shared number line; see the
+/// [module docs](self).
+#[derive(Debug, Clone)]
+pub struct Timeline<'a, T> {
+    rows: Vec<(&'a str, &'a IntervalSet<T>)>,
+    width: usize,
+    viewport: Option<(T, T)>,
+    unicode: bool,
+}
+
+impl<T> Default for Timeline<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Timeline<'a, T> {
+    /// An empty timeline 60 columns wide, in ASCII.
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            width: 60,
+            viewport: None,
+            unicode: false,
+        }
+    }
+
+    /// Adds a row drawing `set`, labeled `label`.
+    pub fn row(mut self, label: &'a str, set: &'a IntervalSet<T>) -> Self {
+        self.rows.push((label, set));
+        self
+    }
+
+    /// Draw the number line `width` columns wide (at least 2).
+    pub fn with_width(self, width: usize) -> Self {
+        Self {
+            width: width.max(2),
+            ..self
+        }
+    }
+
+    /// Show `[lo, hi]` rather than the hull of the rows' endpoints.
+    /// Pieces reaching past it are drawn with arrows.
+    pub fn with_viewport(self, lo: T, hi: T) -> Self {
+        Self {
+            viewport: Some((lo, hi)),
+            ..self
+        }
+    }
+
+    /// Draw with Unicode box and circle glyphs.
+    pub fn with_unicode(self, on: bool) -> Self {
+        Self {
+            unicode: on,
+            ..self
+        }
+    }
+}
+
+impl<T> Timeline<'_, T>
+where
+    T: Element + Clone + fmt::Display + TrySub,
+    <T as TrySub>::Output: Zero + ToPrimitive,
+{
+    /// Writes every row, then the axis, each ending in `\n`.
+    ///
+    /// # Errors
+    ///
+    /// [`TimelineError::Write`] from `w`, or the viewport cannot be
+    /// scaled; nothing is written then.
+    pub fn render<W: Write + ?Sized>(&self, w: &mut W) -> Result<(), TimelineError> {
+        let scale = self.scale()?;
+        Ok(self.write(w, scale.as_ref())?)
+    }
+
+    /// Writes the rows over `scale`, or only their unbounded pieces if
+    /// there is none, then the axis if there is one.
+    fn write<W: Write + ?Sized>(&self, w: &mut W, scale: Option<&Scale<T>>) -> fmt::Result {
+        let label_width = self.rows.iter().map(|(l, _)| l.chars().count()).max();
+        let pad = match label_width {
+            Some(n) if n > 0 => n + 1,
+            _ => 0,
+        };
+        for (label, set) in &self.rows {
+            write!(w, "{label:<pad$}")?;
+            let line = self.draw(set, scale);
+            line.iter().try_for_each(|&c| w.write_char(c))?;
+            w.write_char('\n')?;
+        }
+        let Some(scale) = scale else {
+            return Ok(());
+        };
+        let (lo, hi) = (scale.lo.to_string(), scale.hi.to_string());
+        write!(w, "{:pad$}{lo}", "")?;
+        let gap = self
+            .width
+            .saturating_sub(lo.chars().count() + hi.chars().count());
+        if gap > 0 {
+            write!(w, "{:gap$}{hi}", "")?;
+        }
+        w.write_char('\n')
+    }
+
+    /// The viewport and its span, or `None` if nothing finite is shown.
+    fn scale(&self) -> Result<Option<Scale<T>>, TimelineError> {
+        let (lo, hi) = match &self.viewport {
+            Some((lo, hi)) => (lo.clone(), hi.clone()),
+            None => {
+                let ends = self
+                    .rows
+                    .iter()
+                    .flat_map(|(_, set)| set.iter())
+                    .flat_map(|piece| [piece.left(), piece.right()])
+                    .flatten()
+                    .map(FiniteBound::value);
+                let hull = Interval::try_hull(ends).map_err(|_| TimelineError::Viewport)?;
+                match (hull.left(), hull.right()) {
+                    (Some(lo), Some(hi)) => (lo.value().clone(), hi.value().clone()),
+                    _ => return Ok(None),
+                }
+            }
+        };
+        let span = Interval::try_closed(lo.clone(), hi.clone())
+            .map_err(|_| TimelineError::Viewport)?
+            .0
+            .span()
+            .map_err(|_| TimelineError::Span)?;
+        match span {
+            Extent::Finite(span) => Ok(Some(Scale {
+                span: span.to_f64().ok_or(TimelineError::Span)?,
+                lo,
+                hi,
+            })),
+            Extent::Infinite => Err(TimelineError::Span),
+        }
+    }
+
+    /// One row's cells.
+    fn draw(&self, set: &IntervalSet<T>, scale: Option<&Scale<T>>) -> Vec<char> {
+        let glyphs = if self.unicode { &UNICODE } else { &ASCII };
+        let mut cells = vec![glyphs.background; self.width];
+        for piece in set.iter().filter(|p| !p.is_empty()) {
+            let Some(ends) = self.ends(piece, scale) else {
+                continue;
+            };
+            let [(start, open), (end, close)] = ends;
+            cells[start..=end].fill(glyphs.fill);
+            cells[start] = glyphs.end(Side::Left, open);
+            cells[end] = glyphs.end(Side::Right, close);
+            if start == end {
+                cells[start] = glyphs.point(open, close);
+            }
+        }
+        cells
+    }
+
+    /// The columns of a piece's ends and how each is drawn, or `None`
+    /// if it lies outside the viewport, or has a finite bound and there
+    /// is no viewport to place it in.
+    fn ends(&self, piece: &Interval<T>, scale: Option<&Scale<T>>) -> Option<[(usize, End); 2]> {
+        let last = self.width - 1;
+        let Some(scale) = scale else {
+            // Nothing finite to scale by: only unbounded pieces show.
+            return (piece.left().is_none() && piece.right().is_none())
+                .then_some([(0, End::Beyond), (last, End::Beyond)]);
+        };
+        let left = match piece.left() {
+            Some(b) if *b.value() > scale.hi => return None,
+            Some(b) if *b.value() >= scale.lo => {
+                (self.column(b.value(), scale), End::At(b.bound_type()))
+            }
+            _ => (0, End::Beyond),
+        };
+        let right = match piece.right() {
+            Some(b) if *b.value() < scale.lo => return None,
+            Some(b) if *b.value() <= scale.hi => {
+                (self.column(b.value(), scale), End::At(b.bound_type()))
+            }
+            _ => (last, End::Beyond),
+        };
+        Some([left, right])
+    }
+
+    /// The column of `x`, which lies in the viewport.
+    fn column(&self, x: &T, scale: &Scale<T>) -> usize {
+        let last = self.width - 1;
+        if scale.span <= 0.0 {
+            return last / 2;
+        }
+        let offset = x
+            .clone()
+            .try_sub(scale.lo.clone())
+            .ok()
+            .and_then(|d| d.to_f64())
+            .unwrap_or(0.0);
+        let col = (offset / scale.span * last as f64).round();
+        (col.max(0.0) as usize).min(last)
+    }
+}
+
+impl<T> fmt::Display for Timeline<'_, T>
+where
+    T: Element + Clone + fmt::Display + TrySub,
+    <T as TrySub>::Output: Zero + ToPrimitive,
+{
+    /// Like [`render`](Timeline::render), but a viewport that cannot
+    /// be scaled draws only pieces unbounded on both sides, without an
+    /// axis, so that only the formatter can fail.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, self.scale().ok().flatten().as_ref())
+    }
+}
+
+struct Scale<T> {
+    lo: T,
+    hi: T,
+    span: f64,
+}
+
+/// How one end of a piece is drawn.
+#[derive(Clone, Copy)]
+enum End {
+    At(BoundType),
+    Beyond,
+}
+
+struct Glyphs {
+    background: char,
+    fill: char,
+    closed: [char; 2],
+    open: [char; 2],
+    beyond: [char; 2],
+    point: char,
+    open_point: char,
+}
+
+const ASCII: Glyphs = Glyphs {
+    background: '-',
+    fill: '=',
+    closed: ['[', ']'],
+    open: ['(', ')'],
+    beyond: ['<', '>'],
+    point: '|',
+    open_point: '|',
+};
+
+const UNICODE: Glyphs = Glyphs {
+    background: '─',
+    fill: '━',
+    closed: ['●', '●'],
+    open: ['○', '○'],
+    beyond: ['◀', '▶'],
+    point: '●',
+    open_point: '○',
+};
+
+impl Glyphs {
+    fn end(&self, side: Side, end: End) -> char {
+        let pair = match end {
+            End::At(BoundType::Closed) => self.closed,
+            End::At(BoundType::Open) => self.open,
+            End::Beyond => self.beyond,
+        };
+        side.select(pair[0], pair[1])
+    }
+
+    /// A piece whose ends share a column.
+    fn point(&self, left: End, right: End) -> char {
+        match (left, right) {
+            (End::Beyond, _) => self.beyond[0],
+            (_, End::Beyond) => self.beyond[1],
+            (End::At(BoundType::Open), End::At(BoundType::Open)) => self.open_point,
+            _ => self.point,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::traits::*;
+    use crate::ops::Union;
+
+    #[test]
+    fn draws_endpoints_and_arrows() {
+        let set = Interval::unbound_open(0.0)
+            .union(Interval::closed(2.0, 4.0))
+            .union(Interval::open_unbound(8.0));
+        let t = Timeline::new().with_width(9).row("", &set);
+        assert_eq!(t.to_string(), "<-[=]--->\n0       8\n");
+
+        let t = Timeline::new()
+            .with_width(13)
+            .with_viewport(-2.0, 10.0)
+            .row("", &set);
+        assert_eq!(t.to_string(), "<=)-[=]---(=>\n-2         10\n");
+
+        let t = Timeline::new()
+            .with_width(13)
+            .with_viewport(-2.0, 10.0)
+            .with_unicode(true)
+            .row("", &set);
+        assert_eq!(t.to_string(), "◀━○─●━●───○━▶\n-2         10\n");
+    }
+
+    #[test]
+    fn viewport_clips_with_arrows() {
+        let set = IntervalSet::from(Interval::closed(0, 100));
+        let t = Timeline::new()
+            .with_width(11)
+            .with_viewport(50, 60)
+            .row("x", &set);
+        assert_eq!(t.to_string(), "x <=========>\n  50       60\n");
+        let t = Timeline::new()
+            .with_width(11)
+            .with_viewport(200, 300)
+            .row("x", &set);
+        assert_eq!(t.to_string(), "x -----------\n  200     300\n");
+    }
+
+    #[test]
+    fn labels_align_rows() {
+        let a = IntervalSet::from(Interval::closed(0, 10));
+        let b = IntervalSet::from(Interval::singleton(5));
+        let empty = IntervalSet::empty();
+        let t = Timeline::new()
+            .with_width(11)
+            .row("a", &a)
+            .row("bb", &b)
+            .row("", &empty);
+        assert_eq!(
+            t.to_string(),
+            "a  [=========]\nbb -----|-----\n   -----------\n   0        10\n"
+        );
+    }
+
+    #[test]
+    fn unbounded_only() {
+        let all = IntervalSet::from(Interval::<i32>::unbounded());
+        let t = Timeline::new().with_width(5).row("all", &all);
+        assert_eq!(t.to_string(), "all <===>\n");
+    }
+
+    #[test]
+    fn unscalable_viewport_draws_unbounded_only() {
+        let set = IntervalSet::from(Interval::closed(i32::MIN, i32::MAX));
+        let t = Timeline::new().with_width(5).row("", &set);
+        let mut out = String::new();
+        assert_eq!(t.render(&mut out), Err(TimelineError::Span));
+        assert!(out.is_empty());
+        assert_eq!(t.to_string(), "-----\n");
+
+        let set = IntervalSet::from(Interval::closed(0, 1));
+        let all = IntervalSet::from(Interval::unbounded());
+        let t = Timeline::new()
+            .with_width(5)
+            .with_viewport(4, 2)
+            .row("a", &set)
+            .row("b", &all);
+        assert_eq!(t.render(&mut out), Err(TimelineError::Viewport));
+        assert_eq!(t.to_string(), "a -----\nb <===>\n");
+    }
+
+    #[quickcheck]
+    fn check_each_row_is_width(set: IntervalSet<i8>, width: u8) -> bool {
+        let t = Timeline::new()
+            .with_width(usize::from(width))
+            .row("s", &set);
+        let out = t.to_string();
+        let expected = usize::from(width).max(2) + 2;
+        out.lines()
+            .next()
+            .is_some_and(|row| row.chars().count() == expected)
+    }
+}